@@ -0,0 +1,486 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the blocking `Client` struct.
+//!
+//! It is only available with the `blocking` feature.
+
+use std::{future::Future, sync::Arc, time::Duration};
+
+use crate::{
+    models::{
+        Anime, AnimeSearchOptions, Character, Manga, MangaSearchOptions, Page, Person, Studio, Tag,
+        User, UserIdentifier,
+    },
+    Result,
+};
+
+/// Represents a blocking client for interacting with the AniList API.
+///
+/// The `Client` struct wraps the asynchronous `Client` and drives it
+/// on a small internal runtime, so it can be used from synchronous
+/// code without setting up an async runtime.
+///
+/// The models it returns keep their asynchronous methods, such as
+/// `load_full`, which can be run with [`Client::block_on`].
+///
+/// # Panics
+///
+/// The methods of this client panic if called from within an async
+/// runtime, use the asynchronous `Client` there instead.
+///
+/// # Example
+///
+/// ```no_run
+/// use rust_anilist::blocking::Client;
+///
+/// let client = Client::default();
+/// let anime = client.get_anime(1)?;
+/// let manga = client.block_on(client.get_manga(30013)?.load_full())?;
+/// # Ok::<(), rust_anilist::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Client {
+    /// The asynchronous client sending the requests.
+    inner: crate::Client,
+    /// The runtime driving the requests.
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Creates a new client instance with the specified timeout duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The timeout duration for requests.
+    pub fn with_timeout(duration: Duration) -> Self {
+        Client::from(crate::Client::with_timeout(duration))
+    }
+
+    /// Creates a new client instance with the specified API token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - A string slice that holds the API token.
+    pub fn with_token(token: &str) -> Self {
+        Client::from(crate::Client::with_token(token))
+    }
+
+    /// Sets the timeout duration for the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The timeout duration for requests.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.inner = self.inner.timeout(duration);
+        self
+    }
+
+    /// Sets the connect timeout duration for the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The timeout duration for connecting to the API.
+    pub fn connect_timeout(mut self, duration: Duration) -> Self {
+        self.inner = self.inner.connect_timeout(duration);
+        self
+    }
+
+    /// Caches the anime, manga, characters, staff and users fetched by
+    /// their ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximum number of responses held.
+    /// * `ttl` - The time a response is kept for.
+    pub fn with_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.inner = self.inner.with_cache(capacity, ttl);
+        self
+    }
+
+    /// Sets the API token for the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - A string slice that holds the API token.
+    pub fn token(mut self, token: &str) -> Self {
+        self.inner = self.inner.token(token);
+        self
+    }
+
+    /// Sets whether the descriptions are requested as HTML.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to request the descriptions as HTML.
+    pub fn prefer_html_descriptions(mut self, enabled: bool) -> Self {
+        self.inner = self.inner.prefer_html_descriptions(enabled);
+        self
+    }
+
+    /// Sets whether the media for adults are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to leave out the media for adults.
+    pub fn hide_adult_content(mut self, enabled: bool) -> Self {
+        self.inner = self.inner.hide_adult_content(enabled);
+        self
+    }
+
+    /// Returns the asynchronous client wrapped by this client.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client.
+    ///
+    /// This is how the asynchronous methods of the models, such as
+    /// `load_full`, are called from synchronous code.
+    ///
+    /// # Arguments
+    ///
+    /// * `future` - The future to run.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Get an anime by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_anime(&self, id: i64) -> Result<Anime> {
+        self.block_on(self.inner.get_anime(id))
+    }
+
+    /// Get the basic details of an anime by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_anime_basic(&self, id: i64) -> Result<Anime> {
+        self.block_on(self.inner.get_anime_basic(id))
+    }
+
+    /// Get several animes by their IDs, with a bounded number of
+    /// requests in flight.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the animes.
+    /// * `max_in_flight` - The maximum number of concurrent requests.
+    pub fn get_animes_concurrent(&self, ids: &[i64], max_in_flight: usize) -> Vec<Result<Anime>> {
+        self.block_on(self.inner.get_animes_concurrent(ids, max_in_flight))
+    }
+
+    /// Get a manga by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the manga.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_manga(&self, id: i64) -> Result<Manga> {
+        self.block_on(self.inner.get_manga(id))
+    }
+
+    /// Get a character by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_character(&self, id: i64) -> Result<Character> {
+        self.block_on(self.inner.get_character(id))
+    }
+
+    /// Get a user by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_user(&self, id: i64) -> Result<User> {
+        self.block_on(self.inner.get_user(id))
+    }
+
+    /// Get a user by its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_user_by_name<N: ToString>(&self, name: N) -> Result<User> {
+        self.block_on(self.inner.get_user_by_name(name))
+    }
+
+    /// Get a user by its ID or by its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - The ID or the name of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_user_by<I: Into<UserIdentifier>>(&self, identifier: I) -> Result<User> {
+        self.block_on(self.inner.get_user_by(identifier))
+    }
+
+    /// Get a person by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_person(&self, id: i64) -> Result<Person> {
+        self.block_on(self.inner.get_person(id))
+    }
+
+    /// Get a studio by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the studio.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_studio(&self, id: i64) -> Result<Studio> {
+        self.block_on(self.inner.get_studio(id))
+    }
+
+    /// Search for animes.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the anime to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn search_anime(&self, title: &str, page: u16, limit: u16) -> Result<Page<Anime>> {
+        self.block_on(self.inner.search_anime(title, page, limit))
+    }
+
+    /// Search for animes matching the given filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The filters of the search.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn search_anime_with(
+        &self,
+        options: AnimeSearchOptions,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Anime>> {
+        self.block_on(self.inner.search_anime_with(options, page, per_page))
+    }
+
+    /// Search for mangas.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the manga to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of mangas to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn search_manga(&self, title: &str, page: u16, limit: u16) -> Result<Page<Manga>> {
+        self.block_on(self.inner.search_manga(title, page, limit))
+    }
+
+    /// Search for mangas matching the given filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The filters of the search.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of mangas to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn search_manga_with(
+        &self,
+        options: MangaSearchOptions,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Manga>> {
+        self.block_on(self.inner.search_manga_with(options, page, per_page))
+    }
+
+    /// Search for users.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the user to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of users to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn search_user(&self, name: &str, page: u16, limit: u16) -> Result<Page<User>> {
+        self.block_on(self.inner.search_user(name, page, limit))
+    }
+
+    /// Search for characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the character to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn search_character(&self, name: &str, page: u16, limit: u16) -> Result<Page<Character>> {
+        self.block_on(self.inner.search_character(name, page, limit))
+    }
+
+    /// Search for persons.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the person to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of persons to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn search_person(&self, name: &str, page: u16, limit: u16) -> Result<Page<Person>> {
+        self.block_on(self.inner.search_person(name, page, limit))
+    }
+
+    /// Search for studios.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the studio to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of studios to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn search_studio(&self, name: &str, page: u16, limit: u16) -> Result<Page<Studio>> {
+        self.block_on(self.inner.search_studio(name, page, limit))
+    }
+
+    /// Get all the genres available on AniList.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_genres(&self) -> Result<Vec<String>> {
+        self.block_on(self.inner.get_genres())
+    }
+
+    /// Get all the media tags available on AniList.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_tags(&self) -> Result<Vec<Tag>> {
+        self.block_on(self.inner.get_tags())
+    }
+}
+
+impl From<crate::Client> for Client {
+    /// Wraps an asynchronous client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime cannot be created.
+    fn from(inner: crate::Client) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build the runtime");
+
+        Self {
+            inner,
+            runtime: Arc::new(runtime),
+        }
+    }
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Client::from(crate::Client::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+
+    #[test]
+    fn test_get_genres() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": { "GenreCollection": ["Action", "Comedy"] }
+        }));
+        let client = Client::from(crate::Client::with_transport(transport.clone()));
+
+        assert_eq!(client.get_genres().unwrap(), vec!["Action", "Comedy"]);
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_parity() {
+        // Keeps the blocking client in line with the asynchronous one.
+        let client = Client::default();
+        let _: fn(&Client, i64) -> Result<Anime> = Client::get_anime;
+        let _: fn(&Client, i64) -> Result<Manga> = Client::get_manga;
+        let _: fn(&Client, i64) -> Result<Character> = Client::get_character;
+        let _: fn(&Client, i64) -> Result<User> = Client::get_user;
+        let _: fn(&Client, i64) -> Result<Person> = Client::get_person;
+        let _: fn(&Client, i64) -> Result<Studio> = Client::get_studio;
+        let _: fn(&Client, &str, u16, u16) -> Result<Page<Anime>> = Client::search_anime;
+        let _: fn(&Client, &str, u16, u16) -> Result<Page<Manga>> = Client::search_manga;
+        let _: fn(&Client, &str, u16, u16) -> Result<Page<User>> = Client::search_user;
+        let _: fn(&Client, &str, u16, u16) -> Result<Page<Character>> = Client::search_character;
+        let _: fn(&Client, &str, u16, u16) -> Result<Page<Person>> = Client::search_person;
+        let _: fn(&Client, &str, u16, u16) -> Result<Page<Studio>> = Client::search_studio;
+
+        assert_eq!(client.inner(), &crate::Client::default());
+    }
+}
@@ -0,0 +1,263 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Cache` struct.
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use serde_json::Value;
+use tokio::time::Instant;
+
+use crate::models::{DescriptionFormat, MediaKey};
+
+/// Represents an in-memory cache of the responses of the API.
+///
+/// The `Cache` struct holds up to `capacity` responses, each one
+/// expiring `ttl` after it was stored. Once full, the least recently
+/// used response is evicted to make room for a new one.
+///
+/// Responses are stored as JSON rather than as models, since the models
+/// hold the client, and thus the cache itself. They are also stored per
+/// API token, since they include fields specific to the authenticated
+/// user, like whether a media is a favourite.
+#[derive(Debug)]
+pub(crate) struct Cache {
+    /// The maximum number of responses held.
+    capacity: usize,
+    /// The time a response is kept for.
+    ttl: Duration,
+    /// The responses held, and the usage counter.
+    entries: Mutex<Entries>,
+}
+
+/// Represents the responses held by a `Cache`.
+#[derive(Debug, Default)]
+struct Entries {
+    /// The responses, by the hash of the API token they were requested
+    /// with and the key of what they describe.
+    map: HashMap<(Option<u64>, MediaKey), Entry>,
+    /// The counter incremented on every use of a response.
+    clock: u64,
+}
+
+/// Represents a response held by a `Cache`.
+#[derive(Debug)]
+struct Entry {
+    /// The response of the API.
+    value: Value,
+    /// The format the descriptions of the response are in.
+    format: DescriptionFormat,
+    /// The time the response expires at.
+    expires_at: Instant,
+    /// The value of the usage counter when the response was last used.
+    used_at: u64,
+}
+
+impl Cache {
+    /// Creates a new empty cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximum number of responses held.
+    /// * `ttl` - The time a response is kept for.
+    pub(crate) fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Returns the response stored for a key, if it has not expired and
+    /// has its descriptions in the given format.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The hash of the API token of the request, if any.
+    /// * `key` - The key of the response.
+    /// * `format` - The format the descriptions must be in.
+    pub(crate) fn get(
+        &self,
+        token: Option<u64>,
+        key: &MediaKey,
+        format: DescriptionFormat,
+    ) -> Option<Value> {
+        let mut entries = self.lock();
+        let Entries { map, clock } = &mut *entries;
+        let key = (token, key.clone());
+
+        match map.get_mut(&key) {
+            Some(entry) if entry.expires_at <= Instant::now() => {
+                map.remove(&key);
+                None
+            }
+            Some(entry) if entry.format == format => {
+                *clock += 1;
+                entry.used_at = *clock;
+                Some(entry.value.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Stores the response for a key, evicting the least recently used
+    /// response if the cache is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The hash of the API token of the request, if any.
+    /// * `key` - The key of the response.
+    /// * `format` - The format the descriptions of the response are in.
+    /// * `value` - The response of the API.
+    pub(crate) fn insert(
+        &self,
+        token: Option<u64>,
+        key: MediaKey,
+        format: DescriptionFormat,
+        value: Value,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.lock();
+        let Entries { map, clock } = &mut *entries;
+        let key = (token, key);
+
+        if !map.contains_key(&key) && map.len() >= self.capacity {
+            let now = Instant::now();
+            map.retain(|_, entry| entry.expires_at > now);
+
+            if map.len() >= self.capacity {
+                let oldest = map
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.used_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    map.remove(&oldest);
+                }
+            }
+        }
+
+        *clock += 1;
+        map.insert(
+            key,
+            Entry {
+                value,
+                format,
+                expires_at: Instant::now() + self.ttl,
+                used_at: *clock,
+            },
+        );
+    }
+
+    /// Removes the responses stored for a key, whatever the API token
+    /// they were requested with.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the responses.
+    pub(crate) fn remove(&self, key: &MediaKey) {
+        self.lock().map.retain(|(_, other), _| other != key);
+    }
+
+    /// Removes all the responses.
+    pub(crate) fn clear(&self) {
+        self.lock().map.clear();
+    }
+
+    /// Returns the number of responses held, expired ones included.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.lock().map.len()
+    }
+
+    /// Locks the responses, recovering them if the lock was poisoned.
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::MediaType;
+
+    fn key(id: i64) -> MediaKey {
+        MediaKey::new(MediaType::Anime, id)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cache_expires() {
+        let cache = Cache::new(2, Duration::from_secs(60));
+        cache.insert(None, key(1), DescriptionFormat::Html, serde_json::json!(1));
+
+        tokio::time::advance(Duration::from_secs(59)).await;
+        assert_eq!(
+            cache.get(None, &key(1), DescriptionFormat::Html),
+            Some(serde_json::json!(1))
+        );
+
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert_eq!(cache.get(None, &key(1), DescriptionFormat::Html), None);
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cache_evicts_least_recently_used() {
+        let cache = Cache::new(2, Duration::from_secs(60));
+        cache.insert(None, key(1), DescriptionFormat::Html, serde_json::json!(1));
+        cache.insert(None, key(2), DescriptionFormat::Html, serde_json::json!(2));
+        cache.get(None, &key(1), DescriptionFormat::Html);
+        cache.insert(None, key(3), DescriptionFormat::Html, serde_json::json!(3));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(None, &key(1), DescriptionFormat::Html).is_some());
+        assert!(cache.get(None, &key(2), DescriptionFormat::Html).is_none());
+        assert!(cache.get(None, &key(3), DescriptionFormat::Html).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cache_checks_format() {
+        let cache = Cache::new(2, Duration::from_secs(60));
+        cache.insert(None, key(1), DescriptionFormat::Html, serde_json::json!(1));
+
+        assert!(cache
+            .get(None, &key(1), DescriptionFormat::Markdown)
+            .is_none());
+
+        cache.remove(&key(1));
+        assert!(cache.get(None, &key(1), DescriptionFormat::Html).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cache_without_capacity() {
+        let cache = Cache::new(0, Duration::from_secs(60));
+        cache.insert(None, key(1), DescriptionFormat::Html, serde_json::json!(1));
+
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cache_per_token() {
+        let cache = Cache::new(2, Duration::from_secs(60));
+        cache.insert(
+            Some(1),
+            key(1),
+            DescriptionFormat::Html,
+            serde_json::json!(1),
+        );
+
+        assert!(cache
+            .get(Some(1), &key(1), DescriptionFormat::Html)
+            .is_some());
+        assert!(cache
+            .get(Some(2), &key(1), DescriptionFormat::Html)
+            .is_none());
+        assert!(cache.get(None, &key(1), DescriptionFormat::Html).is_none());
+
+        cache.insert(None, key(1), DescriptionFormat::Html, serde_json::json!(1));
+        cache.remove(&key(1));
+        assert_eq!(cache.len(), 0);
+    }
+}
@@ -3,26 +3,89 @@
 
 //! This module contains the `Client` struct and its related types.
 
-use serde::Deserialize;
-use std::time::Duration;
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone, Utc, Weekday};
+use futures_util::{Stream, StreamExt, TryStreamExt};
+use reqwest::Url;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    hash::{Hash, Hasher},
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
 
 use crate::{
+    cache::Cache,
     models::{
-        Anime, Character, Cover, Format, Image, Manga, MediaType, Person, Status, Title, User,
+        AiringSchedule, Anime, AnimeSearchOptions, Character, CharacterConnection,
+        DescriptionFormat, FavouriteCategory, Favourites, Language, LikeableType, ListComparison,
+        Manga, MangaSearchOptions, Media, MediaConnection, MediaKey, MediaListEntry,
+        MediaListEntryUpdate, MediaListGroup, MediaListStatus, MediaSort, MediaTrend,
+        MediaTrendSort, MediaType, Page, Person, Recommendation, RecommendationRating, Review,
+        ScoreFormat, Season, Studio, Tag, Thread, ThreadComment, User, UserIdentifier,
     },
+    parse,
+    rate_limit::{RateLimitStatus, RateLimiter},
+    retry, stream,
+    transport::{HttpTransport, Transport},
     Error, Result,
 };
 
+/// The default timeout for requests.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// The default timeout for connecting to the API.
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The default URL of the GraphQL endpoint.
+const DEFAULT_BASE_URL: &str = "https://graphql.anilist.co/";
+
+/// The maximum number of items the API returns in a single page.
+pub(crate) const MAX_PER_PAGE: usize = 50;
+
+/// The number of entries requested per chunk of a media list.
+const MEDIA_LIST_CHUNK_SIZE: usize = 500;
+
+/// The function called with the errors of a partial response.
+type WarningHandler = Arc<dyn Fn(&[Error]) + Send + Sync>;
+
 /// Represents a client for interacting with an API.
 ///
 /// The `Client` struct contains the necessary configuration for making
 /// requests to an API, including the API token and the timeout duration.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// The underlying transport is shared between clones, so cloning a
+/// `Client` is cheap and keeps reusing the same connection pool.
+///
+/// The API token is redacted from the `Debug` output, so formatting a
+/// client, or a model holding one, does not leak it into logs.
+#[derive(Clone)]
 pub struct Client {
     /// The API token to use for requests.
     api_token: Option<String>,
     /// The timeout for requests (in seconds).
     timeout: Duration,
+    /// The timeout for connecting to the API.
+    connect_timeout: Duration,
+    /// The URL of the GraphQL endpoint.
+    base_url: Url,
+    /// Whether to retry once after being rate limited.
+    auto_retry: bool,
+    /// The maximum number of retries on transient failures.
+    max_retries: u32,
+    /// The transport used to send requests.
+    transport: Arc<dyn Transport>,
+    /// Whether the transport was provided by the user.
+    custom_transport: bool,
+    /// The client-side rate limiter, if enabled.
+    limiter: Option<Arc<RateLimiter>>,
+    /// The cache of the responses, if enabled.
+    cache: Option<Arc<Cache>>,
+    /// The format the descriptions are requested in.
+    description_format: DescriptionFormat,
+    /// Whether the media for adults are left out.
+    hide_adult_content: bool,
+    /// The function called with the errors of partial responses, if set.
+    warning_handler: Option<WarningHandler>,
 }
 
 impl Client {
@@ -35,9 +98,22 @@ impl Client {
     ///
     /// * `timeout` - The timeout duration for requests, in seconds.
     pub fn with_timeout(duration: Duration) -> Self {
+        let base_url = Url::parse(DEFAULT_BASE_URL).expect("the default URL is valid");
+
         Self {
             api_token: None,
             timeout: duration,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            transport: Client::http_transport(duration, DEFAULT_CONNECT_TIMEOUT, &base_url),
+            base_url,
+            auto_retry: false,
+            max_retries: 0,
+            custom_transport: false,
+            limiter: None,
+            cache: None,
+            description_format: DescriptionFormat::Html,
+            hide_adult_content: false,
+            warning_handler: None,
         }
     }
 
@@ -52,7 +128,7 @@ impl Client {
     pub fn with_token(token: &str) -> Self {
         Self {
             api_token: Some(token.to_string()),
-            timeout: Duration::from_secs(20),
+            ..Default::default()
         }
     }
 
@@ -67,6 +143,37 @@ impl Client {
     /// * `seconds` - The timeout duration in seconds.
     pub fn timeout(mut self, duration: Duration) -> Self {
         self.timeout = duration;
+        if !self.custom_transport {
+            self.transport = Client::http_transport(duration, self.connect_timeout, &self.base_url);
+        }
+        self
+    }
+
+    /// Sets the connect timeout duration for the client.
+    ///
+    /// The connect timeout only bounds establishing the connection to
+    /// the API, so dead connections fail fast, while the timeout set
+    /// with `timeout` remains the deadline of the whole request. It
+    /// defaults to 5 seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The timeout duration for connecting to the API.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use rust_anilist::Client;
+    /// let client = Client::default()
+    ///     .connect_timeout(Duration::from_secs(2))
+    ///     .timeout(Duration::from_secs(30));
+    /// ```
+    pub fn connect_timeout(mut self, duration: Duration) -> Self {
+        self.connect_timeout = duration;
+        if !self.custom_transport {
+            self.transport = Client::http_transport(self.timeout, duration, &self.base_url);
+        }
         self
     }
 
@@ -83,156 +190,284 @@ impl Client {
         self
     }
 
-    /// Get an anime by its ID or MAL ID.
+    /// Creates a new client instance sending requests to the specified
+    /// GraphQL endpoint.
+    ///
+    /// This is useful to send requests through a caching proxy or to a
+    /// local mock server, instead of the official AniList endpoint.
     ///
     /// # Arguments
     ///
-    /// * `id` - The ID of the anime.
-    /// * `mal_id` - The MAL ID of the anime.
+    /// * `url` - The URL of the GraphQL endpoint.
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails.
+    /// Returns an error if the URL is not a valid HTTP or HTTPS URL.
     ///
     /// # Example
     ///
     /// ```
-    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
-    /// let anime = client.get_anime(1).await?;
-    ///
-    /// # Ok(())
-    /// # }
+    /// # use rust_anilist::Client;
+    /// let client = Client::with_base_url("http://localhost:8080/graphql")?;
+    /// # Ok::<(), rust_anilist::Error>(())
     /// ```
-    pub async fn get_anime(&self, id: i64) -> Result<Anime> {
-        let data = self
-            .request(
-                MediaType::Anime,
-                Action::Get,
-                serde_json::json!({ "id": id }),
-            )
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+    pub fn with_base_url(url: &str) -> Result<Self> {
+        let base_url = Url::parse(url).map_err(|e| Error::InvalidUrl(e.to_string()))?;
+        if !matches!(base_url.scheme(), "http" | "https") {
+            return Err(Error::InvalidUrl(format!(
+                "unsupported scheme `{}`",
+                base_url.scheme()
+            )));
+        }
 
-        match serde_json::from_str::<Anime>(&data["data"]["Media"].to_string()) {
-            Ok(mut anime) => {
-                anime.client = self.clone();
-                anime.is_full_loaded = true;
+        let client = Client::default();
 
-                Ok(anime)
-            }
-            Err(e) => Err(crate::Error::ApiError(e.to_string())),
+        Ok(Self {
+            transport: Client::http_transport(client.timeout, client.connect_timeout, &base_url),
+            base_url,
+            ..client
+        })
+    }
+
+    /// Creates a new client instance sending requests through the
+    /// specified transport.
+    ///
+    /// This is mostly useful to mock the API in tests, the timeout of
+    /// the client is then left to the transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The transport to send requests through.
+    pub fn with_transport<T: Transport + 'static>(transport: T) -> Self {
+        Self {
+            transport: Arc::new(transport),
+            custom_transport: true,
+            ..Default::default()
         }
     }
 
-    /// Get a manga by its ID or MAL ID.
+    /// Sets whether the client retries rate limited requests.
+    ///
+    /// When enabled, a request rejected with HTTP 429 waits for the
+    /// duration advertised by the API and is retried once before
+    /// returning `Error::RateLimited`. Disabled by default.
     ///
     /// # Arguments
     ///
-    /// * `id` - The ID of the manga.
-    /// * `mal_id` - The MAL ID of the manga.
+    /// * `enabled` - Whether to retry rate limited requests.
+    pub fn with_auto_retry(mut self, enabled: bool) -> Self {
+        self.auto_retry = enabled;
+        self
+    }
+
+    /// Sets whether the descriptions are requested as HTML.
     ///
-    /// # Errors
+    /// AniList renders the markdown of the descriptions of the media,
+    /// characters and staff to HTML by default. When disabled, they are
+    /// returned as written, and the models hold
+    /// `DescriptionFormat::Markdown` so they can be escaped accordingly.
     ///
-    /// Returns an error if the request fails.
+    /// # Arguments
     ///
-    /// # Example
+    /// * `enabled` - Whether to request the descriptions as HTML.
+    pub fn prefer_html_descriptions(mut self, enabled: bool) -> Self {
+        self.description_format = if enabled {
+            DescriptionFormat::Html
+        } else {
+            DescriptionFormat::Markdown
+        };
+        self
+    }
+
+    /// Sets whether the media for adults are left out.
     ///
-    /// ```
-    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
-    /// let manga = client.get_manga(1).await?;
+    /// When enabled, the searches and browses of media only request the
+    /// media that are not for adults, overriding
+    /// `AnimeSearchOptions::is_adult`, and the adult media are removed
+    /// from the relations of the anime and mangas and from their
+    /// recommendations. Disabled by default.
     ///
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub async fn get_manga(&self, id: i64) -> Result<Manga> {
-        let data = self
-            .request(
-                MediaType::Manga,
-                Action::Get,
-                serde_json::json!({ "id": id }),
-            )
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to leave out the media for adults.
+    pub fn hide_adult_content(mut self, enabled: bool) -> Self {
+        self.hide_adult_content = enabled;
+        self
+    }
 
-        match serde_json::from_str::<Manga>(&data["data"]["Media"].to_string()) {
-            Ok(mut manga) => {
-                manga.client = self.clone();
-                manga.is_full_loaded = true;
+    /// Returns the hash of the API token, if any.
+    ///
+    /// The cached responses are stored per token, so a client with
+    /// another token never gets the responses of another user.
+    fn token_hash(&self) -> Option<u64> {
+        self.api_token.as_ref().map(|token| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            token.hash(&mut hasher);
+            hasher.finish()
+        })
+    }
 
-                Ok(manga)
-            }
-            Err(e) => Err(crate::Error::ApiError(e.to_string())),
-        }
+    /// Returns the format the descriptions are requested in.
+    pub(crate) fn description_format(&self) -> DescriptionFormat {
+        self.description_format
     }
 
-    /// Get a character by its ID.
+    /// Sets the maximum number of retries on transient failures.
+    ///
+    /// Network errors and server errors are retried with an exponential
+    /// backoff and jitter, while client errors and GraphQL validation
+    /// errors are returned right away. Mutations are never retried, as
+    /// they are not idempotent. Disabled by default.
     ///
     /// # Arguments
     ///
-    /// * `id` - The ID of the character.
+    /// * `max` - The maximum number of retries.
     ///
-    /// # Errors
+    /// # Example
     ///
-    /// Returns an error if the request fails.
+    /// ```
+    /// # use rust_anilist::Client;
+    /// let client = Client::default().with_retries(3);
+    /// ```
+    pub fn with_retries(mut self, max: u32) -> Self {
+        self.max_retries = max;
+        self
+    }
+
+    /// Sets the function called with the errors of partial responses.
+    ///
+    /// The API may answer with both data and errors, like when one of
+    /// the requested fields is restricted. Such responses succeed with
+    /// the data that was returned, and their errors are passed to this
+    /// function so they can be logged rather than lost. Responses
+    /// without any data still fail with their first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - The function called with the errors.
     ///
     /// # Example
     ///
     /// ```
-    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
-    /// let character = client.get_character(1).await?;
+    /// # use rust_anilist::Client;
+    /// let client = Client::default().on_warnings(|warnings| {
+    ///     for warning in warnings {
+    ///         eprintln!("partial response: {warning}");
+    ///     }
+    /// });
+    /// ```
+    pub fn on_warnings<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&[Error]) + Send + Sync + 'static,
+    {
+        self.warning_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Caches the anime, manga, characters, staff and users fetched by
+    /// their ID.
+    ///
+    /// The responses of `get_anime`, `get_manga`, `get_character`,
+    /// `get_person` and `get_user` are kept in memory for `ttl`, and up
+    /// to `capacity` of them are held, evicting the least recently used
+    /// first. The cache is shared between clones of the client, but the
+    /// responses are kept apart per API token, and the mutations clear
+    /// the responses they affect. Disabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximum number of responses held.
+    /// * `ttl` - The time a response is kept for.
+    ///
+    /// # Example
     ///
-    /// # Ok(())
-    /// # }
     /// ```
-    pub async fn get_character(&self, id: i64) -> Result<Character> {
-        let data = self
-            .request(
-                MediaType::Character,
-                Action::Get,
-                serde_json::json!({ "id": id }),
-            )
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+    /// # use std::time::Duration;
+    /// # use rust_anilist::Client;
+    /// let client = Client::default().with_cache(100, Duration::from_secs(600));
+    /// ```
+    pub fn with_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.cache = Some(Arc::new(Cache::new(capacity, ttl)));
+        self
+    }
 
-        match serde_json::from_str::<Character>(&data["data"]["Character"].to_string()) {
-            Ok(mut character) => {
-                character.client = self.clone();
-                character.is_full_loaded = true;
+    /// Removes the cached response of an anime, manga, character, staff
+    /// or user, so it is fetched again on the next request.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of the media.
+    /// * `id` - The ID of the media.
+    pub fn invalidate(&self, media_type: MediaType, id: i64) {
+        if let Some(cache) = &self.cache {
+            cache.remove(&MediaKey::new(media_type, id));
+        }
+    }
 
-                Ok(character)
-            }
-            Err(e) => Err(crate::Error::ApiError(e.to_string())),
+    /// Removes all the cached responses.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
         }
     }
 
-    /// Get a character by its ID.
+    /// Limits the number of requests sent by the client.
+    ///
+    /// Requests are throttled by a token bucket shared between clones
+    /// of the client, so concurrent tasks collectively stay under the
+    /// limit. Disabled by default.
     ///
     /// # Arguments
     ///
-    /// * `id` - The ID of the character.
+    /// * `requests_per_minute` - The maximum number of requests per minute.
     ///
-    /// # Errors
+    /// # Example
     ///
-    /// Returns an error if the request fails.
+    /// ```
+    /// # use rust_anilist::Client;
+    /// let client = Client::default().with_rate_limit(60);
+    /// ```
+    pub fn with_rate_limit(mut self, requests_per_minute: u32) -> Self {
+        self.limiter = Some(Arc::new(RateLimiter::new(requests_per_minute)));
+        self
+    }
+
+    /// Returns the rate limit quota reported by the last response.
+    ///
+    /// The quota is kept by the transport of the client, so it is shared
+    /// between clones of the client. The clients using the default
+    /// timeouts and URL all share one transport, so for them the quota is
+    /// process-wide and comes from the last response received by any of
+    /// them. AniList counts the quota per IP address, so it holds for
+    /// all of them anyway.
+    ///
+    /// It is `None` until a response with rate limit headers is received.
+    /// It can be checked before a background refresh, to defer it while
+    /// few requests remain.
     ///
     /// # Example
     ///
     /// ```
     /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
-    /// let character = client.get_char(1).await?;
+    /// let anime = client.get_anime(1).await?;
+    ///
+    /// if let Some(status) = client.rate_limit_status() {
+    ///     println!("{} requests remaining", status.remaining);
+    /// }
     ///
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_char(&self, id: i64) -> Result<Character> {
-        self.get_character(id).await
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.transport.rate_limit_status()
     }
 
-    /// Get a user by its ID.
+    /// Get an anime by its ID or MAL ID.
     ///
     /// # Arguments
     ///
-    /// * `id` - The ID of the user.
+    /// * `id` - The ID of the anime.
+    /// * `mal_id` - The MAL ID of the anime.
     ///
     /// # Errors
     ///
@@ -242,32 +477,44 @@ impl Client {
     ///
     /// ```
     /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
-    /// let user = client.get_user(1).await?;
+    /// let anime = client.get_anime(1).await?;
     ///
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_user(&self, id: i32) -> Result<User> {
+    pub async fn get_anime(&self, id: i64) -> Result<Anime> {
         let data = self
             .request(
-                MediaType::User,
+                MediaType::Anime,
                 Action::Get,
                 serde_json::json!({ "id": id }),
             )
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+            .await?;
 
-        match serde_json::from_str::<User>(&data["data"]["User"].to_string()) {
-            Ok(user) => Ok(user),
-            Err(e) => Err(crate::Error::ApiError(e.to_string())),
+        let mut anime: Anime = parse::deserialize(&data, "data.Media")?;
+        anime.client = self.clone();
+        anime.is_full_loaded = true;
+        anime.description_format = self.description_format;
+        anime
+            .characters
+            .set_description_format(self.description_format);
+        if self.hide_adult_content {
+            anime.relations.remove_adult();
         }
+
+        Ok(anime)
     }
 
-    /// Get a user by its name.
+    /// Get the basic details of an anime by its ID.
+    ///
+    /// Only the ID, title, format, status, cover and URL of the anime
+    /// are requested, which is much cheaper than `get_anime` for list
+    /// views. The other fields are left empty and the anime is not full
+    /// loaded, so `Anime::load_full` fetches the rest when needed.
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the user.
+    /// * `id` - The ID of the anime.
     ///
     /// # Errors
     ///
@@ -277,39 +524,64 @@ impl Client {
     ///
     /// ```
     /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
-    /// let user = client.get_user_by_name("andrielfr").await?;
+    /// let anime = client.get_anime_basic(1).await?;
+    /// let anime = anime.load_full().await?;
     ///
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_user_by_name<N: ToString>(&self, name: N) -> Result<User> {
-        let name = name.to_string();
-
+    pub async fn get_anime_basic(&self, id: i64) -> Result<Anime> {
         let data = self
-            .request(
-                MediaType::User,
-                Action::Get,
-                serde_json::json!({ "name": name }),
+            .send(
+                include_str!("../queries/get_anime_basic.graphql"),
+                serde_json::json!({ "id": id }),
             )
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+            .await?;
 
-        match serde_json::from_str::<User>(&data["data"]["User"].to_string()) {
-            Ok(mut user) => {
-                user.client = self.clone();
-                user.is_full_loaded = true;
+        Anime::from_node(&data["data"]["Media"], self)
+    }
 
-                Ok(user)
-            }
-            Err(e) => Err(crate::Error::ApiError(e.to_string())),
-        }
+    /// Get several animes by their IDs, with a bounded number of
+    /// requests in flight.
+    ///
+    /// The results are in the same order as the IDs, and each request
+    /// goes through the rate limiter and the cache, if enabled, so a
+    /// failed request does not fail the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the animes.
+    /// * `max_in_flight` - The maximum number of concurrent requests, at
+    ///   least one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// for anime in client.get_animes_concurrent(&[1, 5, 20], 2).await {
+    ///     println!("{}", anime?.title.romaji());
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_animes_concurrent(
+        &self,
+        ids: &[i64],
+        max_in_flight: usize,
+    ) -> Vec<Result<Anime>> {
+        futures_util::stream::iter(ids)
+            .map(|id| self.get_anime(*id))
+            .buffered(max_in_flight.max(1))
+            .collect()
+            .await
     }
 
-    /// Get a person by its ID.
+    /// Get a manga by its ID.
     ///
     /// # Arguments
     ///
-    /// * `id` - The ID of the person.
+    /// * `id` - The ID of the manga.
     ///
     /// # Errors
     ///
@@ -319,39 +591,39 @@ impl Client {
     ///
     /// ```
     /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
-    /// let person = client.get_person(1).await?;
+    /// let manga = client.get_manga(1).await?;
     ///
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_person(&self, id: i64) -> Result<Person> {
+    pub async fn get_manga(&self, id: i64) -> Result<Manga> {
         let data = self
             .request(
-                MediaType::Person,
+                MediaType::Manga,
                 Action::Get,
                 serde_json::json!({ "id": id }),
             )
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+            .await?;
 
-        match serde_json::from_str::<Person>(&data["data"]["Staff"].to_string()) {
-            Ok(mut person) => {
-                person.client = self.clone();
-                person.is_full_loaded = true;
-
-                Ok(person)
-            }
-            Err(e) => Err(crate::Error::ApiError(e.to_string())),
+        let mut manga: Manga = parse::deserialize(&data, "data.Media")?;
+        manga.client = self.clone();
+        manga.is_full_loaded = true;
+        manga.description_format = self.description_format;
+        manga
+            .characters
+            .set_description_format(self.description_format);
+        if self.hide_adult_content {
+            manga.relations.remove_adult();
         }
+
+        Ok(manga)
     }
 
-    /// Search for animes.
+    /// Get a manga by its MAL ID.
     ///
     /// # Arguments
     ///
-    /// * `title` - The title of the anime to search.
-    /// * `page` - The page number to get.
-    /// * `limit` - The number of animes to get per page.
+    /// * `mal_id` - The MAL ID of the manga.
     ///
     /// # Errors
     ///
@@ -361,307 +633,5379 @@ impl Client {
     ///
     /// ```
     /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
-    /// let animes = client.search_anime("Naruto", 1, 10).await.unwrap();
+    /// let manga = client.get_manga_by_mal_id(13).await?;
     ///
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn search_anime(&self, title: &str, page: u16, limit: u16) -> Option<Vec<Anime>> {
-        let result = self
+    pub async fn get_manga_by_mal_id(&self, mal_id: i64) -> Result<Manga> {
+        let data = self
             .request(
-                MediaType::Anime,
-                Action::Search,
-                serde_json::json!({ "search": title, "page": page, "per_page": limit, }),
+                MediaType::Manga,
+                Action::Get,
+                serde_json::json!({ "idMal": mal_id }),
             )
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))
-            .unwrap();
-
-        if let Some(medias) = result["data"]["Page"]["media"].as_array() {
-            let mut animes = Vec::new();
-
-            for media in medias.iter() {
-                animes.push(Anime {
-                    id: media["id"].as_i64().unwrap(),
-                    id_mal: media["idMal"].as_i64(),
-                    title: Title::deserialize(&media["title"]).unwrap(),
-                    format: Format::deserialize(&media["format"]).unwrap(),
-                    status: Status::deserialize(&media["status"]).unwrap(),
-                    description: media["description"].as_str().unwrap().to_string(),
-                    cover: Cover::deserialize(&media["coverImage"]).unwrap(),
-                    banner: media["bannerImage"].as_str().map(String::from),
-                    average_score: media["averageScore"].as_u64().map(|x| x as u8),
-                    mean_score: media["meanScore"].as_u64().map(|x| x as u8),
-                    is_adult: media["isAdult"].as_bool().unwrap(),
-                    url: media["siteUrl"].as_str().unwrap().to_string(),
-
-                    client: self.clone(),
-                    ..Default::default()
-                });
-            }
+            .await?;
 
-            return Some(animes);
+        let mut manga: Manga = parse::deserialize(&data, "data.Media")?;
+        manga.client = self.clone();
+        manga.is_full_loaded = true;
+        manga.description_format = self.description_format;
+        manga
+            .characters
+            .set_description_format(self.description_format);
+        if self.hide_adult_content {
+            manga.relations.remove_adult();
         }
 
-        None
+        Ok(manga)
     }
 
-    /// Search for mangas.
+    /// Get several animes by their IDs.
+    ///
+    /// The IDs are fetched in batches of 50, so only one request is made
+    /// per 50 IDs. The animes are returned in the order of the IDs, and
+    /// IDs that do not match any anime are left out.
     ///
     /// # Arguments
     ///
-    /// * `title` - The title of the manga to search.
-    /// * `page` - The page number to get.
-    /// * `limit` - The number of mangas to get per page.
+    /// * `ids` - The IDs of the animes.
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails.
+    /// Returns an error if any of the requests fails.
     ///
     /// # Example
     ///
     /// ```
     /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
-    /// let mangas = client.search_manga("Naruto", 1, 10).await.unwrap();
+    /// let animes = client.get_animes(&[1, 20, 21]).await?;
     ///
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn search_manga(&self, title: &str, page: u16, limit: u16) -> Option<Vec<Manga>> {
-        let result = self
-            .request(
-                MediaType::Manga,
-                Action::Search,
-                serde_json::json!({ "search": title, "page": page, "per_page": limit, }),
-            )
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))
-            .unwrap();
-
-        if let Some(medias) = result["data"]["Page"]["media"].as_array() {
-            let mut mangas = Vec::new();
-
-            for media in medias.iter() {
-                mangas.push(Manga {
-                    id: media["id"].as_i64().unwrap(),
-                    id_mal: media["idMal"].as_i64(),
-                    title: Title::deserialize(&media["title"]).unwrap(),
-                    format: Format::deserialize(&media["format"]).unwrap(),
-                    status: Status::deserialize(&media["status"]).unwrap(),
-                    description: media["description"].as_str().unwrap().to_string(),
-                    cover: Cover::deserialize(&media["coverImage"]).unwrap(),
-                    banner: media["bannerImage"].as_str().map(String::from),
-                    average_score: media["averageScore"].as_u64().map(|x| x as u8),
-                    mean_score: media["meanScore"].as_u64().map(|x| x as u8),
-                    is_adult: media["isAdult"].as_bool().unwrap(),
-                    url: media["siteUrl"].as_str().unwrap().to_string(),
-
-                    client: self.clone(),
-                    ..Default::default()
-                });
-            }
-
-            return Some(mangas);
-        }
-
-        None
+    pub async fn get_animes(&self, ids: &[i64]) -> Result<Vec<Anime>> {
+        self.get_medias(MediaType::Anime, ids)
+            .await?
+            .iter()
+            .map(|media| Anime::from_node(media, self))
+            .collect()
     }
 
-    /// Search for users.
+    /// Get several mangas by their IDs.
+    ///
+    /// The IDs are fetched in batches of 50, so only one request is made
+    /// per 50 IDs. The mangas are returned in the order of the IDs, and
+    /// IDs that do not match any manga are left out.
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the user to search.
-    /// * `page` - The page number to get.
-    /// * `limit` - The number of users to get per page.
+    /// * `ids` - The IDs of the mangas.
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails.
+    /// Returns an error if any of the requests fails.
     ///
     /// # Example
     ///
     /// ```
     /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
-    /// let users = client.search_user("andrielfr", 1, 10).await.unwrap();
+    /// let mangas = client.get_mangas(&[30002, 30013]).await?;
     ///
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn search_user(&self, name: &str, page: u16, limit: u16) -> Option<Vec<User>> {
-        let result = self
-            .request(
-                MediaType::User,
-                Action::Search,
-                serde_json::json!({ "search": name, "page": page, "per_page": limit, }),
+    pub async fn get_mangas(&self, ids: &[i64]) -> Result<Vec<Manga>> {
+        self.get_medias(MediaType::Manga, ids)
+            .await?
+            .iter()
+            .map(|media| Manga::from_node(media, self))
+            .collect()
+    }
+
+    /// Get a page of the media a character appears in.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of media to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub(crate) async fn get_character_media(
+        &self,
+        id: i64,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Vec<Media>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_character_media.graphql"),
+                serde_json::json!({ "id": id, "page": page, "per_page": per_page }),
             )
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))
-            .unwrap();
+            .await?;
 
-        if let Some(users) = result["data"]["Page"]["users"].as_array() {
-            let mut vec = Vec::new();
+        Ok(parse::deserialize::<MediaConnection>(&result, "data.Character.media")?.media(self))
+    }
 
-            for user in users.iter() {
-                vec.push(User {
-                    id: user["id"].as_i64().unwrap() as i32,
-                    name: user["name"].as_str().unwrap().to_string(),
-                    about: user["about"].as_str().map(String::from),
-                    avatar: Image::deserialize(&user["avatar"]).ok(),
-                    banner: user["bannerImage"].as_str().map(String::from),
-
-                    client: self.clone(),
-                    ..Default::default()
+    /// Get a page of the characters of an anime, along with their voice
+    /// actors of a language.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    /// * `language` - The language of the voice actors.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub(crate) async fn get_anime_characters(
+        &self,
+        id: i64,
+        language: Language,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Vec<(Character, Vec<Person>)>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_anime_characters.graphql"),
+                serde_json::json!({
+                    "id": id,
+                    "language": language,
+                    "page": page,
+                    "per_page": per_page,
+                }),
+            )
+            .await?;
+
+        Ok(
+            parse::deserialize::<CharacterConnection>(&result, "data.Media.characters")?
+                .characters_with_voice_actors(self),
+        )
+    }
+
+    /// Get a page of the recommendations for a media.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the media.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of recommendations to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub(crate) async fn get_recommendations(
+        &self,
+        id: i64,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Vec<Recommendation>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_recommendations.graphql"),
+                serde_json::json!({ "id": id, "page": page, "per_page": per_page }),
+            )
+            .await?;
+
+        let mut recommendations = Vec::new();
+
+        if let Some(nodes) = result["data"]["Media"]["recommendations"]["nodes"].as_array() {
+            for node in nodes {
+                // The recommended media is null when it was deleted.
+                if node["mediaRecommendation"].is_null() {
+                    continue;
+                }
+                if self.hide_adult_content
+                    && node["mediaRecommendation"]["isAdult"].as_bool() == Some(true)
+                {
+                    continue;
+                }
+
+                recommendations.push(Recommendation {
+                    id: node["id"].as_i64().ok_or(Error::InvalidId)?,
+                    media: Media::from_node(&node["mediaRecommendation"], self)?,
+                    rating: node["rating"].as_i64().unwrap_or_default() as i32,
+                    user_rating: node["userRating"].as_str().map(RecommendationRating::from),
                 });
             }
-
-            return Some(vec);
         }
 
-        None
+        Ok(recommendations)
     }
 
-    /// Send a request to the AniList API.
+    /// Get a page of the reviews of a media.
     ///
     /// # Arguments
     ///
-    /// * `media_type` - The type of media to request.
-    /// * `action` - The action to perform.
-    /// * `variables` - The variables to send with the request.
+    /// * `id` - The ID of the media.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of reviews to get per page.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails.
-    async fn request(
+    pub(crate) async fn get_reviews(
         &self,
-        media_type: MediaType,
-        action: Action,
-        variables: serde_json::Value,
-    ) -> std::result::Result<serde_json::Value, reqwest::Error> {
-        let query = Client::get_query(media_type, action).unwrap();
-        let json = serde_json::json!({"query": query, "variables": variables});
-        let mut body = reqwest::Client::new()
-            .post("https://graphql.anilist.co/")
-            .header("Content-Type", "application/json")
-            .header("Accept", "application/json")
-            .timeout(self.timeout)
-            .body(json.to_string());
+        id: i64,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Vec<Review>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_reviews.graphql"),
+                serde_json::json!({ "id": id, "page": page, "per_page": per_page }),
+            )
+            .await?;
 
-        if let Some(token) = &self.api_token {
-            body = body.bearer_auth(token);
-        }
+        let mut reviews = Vec::new();
 
-        let response = body.send().await?.text().await?;
-        let result = serde_json::from_str::<serde_json::Value>(&response).unwrap();
+        if let Some(nodes) = result["data"]["Media"]["reviews"]["nodes"].as_array() {
+            for node in nodes {
+                reviews.push(Review::from_node(node, self)?);
+            }
+        }
 
-        Ok(result)
+        Ok(reviews)
     }
 
-    /// Get the GraphQL query for a specific media type.
+    /// Get a page of the favourites of a user, for a single category.
     ///
     /// # Arguments
     ///
-    /// * `media_type` - The type of media to get the query for.
-    /// * `action` - The action to perform.
+    /// * `id` - The ID of the user.
+    /// * `category` - The category of the favourites.
+    /// * `page` - The page number to get.
     ///
     /// # Errors
     ///
-    /// Returns an error if the media type is not valid.
-    fn get_query(media_type: MediaType, action: Action) -> Result<String> {
-        let graphql_query = match action {
-            Action::Get => {
-                match media_type {
-                    MediaType::Anime => include_str!("../queries/get_anime.graphql").to_string(),
-                    MediaType::Manga => include_str!("../queries/get_manga.graphql").to_string(),
-                    MediaType::Character => {
-                        include_str!("../queries/get_character.graphql").to_string()
-                    }
-                    MediaType::User => include_str!("../queries/get_user.graphql").to_string(),
-                    MediaType::Person => include_str!("../queries/get_person.graphql").to_string(),
-                    // MediaType::Studio => include_str!("../queries/get_studio.graphql").to_string(),
-                    _ => unimplemented!(),
-                }
-            }
-            Action::Search => {
-                match media_type {
-                    MediaType::Anime => include_str!("../queries/search_anime.graphql").to_string(),
-                    MediaType::Manga => include_str!("../queries/search_manga.graphql").to_string(),
-                    // MediaType::Character => {
-                    //     include_str!("../queries/search_character.graphql").to_string()
-                    // }
-                    MediaType::User => include_str!("../queries/search_user.graphql").to_string(),
-                    // MediaType::Person => {
-                    //     include_str!("../queries/search_person.graphql").to_string()
-                    // }
-                    // MediaType::Studio => include_str!("../queries/search_studio.graphql").to_string(),
-                    _ => unimplemented!(),
-                }
-            }
-        };
+    /// Returns an error if the request fails.
+    pub(crate) async fn get_user_favourites(
+        &self,
+        id: i64,
+        category: FavouriteCategory,
+        page: u16,
+    ) -> Result<Favourites> {
+        let result = self
+            .send(
+                include_str!("../queries/get_user_favourites.graphql"),
+                serde_json::json!({ "id": id, "page": page, category.key(): true }),
+            )
+            .await?;
 
-        Ok(graphql_query)
+        Favourites::from_node(&result["data"]["User"]["favourites"], self)
     }
-}
 
-impl Default for Client {
-    fn default() -> Self {
-        Client {
-            api_token: None,
-            timeout: Duration::from_secs(20),
-        }
+    /// Get a page of the characters voiced by a person.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub(crate) async fn get_person_characters(
+        &self,
+        id: i64,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Vec<Character>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_person_characters.graphql"),
+                serde_json::json!({ "id": id, "page": page, "per_page": per_page }),
+            )
+            .await?;
+
+        Ok(
+            parse::deserialize::<CharacterConnection>(&result, "data.Staff.characters")?
+                .characters(),
+        )
     }
-}
 
-/// Represents an action that can be performed by the client.
-///
-/// The `Action` enum defines various actions that the client can perform,
-/// such as getting media by ID or searching for media.
-enum Action {
-    /// Get media by ID.
-    Get,
-    /// Search for media.
-    Search,
-}
+    /// Get a page of the media a person worked on.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of media to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub(crate) async fn get_person_media(
+        &self,
+        id: i64,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Vec<Media>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_person_media.graphql"),
+                serde_json::json!({ "id": id, "page": page, "per_page": per_page }),
+            )
+            .await?;
 
-#[cfg(test)]
-mod tests {
-    use std::time::Duration;
+        Ok(parse::deserialize::<MediaConnection>(&result, "data.Staff.staffMedia")?.media(self))
+    }
 
-    use super::*;
+    /// Get a character by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let character = client.get_character(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_character(&self, id: i64) -> Result<Character> {
+        let data = self
+            .request(
+                MediaType::Character,
+                Action::Get,
+                serde_json::json!({ "id": id }),
+            )
+            .await?;
 
-    #[test]
-    fn test_with_timeout() {
-        let duration = Duration::from_secs(30);
-        let client = Client::with_timeout(duration);
+        let mut character: Character = parse::deserialize(&data, "data.Character")?;
+        character.client = self.clone();
+        character.is_full_loaded = true;
+        character.description_format = self.description_format;
 
-        assert_eq!(client.timeout, duration);
-        assert!(client.api_token.is_none());
+        Ok(character)
     }
 
-    #[test]
-    fn test_with_token() {
-        let api_token = "test_token";
-        let client = Client::with_token(api_token);
+    /// Get a character by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let character = client.get_char(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_char(&self, id: i64) -> Result<Character> {
+        self.get_character(id).await
+    }
 
-        assert_eq!(client.timeout, Duration::from_secs(20));
-        assert_eq!(client.api_token, Some(api_token.to_string()));
+    /// Get a user by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let user = client.get_user(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user(&self, id: i64) -> Result<User> {
+        self.get_user_by(UserIdentifier::Id(id)).await
     }
 
-    #[test]
-    fn test_timeout() {
-        let initial_duration = Duration::from_secs(30);
-        let new_duration = Duration::from_secs(60);
-        let client = Client::with_timeout(initial_duration).timeout(new_duration);
+    /// Get a user by its ID or by its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - The ID or the name of the user. Numeric strings
+    ///   are taken as IDs, use `UserIdentifier::Name` to force a name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use rust_anilist::models::UserIdentifier;
+    ///
+    /// let user = client.get_user_by("andrielfr").await?;
+    /// let user = client.get_user_by(5375822).await?;
+    /// let user = client
+    ///     .get_user_by(UserIdentifier::Name("1234".to_string()))
+    ///     .await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_by<I: Into<UserIdentifier>>(&self, identifier: I) -> Result<User> {
+        let data = self
+            .request(MediaType::User, Action::Get, identifier.into().variables())
+            .await?;
 
-        assert_eq!(client.timeout, new_duration);
+        let mut user: User = parse::deserialize(&data, "data.User")?;
+        user.favourites = Favourites::from_node(&data["data"]["User"]["favourites"], self)?;
+        user.client = self.clone();
+        user.is_full_loaded = true;
+
+        Ok(user)
     }
 
-    #[test]
-    fn test_token() {
-        let initial_token = "initial_token";
-        let new_token = "new_token";
-        let client = Client::with_token(initial_token).token(new_token);
+    /// Get a review by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the review.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let review = client.get_review(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_review(&self, id: i64) -> Result<Review> {
+        let result = self
+            .send(
+                include_str!("../queries/get_review.graphql"),
+                serde_json::json!({ "id": id }),
+            )
+            .await?;
 
-        assert_eq!(client.api_token, Some(new_token.to_string()));
+        Review::from_node(&result["data"]["Review"], self)
+    }
+
+    /// Search for forum threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `term` - The term to search for.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of threads to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let threads = client.search_threads("Frieren Episode 1", 1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_threads(
+        &self,
+        term: &str,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Thread>> {
+        let result = self
+            .send(
+                include_str!("../queries/search_threads.graphql"),
+                serde_json::json!({ "search": term, "page": page, "per_page": per_page }),
+            )
+            .await?;
+
+        let mut threads = Vec::new();
+
+        if let Some(nodes) = result["data"]["Page"]["threads"].as_array() {
+            for node in nodes {
+                threads.push(Thread::from_node(node, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: threads,
+        })
+    }
+
+    /// Get a forum thread by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let thread = client.get_thread(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_thread(&self, id: i64) -> Result<Thread> {
+        let result = self
+            .send(
+                include_str!("../queries/get_thread.graphql"),
+                serde_json::json!({ "id": id }),
+            )
+            .await?;
+
+        Thread::from_node(&result["data"]["Thread"], self)
+    }
+
+    /// Get a page of the comments of a forum thread, with their replies.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread_id` - The ID of the thread.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of comments to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let comments = client.get_thread_comments(1, 1, 25).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_thread_comments(
+        &self,
+        thread_id: i64,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<ThreadComment>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_thread_comments.graphql"),
+                serde_json::json!({ "threadId": thread_id, "page": page, "per_page": per_page }),
+            )
+            .await?;
+
+        let mut comments = Vec::new();
+
+        if let Some(nodes) = result["data"]["Page"]["threadComments"].as_array() {
+            for node in nodes {
+                comments.push(ThreadComment::from_node(node, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: comments,
+        })
+    }
+
+    /// Get a user by its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let user = client.get_user_by_name("andrielfr").await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_by_name<N: ToString>(&self, name: N) -> Result<User> {
+        self.get_user_by(UserIdentifier::Name(name.to_string()))
+            .await
+    }
+
+    /// Get a person by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let person = client.get_person(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_person(&self, id: i64) -> Result<Person> {
+        let data = self
+            .request(
+                MediaType::Person,
+                Action::Get,
+                serde_json::json!({ "id": id }),
+            )
+            .await?;
+
+        let mut person: Person = parse::deserialize(&data, "data.Staff")?;
+        person.client = self.clone();
+        person.is_full_loaded = true;
+        person.description_format = self.description_format;
+        person
+            .characters
+            .set_description_format(self.description_format);
+
+        Ok(person)
+    }
+
+    /// Get a studio by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the studio.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let studio = client.get_studio(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_studio(&self, id: i64) -> Result<Studio> {
+        let data = self
+            .request(
+                MediaType::Studio,
+                Action::Get,
+                serde_json::json!({ "id": id }),
+            )
+            .await?;
+
+        let mut studio: Studio = parse::deserialize(&data, "data.Studio")?;
+        studio.client = self.clone();
+        studio.is_full_loaded = true;
+
+        Ok(studio)
+    }
+
+    /// Search for animes.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the anime to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let animes = client.search_anime("Naruto", 1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_anime(&self, title: &str, page: u16, limit: u16) -> Result<Page<Anime>> {
+        self.search_anime_with(AnimeSearchOptions::new().search(title), page, limit)
+            .await
+    }
+
+    /// Search for animes matching the given filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The filters of the search.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use rust_anilist::models::{AnimeSearchOptions, MediaSort, Season};
+    ///
+    /// let options = AnimeSearchOptions::new()
+    ///     .genre_in(["Action"])
+    ///     .season(Season::Spring)
+    ///     .season_year(2024)
+    ///     .sort([MediaSort::ScoreDesc]);
+    /// let animes = client.search_anime_with(options, 1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_anime_with(
+        &self,
+        options: AnimeSearchOptions,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Anime>> {
+        let mut variables = serde_json::to_value(options)?;
+        variables["page"] = serde_json::json!(page);
+        variables["per_page"] = serde_json::json!(per_page);
+
+        let result = self
+            .request(MediaType::Anime, Action::Search, variables)
+            .await?;
+
+        let mut animes = Vec::new();
+
+        if let Some(medias) = result["data"]["Page"]["media"].as_array() {
+            for media in medias.iter() {
+                animes.push(Anime::from_node(media, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: animes,
+        })
+    }
+
+    /// Stream the animes matching a title, page by page.
+    ///
+    /// Pages are fetched lazily as the stream is polled, until the last
+    /// page. Each request goes through the rate limiter, if enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the anime to search.
+    /// * `per_page` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error and ends if a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut animes = std::pin::pin!(client.search_anime_stream("Naruto", 50));
+    ///
+    /// while let Some(anime) = animes.try_next().await? {
+    ///     println!("{}", anime.id);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_anime_stream(
+        &self,
+        title: &str,
+        per_page: u16,
+    ) -> impl Stream<Item = Result<Anime>> {
+        let client = self.clone();
+        let title = title.to_string();
+
+        stream::paginate(move |page| {
+            let client = client.clone();
+            let title = title.clone();
+
+            async move { client.search_anime(&title, page, per_page).await }
+        })
+    }
+
+    /// Browse the animes of a season.
+    ///
+    /// # Arguments
+    ///
+    /// * `season` - The season of the animes.
+    /// * `year` - The year of the season.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use rust_anilist::models::Season;
+    ///
+    /// let animes = client.browse_anime(Season::Winter, 2025, 1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn browse_anime(
+        &self,
+        season: Season,
+        year: u32,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Anime>> {
+        let result = self
+            .request(
+                MediaType::Anime,
+                Action::Browse,
+                serde_json::json!({
+                    "season": season,
+                    "season_year": year,
+                    "page": page,
+                    "per_page": per_page,
+                }),
+            )
+            .await?;
+
+        let mut animes = Vec::new();
+
+        if let Some(medias) = result["data"]["Page"]["media"].as_array() {
+            for media in medias.iter() {
+                animes.push(Anime::from_node(media, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: animes,
+        })
+    }
+
+    /// Search for mangas.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the manga to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of mangas to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let mangas = client.search_manga("Naruto", 1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_manga(&self, title: &str, page: u16, limit: u16) -> Result<Page<Manga>> {
+        self.search_manga_with(MangaSearchOptions::new().search(title), page, limit)
+            .await
+    }
+
+    /// Search for mangas matching the given filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The filters of the search.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of mangas to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use rust_anilist::models::{CountryOfOrigin, MangaSearchOptions};
+    ///
+    /// let options = MangaSearchOptions::new().country_of_origin(CountryOfOrigin::SouthKorea);
+    /// let manhwas = client.search_manga_with(options, 1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_manga_with(
+        &self,
+        options: MangaSearchOptions,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Manga>> {
+        let mut variables = serde_json::to_value(options)?;
+        variables["page"] = serde_json::json!(page);
+        variables["per_page"] = serde_json::json!(per_page);
+
+        let result = self
+            .request(MediaType::Manga, Action::Search, variables)
+            .await?;
+
+        let mut mangas = Vec::new();
+
+        if let Some(medias) = result["data"]["Page"]["media"].as_array() {
+            for media in medias.iter() {
+                mangas.push(Manga::from_node(media, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: mangas,
+        })
+    }
+
+    /// Stream the mangas matching a title, page by page.
+    ///
+    /// Pages are fetched lazily as the stream is polled, until the last
+    /// page. Each request goes through the rate limiter, if enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the manga to search.
+    /// * `per_page` - The number of mangas to get per page.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error and ends if a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut mangas = std::pin::pin!(client.search_manga_stream("Naruto", 50));
+    ///
+    /// while let Some(manga) = mangas.try_next().await? {
+    ///     println!("{}", manga.id);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_manga_stream(
+        &self,
+        title: &str,
+        per_page: u16,
+    ) -> impl Stream<Item = Result<Manga>> {
+        let client = self.clone();
+        let title = title.to_string();
+
+        stream::paginate(move |page| {
+            let client = client.clone();
+            let title = title.clone();
+
+            async move { client.search_manga(&title, page, per_page).await }
+        })
+    }
+
+    /// Search for users.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the user to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of users to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let users = client.search_user("andrielfr", 1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_user(&self, name: &str, page: u16, limit: u16) -> Result<Page<User>> {
+        let result = self
+            .request(
+                MediaType::User,
+                Action::Search,
+                serde_json::json!({ "search": name, "page": page, "per_page": limit, }),
+            )
+            .await?;
+
+        let mut vec = Vec::new();
+
+        if let Some(users) = result["data"]["Page"]["users"].as_array() {
+            for user in users.iter() {
+                vec.push(User::from_node(user, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: vec,
+        })
+    }
+
+    /// Stream the users matching a name, page by page.
+    ///
+    /// Pages are fetched lazily as the stream is polled, until the last
+    /// page. Each request goes through the rate limiter, if enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the user to search.
+    /// * `per_page` - The number of users to get per page.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error and ends if a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut users = std::pin::pin!(client.search_user_stream("andrielfr", 50));
+    ///
+    /// while let Some(user) = users.try_next().await? {
+    ///     println!("{}", user.id);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_user_stream(
+        &self,
+        name: &str,
+        per_page: u16,
+    ) -> impl Stream<Item = Result<User>> {
+        let client = self.clone();
+        let name = name.to_string();
+
+        stream::paginate(move |page| {
+            let client = client.clone();
+            let name = name.clone();
+
+            async move { client.search_user(&name, page, per_page).await }
+        })
+    }
+
+    /// Search for characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the character to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let characters = client.search_character("Lelouch", 1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_character(
+        &self,
+        name: &str,
+        page: u16,
+        limit: u16,
+    ) -> Result<Page<Character>> {
+        let result = self
+            .request(
+                MediaType::Character,
+                Action::Search,
+                serde_json::json!({ "search": name, "page": page, "per_page": limit, }),
+            )
+            .await?;
+
+        let mut characters = Vec::new();
+
+        if let Some(nodes) = result["data"]["Page"]["characters"].as_array() {
+            for character in nodes.iter() {
+                characters.push(Character::from_node(character, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: characters,
+        })
+    }
+
+    /// Search for persons.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the person to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of persons to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let persons = client.search_person("Kana Hanazawa", 1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_person(&self, name: &str, page: u16, limit: u16) -> Result<Page<Person>> {
+        let result = self
+            .request(
+                MediaType::Person,
+                Action::Search,
+                serde_json::json!({ "search": name, "page": page, "per_page": limit, }),
+            )
+            .await?;
+
+        let mut persons = Vec::new();
+
+        if let Some(nodes) = result["data"]["Page"]["staff"].as_array() {
+            for person in nodes.iter() {
+                persons.push(Person::from_node(person, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: persons,
+        })
+    }
+
+    /// Get the characters whose birthday is today, the most favourited
+    /// first.
+    ///
+    /// The characters come with their name, image, date of birth and
+    /// number of favourites, so their age can be shown when the year of
+    /// birth is known.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let characters = client.get_birthday_characters(1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_birthday_characters(
+        &self,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Character>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_birthday_characters.graphql"),
+                serde_json::json!({ "page": page, "per_page": per_page }),
+            )
+            .await?;
+
+        let mut characters = Vec::new();
+
+        if let Some(nodes) = result["data"]["Page"]["characters"].as_array() {
+            for character in nodes.iter() {
+                characters.push(Character::from_node(character, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: characters,
+        })
+    }
+
+    /// Get the characters born on a day of the year, the most
+    /// favourited first.
+    ///
+    /// AniList cannot filter characters by their date of birth, so the
+    /// characters are fetched by decreasing number of favourites and
+    /// filtered on the client. Only the first `max_pages` pages of 50
+    /// characters are looked at, so less popular characters born on
+    /// that day are missed. Prefer `get_birthday_characters` for today.
+    ///
+    /// Characters born on February 29 are only returned for that day,
+    /// which is accepted whatever the year.
+    ///
+    /// # Arguments
+    ///
+    /// * `month` - The month of birth, from 1 to 12.
+    /// * `day` - The day of birth.
+    /// * `max_pages` - The maximum number of pages to look at.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the day does not exist, or if a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let characters = client.get_characters_with_birthday(6, 26, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_characters_with_birthday(
+        &self,
+        month: u32,
+        day: u32,
+        max_pages: u16,
+    ) -> Result<Vec<Character>> {
+        // 2000 is a leap year, so February 29 is a valid birthday.
+        if NaiveDate::from_ymd_opt(2000, month, day).is_none() {
+            return Err(Error::InvalidDate(format!("{:02}-{:02}", month, day)));
+        }
+
+        let mut characters = Vec::new();
+
+        for page in 1..=max_pages {
+            let result = self
+                .get_characters_by_favourites(page, MAX_PER_PAGE as u16)
+                .await?;
+            let has_next_page = result.has_next_page();
+
+            characters.extend(result.items.into_iter().filter(|character| {
+                character
+                    .date_of_birth
+                    .as_ref()
+                    .is_some_and(|date| date.month == Some(month) && date.day == Some(day))
+            }));
+
+            if !has_next_page {
+                break;
+            }
+        }
+
+        Ok(characters)
+    }
+
+    /// Get a page of the characters, the most favourited first.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    async fn get_characters_by_favourites(
+        &self,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Character>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_characters_by_favourites.graphql"),
+                serde_json::json!({ "page": page, "per_page": per_page }),
+            )
+            .await?;
+
+        let mut characters = Vec::new();
+
+        if let Some(nodes) = result["data"]["Page"]["characters"].as_array() {
+            for character in nodes.iter() {
+                characters.push(Character::from_node(character, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: characters,
+        })
+    }
+
+    /// Get the staff whose birthday is today, the most favourited first.
+    ///
+    /// The staff come with their name, image, date of birth and number
+    /// of favourites, so their age can be shown when the year of birth
+    /// is known.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of staff to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let staff = client.get_birthday_staff(1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_birthday_staff(&self, page: u16, per_page: u16) -> Result<Page<Person>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_birthday_staff.graphql"),
+                serde_json::json!({ "page": page, "per_page": per_page }),
+            )
+            .await?;
+
+        let mut persons = Vec::new();
+
+        if let Some(nodes) = result["data"]["Page"]["staff"].as_array() {
+            for person in nodes.iter() {
+                persons.push(Person::from_node(person, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: persons,
+        })
+    }
+
+    /// Search for studios.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the studio to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of studios to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let studios = client.search_studio("Kyoto Animation", 1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_studio(&self, name: &str, page: u16, limit: u16) -> Result<Page<Studio>> {
+        let result = self
+            .request(
+                MediaType::Studio,
+                Action::Search,
+                serde_json::json!({ "search": name, "page": page, "per_page": limit, }),
+            )
+            .await?;
+
+        let mut studios = Vec::new();
+
+        if let Some(nodes) = result["data"]["Page"]["studios"].as_array() {
+            for studio in nodes.iter() {
+                studios.push(Studio::from_node(studio, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: studios,
+        })
+    }
+
+    /// Get the trending animes.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let animes = client.get_trending_anime(1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_trending_anime(&self, page: u16, per_page: u16) -> Result<Page<Anime>> {
+        let result = self
+            .list_media(MediaType::Anime, MediaSort::TrendingDesc, page, per_page)
+            .await?;
+
+        let mut animes = Vec::new();
+
+        if let Some(medias) = result["data"]["Page"]["media"].as_array() {
+            for media in medias.iter() {
+                animes.push(Anime::from_node(media, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: animes,
+        })
+    }
+
+    /// Get the trending mangas.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of mangas to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let mangas = client.get_trending_manga(1, 10).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_trending_manga(&self, page: u16, per_page: u16) -> Result<Page<Manga>> {
+        let result = self
+            .list_media(MediaType::Manga, MediaSort::TrendingDesc, page, per_page)
+            .await?;
+
+        let mut mangas = Vec::new();
+
+        if let Some(medias) = result["data"]["Page"]["media"].as_array() {
+            for media in medias.iter() {
+                mangas.push(Manga::from_node(media, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: mangas,
+        })
+    }
+
+    /// Get the top medias of a type, in the given sort order.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of the medias, either anime or manga.
+    /// * `sort` - The order in which the medias are sorted.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of medias to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the media type is neither anime nor manga, or
+    /// if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use rust_anilist::models::{MediaSort, MediaType};
+    ///
+    /// let medias = client
+    ///     .get_top_media(MediaType::Anime, MediaSort::ScoreDesc, 1, 10)
+    ///     .await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_top_media(
+        &self,
+        media_type: MediaType,
+        sort: MediaSort,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Media>> {
+        let result = self.list_media(media_type, sort, page, per_page).await?;
+
+        let mut medias = Vec::new();
+
+        if let Some(nodes) = result["data"]["Page"]["media"].as_array() {
+            for node in nodes.iter() {
+                medias.push(Media::from_node(node, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: medias,
+        })
+    }
+
+    /// Get a page of the daily statistics of a media.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_id` - The ID of the media.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of days to get per page.
+    /// * `sort` - The order in which the days are sorted, newest first
+    ///   by default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use rust_anilist::models::MediaTrendSort;
+    ///
+    /// let trends = client
+    ///     .get_media_trends(1, 1, 25, MediaTrendSort::default())
+    ///     .await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_media_trends(
+        &self,
+        media_id: i64,
+        page: u16,
+        per_page: u16,
+        sort: MediaTrendSort,
+    ) -> Result<Page<MediaTrend>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_media_trends.graphql"),
+                serde_json::json!({
+                    "mediaId": media_id,
+                    "sort": [sort],
+                    "page": page,
+                    "per_page": per_page,
+                }),
+            )
+            .await?;
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: parse::deserialize(&result, "data.Page.mediaTrends")?,
+        })
+    }
+
+    /// Get the airing schedules within a time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The start of the range, as a Unix timestamp.
+    /// * `end` - The end of the range, as a Unix timestamp.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of airing schedules to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let schedules = client
+    ///     .get_airing_schedule(1735689600, 1736294400, 1, 50)
+    ///     .await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_airing_schedule(
+        &self,
+        start: i64,
+        end: i64,
+        page: u16,
+        limit: u16,
+    ) -> Result<Page<AiringSchedule>> {
+        let result = self
+            .send(
+                include_str!("../queries/get_airing_schedule.graphql"),
+                serde_json::json!({ "start": start, "end": end, "page": page, "per_page": limit, }),
+            )
+            .await?;
+
+        let mut schedules = Vec::new();
+
+        if let Some(nodes) = result["data"]["Page"]["airingSchedules"].as_array() {
+            for (index, node) in nodes.iter().enumerate() {
+                let path = format!("data.Page.airingSchedules.{index}");
+                let mut schedule: AiringSchedule = parse::deserialize(&result, &path)?;
+                if node["media"].is_object() {
+                    schedule.media = Some(Box::new(Anime::from_node(&node["media"], self)?));
+                }
+
+                schedules.push(schedule);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: schedules,
+        })
+    }
+
+    /// Get the airing schedules of a week, grouped by weekday.
+    ///
+    /// Every page of the week is fetched, each request going through the
+    /// rate limiter, if enabled. The schedules are grouped by their
+    /// weekday in the local time zone and sorted by airing time. The
+    /// weekdays are ordered from Monday to Sunday, weekdays without any
+    /// airing being left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `week_start` - The start of the week.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let schedule = client.get_weekly_schedule(chrono::Utc::now()).await?;
+    ///
+    /// for (weekday, schedules) in schedule {
+    ///     println!("{weekday}: {} episodes", schedules.len());
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_weekly_schedule(
+        &self,
+        week_start: DateTime<Utc>,
+    ) -> Result<Vec<(Weekday, Vec<AiringSchedule>)>> {
+        let start = week_start.timestamp();
+        // The bounds of the query are exclusive.
+        let (start, end) = (start - 1, start + 7 * 24 * 60 * 60);
+
+        let schedules: Vec<AiringSchedule> = stream::paginate(|page| {
+            self.get_airing_schedule(start, end, page, MAX_PER_PAGE as u16)
+        })
+        .try_collect()
+        .await?;
+
+        Ok(group_by_weekday(schedules, &Local))
+    }
+
+    /// Get all the genres available on AniList.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let genres = client.get_genres().await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_genres(&self) -> Result<Vec<String>> {
+        let data = self
+            .send(
+                include_str!("../queries/get_genres.graphql"),
+                serde_json::json!({}),
+            )
+            .await?;
+
+        parse::deserialize(&data, "data.GenreCollection")
+    }
+
+    /// Get all the media tags available on AniList.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let tags = client.get_tags().await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_tags(&self) -> Result<Vec<Tag>> {
+        let data = self
+            .send(
+                include_str!("../queries/get_tags.graphql"),
+                serde_json::json!({}),
+            )
+            .await?;
+
+        parse::deserialize(&data, "data.MediaTagCollection")
+    }
+
+    /// Get the media list of a user.
+    ///
+    /// The entries are returned grouped by the list they belong to,
+    /// which is either a status list or a custom list of the user.
+    ///
+    /// AniList truncates large lists, so the list is requested in chunks
+    /// of 500 entries until it is complete.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user.
+    /// * `media_type` - The type of the list, either anime or manga.
+    /// * `status` - Only return entries with this status, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the media type is not anime or manga, or if
+    /// the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use rust_anilist::models::MediaType;
+    ///
+    /// let lists = client
+    ///     .get_user_media_list(5375822, MediaType::Anime, None)
+    ///     .await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_media_list(
+        &self,
+        user_id: i64,
+        media_type: MediaType,
+        status: Option<MediaListStatus>,
+    ) -> Result<Vec<MediaListGroup>> {
+        if !matches!(media_type, MediaType::Anime | MediaType::Manga) {
+            return Err(Error::InvalidMediaType(media_type));
+        }
+
+        let mut variables = serde_json::json!({ "userId": user_id, "type": media_type });
+        if let Some(status) = status {
+            variables["status"] = serde_json::to_value(status)?;
+        }
+
+        let mut groups: Vec<MediaListGroup> = Vec::new();
+        let mut seen = HashSet::new();
+
+        for chunk in 1.. {
+            variables["chunk"] = chunk.into();
+            variables["perChunk"] = MEDIA_LIST_CHUNK_SIZE.into();
+
+            let data = self
+                .send(
+                    include_str!("../queries/get_media_list.graphql"),
+                    variables.clone(),
+                )
+                .await?;
+            let collection = &data["data"]["MediaListCollection"];
+
+            if let Some(lists) = collection["lists"].as_array() {
+                for (index, list) in lists.iter().enumerate() {
+                    let path = format!("data.MediaListCollection.lists.{index}");
+                    let group: MediaListGroup = parse::deserialize(&data, &path)?;
+
+                    // A list spanning several chunks is returned once per
+                    // chunk, so its entries are merged into the same group.
+                    let position = groups
+                        .iter()
+                        .position(|other| {
+                            other.name == group.name
+                                && other.is_custom_list == group.is_custom_list
+                                && other.status == group.status
+                        })
+                        .unwrap_or_else(|| {
+                            groups.push(group);
+                            groups.len() - 1
+                        });
+
+                    if let Some(entries) = list["entries"].as_array() {
+                        for (index, entry) in entries.iter().enumerate() {
+                            let path = format!("{path}.entries.{index}");
+                            let mut media_list_entry: MediaListEntry =
+                                parse::deserialize(&data, &path)?;
+
+                            if !seen.insert((position, media_list_entry.id)) {
+                                continue;
+                            }
+
+                            media_list_entry.media = Some(Media::from_node(&entry["media"], self)?);
+                            groups[position].entries.push(media_list_entry);
+                        }
+                    }
+                }
+            }
+
+            if !collection["hasNextChunk"].as_bool().unwrap_or(false) {
+                break;
+            }
+        }
+
+        Ok(groups)
+    }
+
+    /// Compare the media lists of two users.
+    ///
+    /// The comparison contains the media both users have on their
+    /// lists, the media only one of them has, and the correlation of
+    /// the scores they both gave, converted to a common scale through
+    /// their score formats.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_a` - The ID of the first user.
+    /// * `user_b` - The ID of the second user.
+    /// * `media_type` - The type of the lists, either anime or manga.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the media type is not anime or manga, or if
+    /// a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use rust_anilist::models::MediaType;
+    ///
+    /// let comparison = client
+    ///     .compare_lists(5375822, 5148470, MediaType::Anime)
+    ///     .await?;
+    /// let correlation = comparison.correlation;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn compare_lists(
+        &self,
+        user_a: i64,
+        user_b: i64,
+        media_type: MediaType,
+    ) -> Result<ListComparison> {
+        let list_a = self
+            .get_user_media_list(user_a, media_type.clone(), None)
+            .await?;
+        let list_b = self.get_user_media_list(user_b, media_type, None).await?;
+        let format_a = self.get_score_format(user_a).await?;
+        let format_b = self.get_score_format(user_b).await?;
+
+        Ok(ListComparison::new(&list_a, format_a, &list_b, format_b))
+    }
+
+    /// Get the score format of a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    async fn get_score_format(&self, user_id: i64) -> Result<ScoreFormat> {
+        let data = self
+            .send(
+                include_str!("../queries/get_user_score_format.graphql"),
+                serde_json::json!({ "id": user_id }),
+            )
+            .await?;
+
+        parse::deserialize(&data, "data.User.mediaListOptions.scoreFormat")
+    }
+
+    /// Get the entry of a media on the media list of a user.
+    ///
+    /// This is much cheaper than fetching the whole media list when only
+    /// the progress on a single media is needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user.
+    /// * `media_id` - The ID of the media.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the user does not exist, or an error
+    /// if the request fails. A media that is not on the list of an
+    /// existing user is not an error, `None` is returned instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// if let Some(entry) = client.get_media_list_entry(5375822, 1).await? {
+    ///     println!("{:?}", entry.progress);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_media_list_entry(
+        &self,
+        user_id: i64,
+        media_id: i64,
+    ) -> Result<Option<MediaListEntry>> {
+        let data = self
+            .send(
+                include_str!("../queries/get_media_list_entry.graphql"),
+                serde_json::json!({ "userId": user_id, "mediaId": media_id }),
+            )
+            .await?;
+
+        // The entry is listed from a page, which is empty instead of not
+        // found, so a missing user is told apart from a missing entry.
+        if data["data"]["User"].is_null() {
+            return Err(Error::NotFound);
+        }
+
+        let entry = &data["data"]["Page"]["mediaList"][0];
+        if entry.is_null() {
+            return Ok(None);
+        }
+
+        let mut media_list_entry: MediaListEntry =
+            parse::deserialize(&data, "data.Page.mediaList.0")?;
+        media_list_entry.media = Some(Media::from_node(&entry["media"], self)?);
+
+        Ok(Some(media_list_entry))
+    }
+
+    /// Toggle the favourite status of an item for the authenticated
+    /// user, returning whether it is a favourite afterwards.
+    ///
+    /// The new state is requested from the API after the toggle, since
+    /// the state known by a model may be stale.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The type of the item to toggle.
+    /// * `id` - The ID of the item to toggle.
+    /// * `is_favourite_blocked` - Whether the item is blocked from being
+    ///   a favourite, if known.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FavouriteBlocked` if the item is blocked from
+    /// being a favourite, or an error if the client has no API token,
+    /// both without sending any request. Returns an error if the item
+    /// type cannot be favourited or if the request fails.
+    pub(crate) async fn toggle_favourite_state(
+        &self,
+        kind: MediaType,
+        id: i64,
+        is_favourite_blocked: Option<bool>,
+    ) -> Result<bool> {
+        if is_favourite_blocked == Some(true) {
+            return Err(Error::FavouriteBlocked {
+                media_type: kind,
+                id,
+            });
+        }
+
+        self.toggle_favourite(kind.clone(), id).await?;
+
+        let (flag, root) = match kind {
+            MediaType::Anime | MediaType::Manga => ("media", "Media"),
+            MediaType::Character => ("character", "Character"),
+            MediaType::Person => ("staff", "Staff"),
+            _ => ("studio", "Studio"),
+        };
+        let data = self
+            .send(
+                include_str!("../queries/get_is_favourite.graphql"),
+                serde_json::json!({ "id": id, flag: true }),
+            )
+            .await?;
+
+        Ok(data["data"][root]["isFavourite"]
+            .as_bool()
+            .unwrap_or_default())
+    }
+
+    /// Get the entry of a media on the authenticated user's media list.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_id` - The ID of the media.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token or if the
+    /// request fails.
+    pub(crate) async fn get_viewer_media_list_entry(
+        &self,
+        media_id: i64,
+    ) -> Result<Option<MediaListEntry>> {
+        if self.api_token.is_none() {
+            return Err(Error::Unauthorized);
+        }
+
+        let data = self
+            .send(
+                include_str!("../queries/get_viewer_media_list_entry.graphql"),
+                serde_json::json!({ "mediaId": media_id }),
+            )
+            .await?;
+
+        parse::deserialize(&data, "data.Media.mediaListEntry")
+    }
+
+    /// Save an entry of the authenticated user's media list.
+    ///
+    /// Creates the entry if the media is not on the list yet, or updates
+    /// it otherwise. Only the fields set in `entry` are sent to the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_id` - The ID of the media to save.
+    /// * `entry` - The changes to apply to the entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token or if the
+    /// request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use rust_anilist::models::{MediaListEntryUpdate, MediaListStatus};
+    ///
+    /// let entry = client
+    ///     .save_media_list_entry(
+    ///         1,
+    ///         MediaListEntryUpdate::new().status(MediaListStatus::Current).progress(3),
+    ///     )
+    ///     .await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn save_media_list_entry(
+        &self,
+        media_id: i64,
+        entry: MediaListEntryUpdate,
+    ) -> Result<MediaListEntry> {
+        let mut variables = serde_json::to_value(entry)?;
+        variables["mediaId"] = serde_json::json!(media_id);
+
+        let data = self
+            .mutate(
+                include_str!("../queries/save_media_list_entry.graphql"),
+                variables,
+            )
+            .await;
+        // Invalidated once the mutation is done, so a response fetched
+        // while it was in flight is not kept. A failed mutation may have
+        // been applied anyway, so the entry is invalidated either way.
+        self.invalidate(MediaType::Anime, media_id);
+        self.invalidate(MediaType::Manga, media_id);
+        let data = data?;
+
+        parse::deserialize(&data, "data.SaveMediaListEntry")
+    }
+
+    /// Delete an entry of the authenticated user's media list.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry_id` - The ID of the entry to delete.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token, if the entry
+    /// cannot be deleted or if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let deleted = client.delete_media_list_entry(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_media_list_entry(&self, entry_id: i64) -> Result<bool> {
+        let data = self
+            .mutate(
+                include_str!("../queries/delete_media_list_entry.graphql"),
+                serde_json::json!({ "id": entry_id }),
+            )
+            .await?;
+
+        Ok(data["data"]["DeleteMediaListEntry"]["deleted"]
+            .as_bool()
+            .unwrap_or_default())
+    }
+
+    /// Toggle the favourite status of a media, character, person or
+    /// studio for the authenticated user.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The type of the item to toggle.
+    /// * `id` - The ID of the item to toggle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token, if the item
+    /// type cannot be favourited, if the item is blocked from being a
+    /// favourite or if the request fails. The `toggle_favourite` methods
+    /// of the models return `Error::FavouriteBlocked` for blocked items
+    /// without sending any request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use rust_anilist::models::MediaType;
+    ///
+    /// client.toggle_favourite(MediaType::Anime, 1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn toggle_favourite(&self, kind: MediaType, id: i64) -> Result<()> {
+        let variable = match kind {
+            MediaType::Anime => "animeId",
+            MediaType::Manga => "mangaId",
+            MediaType::Character => "characterId",
+            MediaType::Person => "staffId",
+            MediaType::Studio => "studioId",
+            _ => return Err(Error::InvalidMediaType(kind)),
+        };
+
+        let result = self
+            .mutate(
+                include_str!("../queries/toggle_favourite.graphql"),
+                serde_json::json!({ variable: id }),
+            )
+            .await;
+        self.invalidate(kind, id);
+        result?;
+
+        Ok(())
+    }
+
+    /// Toggle whether the authenticated user likes an activity, an
+    /// activity reply, a thread or a thread comment.
+    ///
+    /// Returns the updated number of likes of the item, if the API
+    /// provides it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the item to like or unlike.
+    /// * `like_type` - The type of the item.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token or if the
+    /// request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// use rust_anilist::models::LikeableType;
+    ///
+    /// let likes = client.toggle_like(1, LikeableType::Activity).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn toggle_like(&self, id: i64, like_type: LikeableType) -> Result<Option<u32>> {
+        let data = self
+            .mutate(
+                include_str!("../queries/toggle_like.graphql"),
+                serde_json::json!({ "id": id, "type": like_type }),
+            )
+            .await?;
+
+        Ok(data["data"]["ToggleLikeV2"]["likeCount"]
+            .as_u64()
+            .map(|count| count as u32))
+    }
+
+    /// Get a page of the users following a user.
+    ///
+    /// When the client is authenticated, the users tell whether the
+    /// authenticated user follows them and is followed by them.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of users to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let followers = client.get_followers(5375822, 1, 25).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_followers(
+        &self,
+        user_id: i64,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<User>> {
+        self.get_follows(
+            include_str!("../queries/get_followers.graphql"),
+            "followers",
+            user_id,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    /// Get a page of the users a user follows.
+    ///
+    /// When the client is authenticated, the users tell whether the
+    /// authenticated user follows them and is followed by them.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of users to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let following = client.get_following(5375822, 1, 25).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_following(
+        &self,
+        user_id: i64,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<User>> {
+        self.get_follows(
+            include_str!("../queries/get_following.graphql"),
+            "following",
+            user_id,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    /// Toggle whether the authenticated user follows a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user to follow or unfollow.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token, if the request
+    /// fails or if the response is malformed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let is_following = client.toggle_follow(5375822).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn toggle_follow(&self, user_id: i64) -> Result<bool> {
+        let data = self
+            .mutate(
+                include_str!("../queries/toggle_follow.graphql"),
+                serde_json::json!({ "userId": user_id }),
+            )
+            .await;
+        self.invalidate(MediaType::User, user_id);
+        let data = data?;
+
+        parse::deserialize(&data, "data.ToggleFollow.isFollowing")
+    }
+
+    /// Get the number of unread notifications of the authenticated user.
+    ///
+    /// Only the count is requested, so it is cheap enough to be polled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token or if the
+    /// request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let unread = client.get_unread_notification_count().await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_unread_notification_count(&self) -> Result<i32> {
+        if self.api_token.is_none() {
+            return Err(Error::Unauthorized);
+        }
+
+        let data = self
+            .send(
+                include_str!("../queries/get_unread_notification_count.graphql"),
+                serde_json::json!({}),
+            )
+            .await?;
+
+        Ok(data["data"]["Viewer"]["unreadNotificationCount"]
+            .as_i64()
+            .unwrap_or_default() as i32)
+    }
+
+    /// Mark all the notifications of the authenticated user as read.
+    ///
+    /// AniList resets the unread count when the notifications are
+    /// queried with `resetNotificationCount`, so a single notification
+    /// is requested and discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token or if the
+    /// request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// client.mark_notifications_read().await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn mark_notifications_read(&self) -> Result<()> {
+        if self.api_token.is_none() {
+            return Err(Error::Unauthorized);
+        }
+
+        self.send(
+            include_str!("../queries/mark_notifications_read.graphql"),
+            serde_json::json!({}),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get a page of the followers or of the followed users of a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL query to send.
+    /// * `field` - The field of the page holding the users.
+    /// * `user_id` - The ID of the user.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of users to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    async fn get_follows(
+        &self,
+        query: &str,
+        field: &str,
+        user_id: i64,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<User>> {
+        let result = self
+            .send(
+                query,
+                serde_json::json!({ "userId": user_id, "page": page, "per_page": per_page }),
+            )
+            .await?;
+
+        let mut users = Vec::new();
+
+        if let Some(nodes) = result["data"]["Page"][field].as_array() {
+            for node in nodes {
+                users.push(User::from_node(node, self)?);
+            }
+        }
+
+        Ok(Page {
+            page_info: parse::deserialize(&result, "data.Page.pageInfo")?,
+            items: users,
+        })
+    }
+
+    /// Send a request to the AniList API.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of media to request.
+    /// * `action` - The action to perform.
+    /// * `variables` - The variables to send with the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    async fn request(
+        &self,
+        media_type: MediaType,
+        action: Action,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let query = Client::get_query(media_type.clone(), action)?;
+
+        // Only the requests by ID are cached, fetching a single model.
+        let key = match (action, &media_type, variables.as_object()) {
+            (
+                Action::Get,
+                MediaType::Anime
+                | MediaType::Manga
+                | MediaType::Character
+                | MediaType::Person
+                | MediaType::User,
+                Some(object),
+            ) if object.len() == 1 => object
+                .get("id")
+                .and_then(serde_json::Value::as_i64)
+                .map(|id| MediaKey::new(media_type, id)),
+            _ => None,
+        };
+        let cache = self.cache.as_ref().zip(key);
+
+        if let Some((cache, key)) = &cache {
+            if let Some(data) = cache.get(self.token_hash(), key, self.description_format) {
+                return Ok(data);
+            }
+        }
+
+        let data = self.send(query, variables).await?;
+
+        // Partial responses are not cached.
+        if let Some((cache, key)) = cache.filter(|_| data["errors"].is_null()) {
+            cache.insert(
+                self.token_hash(),
+                key,
+                self.description_format,
+                data.clone(),
+            );
+        }
+
+        Ok(data)
+    }
+
+    /// Send a request listing the medias of a type in the given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of the medias, either anime or manga.
+    /// * `sort` - The order in which the medias are sorted.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of medias to get per page.
+    async fn list_media(
+        &self,
+        media_type: MediaType,
+        sort: MediaSort,
+        page: u16,
+        per_page: u16,
+    ) -> Result<serde_json::Value> {
+        if !matches!(media_type, MediaType::Anime | MediaType::Manga) {
+            return Err(Error::InvalidMediaType(media_type));
+        }
+
+        self.send(
+            include_str!("../queries/get_top_media.graphql"),
+            serde_json::json!({
+                "type": media_type,
+                "sort": [sort],
+                "page": page,
+                "per_page": per_page,
+            }),
+        )
+        .await
+    }
+
+    /// Send the requests getting several medias of a type by their IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of the medias, either anime or manga.
+    /// * `ids` - The IDs of the medias.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the media type is neither anime nor manga, or
+    /// if any of the requests fails.
+    async fn get_medias(
+        &self,
+        media_type: MediaType,
+        ids: &[i64],
+    ) -> Result<Vec<serde_json::Value>> {
+        if !matches!(media_type, MediaType::Anime | MediaType::Manga) {
+            return Err(Error::InvalidMediaType(media_type));
+        }
+
+        let mut unique = ids.to_vec();
+        unique.sort_unstable();
+        unique.dedup();
+
+        let mut found = HashMap::new();
+
+        for chunk in unique.chunks(MAX_PER_PAGE) {
+            let result = self
+                .send(
+                    include_str!("../queries/get_medias.graphql"),
+                    serde_json::json!({
+                        "ids": chunk,
+                        "type": media_type,
+                        "per_page": MAX_PER_PAGE,
+                    }),
+                )
+                .await?;
+
+            if let Some(medias) = result["data"]["Page"]["media"].as_array() {
+                for media in medias.iter() {
+                    if let Some(id) = media["id"].as_i64() {
+                        found.insert(id, media.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(ids.iter().filter_map(|id| found.get(id).cloned()).collect())
+    }
+
+    /// Send a mutation to the AniList API.
+    ///
+    /// Mutations always require an API token, so this method fails
+    /// early without hitting the API if the client has none.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation` - The GraphQL mutation to send.
+    /// * `variables` - The variables to send with the mutation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token, if the API
+    /// rejects the mutation or if the request fails.
+    async fn mutate(
+        &self,
+        mutation: &str,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        if self.api_token.is_none() {
+            return Err(Error::Unauthorized);
+        }
+
+        // Mutations are not idempotent, a mutation failing after the API
+        // applied it would be applied twice if retried.
+        self.dispatch(mutation, variables, 0).await
+    }
+
+    /// Send a GraphQL query to the AniList API.
+    ///
+    /// Transient failures are retried up to the configured number of
+    /// retries.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL query to send.
+    /// * `variables` - The variables to send with the query.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the response contains
+    /// GraphQL errors, in which case the first one is converted into an
+    /// `Error`.
+    async fn send(&self, query: &str, variables: serde_json::Value) -> Result<serde_json::Value> {
+        self.dispatch(query, variables, self.max_retries).await
+    }
+
+    /// Send a GraphQL document to the AniList API.
+    ///
+    /// If the client has auto retry enabled, a rate limited request is
+    /// retried once after waiting for the advertised duration. Transient
+    /// failures are retried up to `max_retries` times.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL document to send.
+    /// * `variables` - The variables to send with the document.
+    /// * `max_retries` - The maximum number of retries on transient
+    ///   failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the response contains
+    /// GraphQL errors, in which case the first one is converted into an
+    /// `Error`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                operation = operation_name(query).unwrap_or("anonymous"),
+                variables = ?variables.as_object().map(|v| v.keys().collect::<Vec<_>>()),
+            ),
+        )
+    )]
+    async fn dispatch(
+        &self,
+        query: &str,
+        mut variables: serde_json::Value,
+        max_retries: u32,
+    ) -> Result<serde_json::Value> {
+        // The queries with descriptions request them as HTML by default.
+        if self.description_format == DescriptionFormat::Markdown && query.contains("$asHtml") {
+            variables["asHtml"] = serde_json::json!(false);
+        }
+        // The searches and browses of media can filter the adult ones.
+        if self.hide_adult_content && query.contains("$isAdult") {
+            variables["isAdult"] = serde_json::json!(false);
+        }
+        let json = serde_json::json!({"query": query, "variables": variables});
+
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+        #[cfg(feature = "tracing")]
+        tracing::debug!("sending request");
+
+        let result = retry::retry(max_retries, || self.attempt(&json)).await;
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(result) => {
+                tracing::debug!(elapsed = ?start.elapsed(), "request finished");
+                for error in result["errors"].as_array().into_iter().flatten() {
+                    tracing::warn!(error = %error["message"], "partial response with error");
+                }
+            }
+            Err(error @ (Error::GraphQl { .. } | Error::NotFound | Error::Unauthorized)) => {
+                tracing::warn!(elapsed = ?start.elapsed(), %error, "request failed");
+            }
+            Err(error) => tracing::debug!(elapsed = ?start.elapsed(), %error, "request failed"),
+        }
+
+        let result = result?;
+
+        if let (Some(handler), Some(errors)) = (&self.warning_handler, result["errors"].as_array())
+        {
+            let warnings: Vec<Error> = errors.iter().map(Error::from_graphql).collect();
+            if !warnings.is_empty() {
+                handler(&warnings);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Make a single attempt at sending a JSON body to the AniList API.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The body to send.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the response contains
+    /// GraphQL errors.
+    async fn attempt(&self, json: &serde_json::Value) -> Result<serde_json::Value> {
+        let result = match self.post(json).await {
+            Err(Error::RateLimited { retry_after }) if self.auto_retry => {
+                tokio::time::sleep(retry_after).await;
+                self.post(json).await?
+            }
+            result => result?,
+        };
+
+        // A response with some data is partial, its errors are warnings.
+        let has_data = result["data"]
+            .as_object()
+            .is_some_and(|data| data.values().any(|value| !value.is_null()));
+        if let Some(error) = result["errors"]
+            .as_array()
+            .and_then(|errors| errors.first())
+            .filter(|_| !has_data)
+        {
+            return Err(Error::from_graphql(error));
+        }
+
+        Ok(result)
+    }
+
+    /// Download the resource at the given URL through the transport.
+    ///
+    /// The request is sent with the HTTP client and timeout of the
+    /// client, but it does not count towards the rate limit of the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the response is not
+    /// successful.
+    #[cfg(feature = "download")]
+    pub(crate) async fn download(&self, url: &str) -> Result<Vec<u8>> {
+        self.transport.download(url).await
+    }
+
+    /// Post a GraphQL document to the AniList API through the transport.
+    ///
+    /// If the client has a rate limiter, this waits for it first.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The GraphQL document and its variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, if the client is rate
+    /// limited or if the response is not valid JSON.
+    async fn post(&self, json: &serde_json::Value) -> Result<serde_json::Value> {
+        if let Some(limiter) = &self.limiter {
+            limiter.acquire().await;
+        }
+
+        let result = self
+            .transport
+            .execute(
+                json["query"].as_str().unwrap_or_default(),
+                &json["variables"],
+                self.api_token.as_deref(),
+            )
+            .await;
+
+        #[cfg(feature = "tracing")]
+        if let Err(Error::RateLimited { retry_after }) = &result {
+            tracing::warn!(?retry_after, "rate limited");
+        }
+
+        result
+    }
+
+    /// Returns an HTTP transport with the specified timeout.
+    ///
+    /// The transport with the default timeout is shared by every client
+    /// using it, which keeps creating default clients cheap.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The timeout duration for requests.
+    /// * `base_url` - The URL of the GraphQL endpoint.
+    fn http_transport(
+        timeout: Duration,
+        connect_timeout: Duration,
+        base_url: &Url,
+    ) -> Arc<dyn Transport> {
+        static DEFAULT: OnceLock<Arc<HttpTransport>> = OnceLock::new();
+
+        if timeout == DEFAULT_TIMEOUT
+            && connect_timeout == DEFAULT_CONNECT_TIMEOUT
+            && base_url.as_str() == DEFAULT_BASE_URL
+        {
+            return DEFAULT
+                .get_or_init(|| {
+                    Arc::new(HttpTransport::new(
+                        timeout,
+                        connect_timeout,
+                        base_url.clone(),
+                    ))
+                })
+                .clone();
+        }
+
+        Arc::new(HttpTransport::new(
+            timeout,
+            connect_timeout,
+            base_url.clone(),
+        ))
+    }
+
+    /// Get the GraphQL query for a specific media type.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of media to get the query for.
+    /// * `action` - The action to perform.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::UnsupportedOperation` if the action is not
+    /// supported for the media type.
+    fn get_query(media_type: MediaType, action: Action) -> Result<&'static str> {
+        let query = match (action, media_type) {
+            (Action::Get, MediaType::Anime) => include_str!("../queries/get_anime.graphql"),
+            (Action::Get, MediaType::Manga) => include_str!("../queries/get_manga.graphql"),
+            (Action::Get, MediaType::Character) => {
+                include_str!("../queries/get_character.graphql")
+            }
+            (Action::Get, MediaType::User) => include_str!("../queries/get_user.graphql"),
+            (Action::Get, MediaType::Person) => include_str!("../queries/get_person.graphql"),
+            (Action::Get, MediaType::Studio) => include_str!("../queries/get_studio.graphql"),
+            (Action::Search, MediaType::Anime) => include_str!("../queries/search_anime.graphql"),
+            (Action::Search, MediaType::Manga) => include_str!("../queries/search_manga.graphql"),
+            (Action::Search, MediaType::Character) => {
+                include_str!("../queries/search_character.graphql")
+            }
+            (Action::Search, MediaType::User) => include_str!("../queries/search_user.graphql"),
+            (Action::Search, MediaType::Person) => {
+                include_str!("../queries/search_person.graphql")
+            }
+            (Action::Search, MediaType::Studio) => {
+                include_str!("../queries/search_studio.graphql")
+            }
+            (Action::Browse, MediaType::Anime) => include_str!("../queries/browse_anime.graphql"),
+            (action, media_type) => {
+                return Err(Error::UnsupportedOperation {
+                    media_type,
+                    action: action.name().to_string(),
+                })
+            }
+        };
+
+        Ok(query)
+    }
+}
+
+/// Returns the name of the operation of a GraphQL document, if it is
+/// named.
+///
+/// # Arguments
+///
+/// * `document` - The GraphQL document.
+#[cfg(feature = "tracing")]
+fn operation_name(document: &str) -> Option<&str> {
+    document
+        .lines()
+        .find_map(|line| {
+            line.strip_prefix("query ")
+                .or_else(|| line.strip_prefix("mutation "))
+        })?
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .filter(|name| !name.is_empty())
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Client::with_timeout(DEFAULT_TIMEOUT)
+    }
+}
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("api_token", &self.api_token.as_ref().map(|_| "[redacted]"))
+            .field("timeout", &self.timeout)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("base_url", &self.base_url)
+            .field("auto_retry", &self.auto_retry)
+            .field("max_retries", &self.max_retries)
+            .field("transport", &self.transport)
+            .field("custom_transport", &self.custom_transport)
+            .field("limiter", &self.limiter)
+            .field("cache", &self.cache)
+            .field("description_format", &self.description_format)
+            .field("hide_adult_content", &self.hide_adult_content)
+            .field(
+                "warning_handler",
+                &self.warning_handler.as_ref().map(|_| ".."),
+            )
+            .finish()
+    }
+}
+
+impl PartialEq for Client {
+    /// Compares the configuration of the clients, whether they are
+    /// authenticated but not with which token.
+    fn eq(&self, other: &Self) -> bool {
+        self.api_token.is_some() == other.api_token.is_some()
+            && self.timeout == other.timeout
+            && self.connect_timeout == other.connect_timeout
+            && self.base_url == other.base_url
+            && self.auto_retry == other.auto_retry
+            && self.max_retries == other.max_retries
+            && self.description_format == other.description_format
+            && self.hide_adult_content == other.hide_adult_content
+    }
+}
+
+/// Represents an action that can be performed by the client.
+///
+/// The `Action` enum defines various actions that the client can perform,
+/// such as getting media by ID, searching for media or browsing media
+/// by season.
+#[derive(Debug, Clone, Copy)]
+enum Action {
+    /// Get media by ID.
+    Get,
+    /// Search for media.
+    Search,
+    /// Browse media by season.
+    Browse,
+}
+
+impl Action {
+    /// Returns the name of the action.
+    fn name(&self) -> &'static str {
+        match self {
+            Action::Get => "get",
+            Action::Search => "search",
+            Action::Browse => "browse",
+        }
+    }
+}
+
+/// Groups airing schedules by their weekday in a time zone, each day
+/// sorted by airing time and the days ordered from Monday to Sunday.
+///
+/// # Arguments
+///
+/// * `schedules` - The airing schedules to group.
+/// * `timezone` - The time zone of the weekdays.
+fn group_by_weekday<Tz: TimeZone>(
+    schedules: Vec<AiringSchedule>,
+    timezone: &Tz,
+) -> Vec<(Weekday, Vec<AiringSchedule>)> {
+    let mut days: BTreeMap<u32, (Weekday, Vec<AiringSchedule>)> = BTreeMap::new();
+
+    for schedule in schedules {
+        if let Some(at) = DateTime::from_timestamp(schedule.at, 0) {
+            let weekday = at.with_timezone(timezone).weekday();
+            days.entry(weekday.num_days_from_monday())
+                .or_insert_with(|| (weekday, Vec::new()))
+                .1
+                .push(schedule);
+        }
+    }
+
+    days.into_values()
+        .map(|(weekday, mut schedules)| {
+            schedules.sort_by_key(|schedule| schedule.at);
+            (weekday, schedules)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{
+        models::CountryOfOrigin,
+        transport::{MockRequest, MockTransport},
+    };
+
+    #[test]
+    fn test_with_timeout() {
+        let duration = Duration::from_secs(30);
+        let client = Client::with_timeout(duration);
+
+        assert_eq!(client.timeout, duration);
+        assert!(client.api_token.is_none());
+    }
+
+    #[test]
+    fn test_with_token() {
+        let api_token = "test_token";
+        let client = Client::with_token(api_token);
+
+        assert_eq!(client.timeout, Duration::from_secs(20));
+        assert_eq!(client.api_token, Some(api_token.to_string()));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let initial_duration = Duration::from_secs(30);
+        let new_duration = Duration::from_secs(60);
+        let client = Client::with_timeout(initial_duration).timeout(new_duration);
+
+        assert_eq!(client.timeout, new_duration);
+    }
+
+    #[test]
+    fn test_connect_timeout() {
+        let client = Client::default();
+
+        assert_eq!(client.connect_timeout, DEFAULT_CONNECT_TIMEOUT);
+
+        let client = client
+            .connect_timeout(Duration::from_secs(2))
+            .timeout(Duration::from_secs(30));
+
+        assert_eq!(client.connect_timeout, Duration::from_secs(2));
+        assert_eq!(client.timeout, Duration::from_secs(30));
+        assert_ne!(client, Client::default().timeout(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_connect_timeout_keeps_base_url() {
+        let client = Client::with_base_url("http://localhost:8080/graphql")
+            .unwrap()
+            .connect_timeout(Duration::from_secs(2));
+
+        assert_eq!(client.base_url.as_str(), "http://localhost:8080/graphql");
+        assert_eq!(client.connect_timeout, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_token() {
+        let initial_token = "initial_token";
+        let new_token = "new_token";
+        let client = Client::with_token(initial_token).token(new_token);
+
+        assert_eq!(client.api_token, Some(new_token.to_string()));
+    }
+
+    #[test]
+    fn test_with_base_url() {
+        let client = Client::with_base_url("http://localhost:8080/graphql").unwrap();
+
+        assert_eq!(client.base_url.as_str(), "http://localhost:8080/graphql");
+        assert_eq!(client.timeout, DEFAULT_TIMEOUT);
+        assert_eq!(
+            Client::default().base_url.as_str(),
+            "https://graphql.anilist.co/"
+        );
+    }
+
+    #[test]
+    fn test_with_invalid_base_url() {
+        assert!(matches!(
+            Client::with_base_url("not a url"),
+            Err(Error::InvalidUrl(_))
+        ));
+        assert!(matches!(
+            Client::with_base_url("ftp://localhost/graphql"),
+            Err(Error::InvalidUrl(_))
+        ));
+    }
+
+    #[test]
+    fn test_timeout_keeps_base_url() {
+        let client = Client::with_base_url("http://localhost:8080/graphql")
+            .unwrap()
+            .timeout(Duration::from_secs(5));
+
+        assert_eq!(client.base_url.as_str(), "http://localhost:8080/graphql");
+    }
+
+    #[test]
+    fn test_transport_is_shared_between_clones() {
+        let client = Client::with_token("test_token");
+        let cloned = client.clone();
+
+        assert!(Arc::ptr_eq(&client.transport, &cloned.transport));
+    }
+
+    #[test]
+    fn test_transport_is_shared_between_default_clients() {
+        let client = Client::default();
+        let other = Client::with_token("test_token");
+        let custom = Client::default().timeout(Duration::from_secs(5));
+
+        assert!(Arc::ptr_eq(&client.transport, &other.transport));
+        assert!(!Arc::ptr_eq(&client.transport, &custom.transport));
+    }
+
+    #[test]
+    fn test_timeout_rebuilds_transport() {
+        let client = Client::default();
+        let transport = Arc::clone(&client.transport);
+        let client = client.timeout(Duration::from_secs(5));
+
+        assert!(!Arc::ptr_eq(&client.transport, &transport));
+    }
+
+    #[test]
+    fn test_timeout_keeps_custom_transport() {
+        let client = Client::with_transport(MockTransport::new());
+        let transport = Arc::clone(&client.transport);
+        let client = client.timeout(Duration::from_secs(5));
+
+        assert!(Arc::ptr_eq(&client.transport, &transport));
+    }
+
+    #[test]
+    fn test_with_auto_retry() {
+        let client = Client::default();
+        assert!(!client.auto_retry);
+
+        let client = client.with_auto_retry(true);
+        assert!(client.auto_retry);
+    }
+
+    #[test]
+    fn test_rate_limiter_is_shared_between_clones() {
+        let client = Client::default();
+        assert!(client.limiter.is_none());
+
+        let client = client.with_rate_limit(60);
+        let cloned = client.clone();
+
+        assert!(Arc::ptr_eq(
+            client.limiter.as_ref().unwrap(),
+            cloned.limiter.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_with_retries() {
+        let client = Client::default();
+        assert_eq!(client.max_retries, 0);
+
+        let client = client.with_retries(3);
+        assert_eq!(client.max_retries, 3);
+    }
+    #[tokio::test]
+    async fn test_search_anime_from_fixture() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": {
+                        "total": 1,
+                        "perPage": 10,
+                        "currentPage": 1,
+                        "lastPage": 1,
+                        "hasNextPage": false
+                    },
+                    "media": [{
+                        "id": 1,
+                        "idMal": 1,
+                        "title": { "romaji": "Cowboy Bebop", "english": "Cowboy Bebop", "native": "カウボーイビバップ" },
+                        "format": "TV",
+                        "status": "FINISHED",
+                        "description": "",
+                        "coverImage": { "large": "https://example.com/cover.png" },
+                        "siteUrl": "https://anilist.co/anime/1"
+                    }]
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone()).token("token");
+
+        let animes = client.search_anime("Cowboy Bebop", 1, 10).await.unwrap();
+
+        assert_eq!(animes.items[0].id, 1);
+        assert_eq!(animes.items[0].title.romaji(), "Cowboy Bebop");
+        assert_eq!(
+            transport.requests(),
+            vec![MockRequest {
+                query: include_str!("../queries/search_anime.graphql").to_string(),
+                variables: serde_json::json!({ "search": "Cowboy Bebop", "page": 1, "per_page": 10 }),
+                token: Some("token".to_string()),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_anime_with_omits_unset_filters() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({ "data": { "Page": { "pageInfo": {}, "media": [] } } }));
+        let client = Client::with_transport(transport.clone());
+
+        let options = AnimeSearchOptions::new()
+            .genre_in(["Action"])
+            .season(Season::Winter)
+            .season_year(2025)
+            .is_adult(false);
+        let animes = client.search_anime_with(options, 2, 5).await.unwrap();
+
+        assert!(animes.is_empty());
+        assert_eq!(
+            transport.requests()[0].variables,
+            serde_json::json!({
+                "genre_in": ["Action"],
+                "season": "WINTER",
+                "seasonYear": 2025,
+                "isAdult": false,
+                "page": 2,
+                "per_page": 5,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_manga_with_country_of_origin() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({ "data": { "Page": { "pageInfo": {}, "media": [] } } }));
+        let client = Client::with_transport(transport.clone());
+
+        let options = MangaSearchOptions::new().country_of_origin(CountryOfOrigin::SouthKorea);
+        client.search_manga_with(options, 1, 10).await.unwrap();
+
+        assert_eq!(
+            transport.requests()[0].variables,
+            serde_json::json!({ "countryOfOrigin": "KR", "page": 1, "per_page": 10 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_anime_stream_from_fixture() {
+        use futures_util::TryStreamExt;
+
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": { "currentPage": 1, "hasNextPage": true },
+                    "media": [
+                        { "id": 1, "title": { "romaji": "Naruto", "native": "" } },
+                        { "id": 2, "title": { "romaji": "Boruto", "native": "" } }
+                    ]
+                }
+            }
+        }));
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": { "currentPage": 2, "hasNextPage": false },
+                    "media": [{ "id": 3, "title": { "romaji": "Naruto Shippuden", "native": "" } }]
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let animes: Vec<Anime> = client
+            .search_anime_stream("Naruto", 2)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            animes.iter().map(|anime| anime.id).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].variables["page"], 1);
+        assert_eq!(requests[1].variables["page"], 2);
+        assert_eq!(requests[1].variables["per_page"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_animes_in_batches() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "media": (1..=50)
+                        .filter(|id| *id != 7)
+                        .map(|id| serde_json::json!({ "id": id, "title": { "romaji": "", "native": "" } }))
+                        .collect::<Vec<_>>()
+                }
+            }
+        }));
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "media": [{ "id": 51, "title": { "romaji": "", "native": "" } }]
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let mut ids = (1..=51).rev().collect::<Vec<i64>>();
+        ids.push(51);
+        let animes = client.get_animes(&ids).await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].variables["ids"].as_array().unwrap().len(), 50);
+        assert_eq!(requests[1].variables["ids"], serde_json::json!([51]));
+        assert_eq!(animes.len(), 51);
+        assert_eq!(animes[0].id, 51);
+        assert_eq!(animes[1].id, 50);
+        assert_eq!(animes[50].id, 51);
+        assert!(animes.iter().all(|anime| anime.id != 7));
+    }
+
+    #[tokio::test]
+    async fn test_characters_with_voice_actors_from_fixture() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Media": {
+                    "characters": {
+                        "pageInfo": { "hasNextPage": false },
+                        "edges": [{
+                            "node": {
+                                "id": 1,
+                                "name": { "first": "Lelouch", "full": "Lelouch Lamperouge" },
+                                "image": { "large": "", "medium": "" },
+                                "siteUrl": "",
+                                "favourites": 0
+                            },
+                            "role": "MAIN",
+                            "voiceActors": [
+                                { "id": 3, "name": { "first": "Johnny", "full": "Johnny Yong Bosch" }, "languageV2": "English" },
+                                { "id": 4, "name": { "first": "Jason", "full": "Jason Liebrecht" }, "languageV2": "English (US)" }
+                            ]
+                        }]
+                    }
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+        let anime = Anime {
+            id: 1,
+            client,
+            ..Default::default()
+        };
+
+        let characters = anime
+            .characters_with_voice_actors(Language::English)
+            .await
+            .unwrap();
+
+        assert_eq!(characters.len(), 1);
+        assert_eq!(characters[0].1.len(), 2);
+        assert_eq!(characters[0].1[0].id, 3);
+        assert_eq!(characters[0].1[0].language, Some(Language::English));
+        assert_eq!(characters[0].1[1].language, None);
+        assert_eq!(transport.requests()[0].variables["language"], "ENGLISH");
+    }
+
+    #[tokio::test]
+    async fn test_not_found_from_fixture() {
+        let transport = MockTransport::new();
+        transport.push_fixture(
+            r#"{ "errors": [{ "message": "Not Found.", "status": 404 }], "data": { "Media": null } }"#,
+        );
+        let client = Client::with_transport(transport);
+
+        assert!(matches!(
+            client.get_anime(999999999).await,
+            Err(Error::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_search_pagination_from_fixture() {
+        let transport = MockTransport::new();
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": {
+                        "total": 3,
+                        "perPage": 2,
+                        "currentPage": 1,
+                        "lastPage": 2,
+                        "hasNextPage": true
+                    },
+                    "studios": [
+                        { "id": 43, "name": "ufotable", "isAnimationStudio": true, "siteUrl": "https://anilist.co/studio/43", "favourites": 1 },
+                        { "id": 44, "name": "Shaft", "isAnimationStudio": true, "siteUrl": "https://anilist.co/studio/44", "favourites": 1 }
+                    ]
+                }
+            }
+        }));
+        let client = Client::with_transport(transport);
+
+        let studios = client.search_studio("a", 1, 2).await.unwrap();
+
+        assert_eq!(studios.len(), 2);
+        assert!(studios.has_next_page());
+        assert_eq!(studios.page_info.last_page, 2);
+        assert_eq!(studios.items[1].name, "Shaft");
+        assert!(!studios.items[0].is_full_loaded);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retries_from_fixture() {
+        let transport = Arc::new(MockTransport::new());
+        transport
+            .push_error(Error::ServerError(502))
+            .push_error(Error::Timeout)
+            .push(serde_json::json!({ "data": { "GenreCollection": ["Action"] } }));
+        let client = Client::with_transport(transport.clone()).with_retries(2);
+
+        let genres = client.get_genres().await.unwrap();
+
+        assert_eq!(genres, vec!["Action"]);
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_mutations_are_not_retried() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_error(Error::Timeout);
+        let client = Client::with_transport(transport.clone())
+            .token("token")
+            .with_retries(2);
+
+        assert!(matches!(
+            client.toggle_favourite(MediaType::Anime, 1).await,
+            Err(Error::Timeout)
+        ));
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_user_attaches_client() {
+        let transport = MockTransport::new();
+        transport.push(serde_json::json!({
+            "data": {
+                "User": {
+                    "id": 5375822,
+                    "name": "andrielfr",
+                    "donatorBadge": "Donator",
+                    "donatorTier": 0,
+                    "siteUrl": "https://anilist.co/user/5375822",
+                    "statistics": {
+                        "anime": { "count": 0, "statuses": [] },
+                        "manga": { "count": 0, "statuses": [] }
+                    },
+                    "createdAt": 1600000000,
+                    "updatedAt": 1700000000
+                }
+            }
+        }));
+        let client = Client::with_transport(transport).token("token");
+
+        let user = client.get_user(5375822).await.unwrap();
+
+        assert!(user.is_full_loaded);
+        assert_eq!(user.client.api_token, Some("token".to_string()));
+        assert!(Arc::ptr_eq(&user.client.transport, &client.transport));
+    }
+
+    #[tokio::test]
+    async fn test_relation_media_load_full() {
+        let media = |id: i64, relations: serde_json::Value| {
+            serde_json::json!({
+                "data": {
+                    "Media": {
+                        "id": id,
+                        "title": { "romaji": "Code Geass", "native": "" },
+                        "type": "ANIME",
+                        "format": "TV",
+                        "status": "FINISHED",
+                        "description": "",
+                        "coverImage": {},
+                        "isAdult": false,
+                        "siteUrl": format!("https://anilist.co/anime/{id}"),
+                        "relations": relations
+                    }
+                }
+            })
+        };
+        let transport = Arc::new(MockTransport::new());
+        transport.push(media(
+            1575,
+            serde_json::json!({
+                "edges": [{
+                    "node": media(2904, serde_json::Value::Null)["data"]["Media"],
+                    "id": 1,
+                    "relationType": "SEQUEL",
+                    "isMainStudio": false
+                }]
+            }),
+        ));
+        transport.push(media(2904, serde_json::Value::Null));
+        let client = Client::with_transport(transport.clone()).token("token");
+
+        let anime = client.get_anime(1575).await.unwrap();
+        let relation = anime.relations().unwrap().remove(0);
+        let Media::Anime(related) = relation.media() else {
+            panic!("the related media is not an anime");
+        };
+        let related = related.load_full().await.unwrap();
+
+        assert_eq!(related.id, 2904);
+        assert!(related.is_full_loaded);
+        assert_eq!(transport.requests()[1].variables["id"], 2904);
+        assert_eq!(transport.requests()[1].token, Some("token".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_get_recommendations_from_fixture() {
+        let transport = MockTransport::new();
+        transport.push(serde_json::json!({
+            "data": {
+                "Media": {
+                    "recommendations": {
+                        "nodes": [
+                            {
+                                "id": 1,
+                                "rating": 42,
+                                "userRating": "RATE_UP",
+                                "mediaRecommendation": {
+                                    "id": 2904,
+                                    "title": { "romaji": "Code Geass", "native": "" },
+                                    "type": "ANIME"
+                                }
+                            },
+                            { "id": 2, "rating": 3, "userRating": null, "mediaRecommendation": null }
+                        ]
+                    }
+                }
+            }
+        }));
+        let client = Client::with_transport(transport);
+
+        let recommendations = client.get_recommendations(1575, 1, 10).await.unwrap();
+
+        assert_eq!(recommendations.len(), 1);
+        assert_eq!(recommendations[0].media.id(), 2904);
+        assert_eq!(recommendations[0].rating, 42);
+        assert_eq!(
+            recommendations[0].user_rating,
+            Some(RecommendationRating::RateUp)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_review_from_fixture() {
+        let transport = MockTransport::new();
+        transport.push(serde_json::json!({
+            "data": {
+                "Review": {
+                    "id": 1,
+                    "summary": "A masterpiece",
+                    "body": "**Great** show",
+                    "bodyHtml": "<p><strong>Great</strong> show</p>",
+                    "score": 95,
+                    "rating": 10,
+                    "ratingAmount": 12,
+                    "mediaId": 1,
+                    "createdAt": 1600000000,
+                    "updatedAt": 1700000000,
+                    "user": { "id": 5375822, "name": "andrielfr" }
+                }
+            }
+        }));
+        let client = Client::with_transport(transport);
+
+        let review = client.get_review(1).await.unwrap();
+
+        assert_eq!(review.body, "**Great** show");
+        assert_eq!(
+            review.body_html.as_deref(),
+            Some("<p><strong>Great</strong> show</p>")
+        );
+        assert_eq!(review.score, 95);
+        assert_eq!(review.rating_amount, 12);
+        assert_eq!(review.user.name, "andrielfr");
+    }
+
+    #[tokio::test]
+    async fn test_user_favourites_from_fixture() {
+        let anime =
+            |id: i64| serde_json::json!({ "id": id, "title": { "romaji": "", "native": "" } });
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "User": {
+                    "id": 1,
+                    "name": "andrielfr",
+                    "donatorBadge": "Donator",
+                    "donatorTier": 0,
+                    "siteUrl": "https://anilist.co/user/1",
+                    "statistics": {
+                        "anime": { "count": 0, "statuses": [] },
+                        "manga": { "count": 0, "statuses": [] }
+                    },
+                    "createdAt": 1600000000,
+                    "updatedAt": 1700000000,
+                    "favourites": {
+                        "anime": { "pageInfo": { "hasNextPage": true }, "nodes": [anime(1)] },
+                        "studios": {
+                            "pageInfo": { "hasNextPage": false },
+                            "nodes": [{ "id": 43, "name": "ufotable", "isAnimationStudio": true, "siteUrl": "", "favourites": 1 }]
+                        }
+                    }
+                }
+            }
+        }));
+        transport.push(serde_json::json!({
+            "data": {
+                "User": {
+                    "favourites": {
+                        "anime": { "pageInfo": { "hasNextPage": false }, "nodes": [anime(2)] }
+                    }
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let user = client.get_user(1).await.unwrap();
+        assert_eq!(user.favourites.anime.len(), 1);
+
+        let favourites = user.favourites().await.unwrap();
+        let requests = transport.requests();
+
+        assert_eq!(
+            favourites
+                .anime
+                .iter()
+                .map(|anime| anime.id)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(favourites.studios.len(), 1);
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].variables["page"], 2);
+        assert_eq!(requests[1].variables["anime"], true);
+    }
+
+    /// Serves a single HTTP response on a local port and returns its URL.
+    #[cfg(feature = "download")]
+    fn serve_once(status: &'static str, body: &'static [u8]) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request).unwrap();
+
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                status,
+                body.len()
+            )
+            .unwrap();
+            stream.write_all(body).unwrap();
+        });
+
+        format!("http://{}/cover.png", address)
+    }
+
+    #[cfg(feature = "download")]
+    #[tokio::test]
+    async fn test_download() {
+        let client = Client::default();
+        let cover = crate::models::Cover {
+            large: Some(serve_once("200 OK", b"\x89PNG")),
+            ..Default::default()
+        };
+
+        assert_eq!(cover.download(&client).await.unwrap(), b"\x89PNG");
+
+        let image = crate::models::Image {
+            medium: serve_once("404 Not Found", b""),
+            ..Default::default()
+        };
+
+        match image.download(&client).await {
+            Err(Error::ApiError(message)) => assert!(message.starts_with("HTTP 404")),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[cfg(feature = "download")]
+    #[tokio::test]
+    async fn test_download_without_image() {
+        let client = Client::with_transport(MockTransport::new());
+        let cover = crate::models::Cover {
+            medium: Some("https://example.com/medium.jpg".to_string()),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            crate::models::Cover::default().download(&client).await,
+            Err(Error::NotFound)
+        ));
+        assert!(matches!(
+            crate::models::Image::default().download(&client).await,
+            Err(Error::NotFound)
+        ));
+        assert!(matches!(
+            cover.download(&client).await,
+            Err(Error::ApiError(_))
+        ));
+    }
+
+    #[test]
+    fn test_debug_redacts_token() {
+        let client = Client::with_token("secret-token");
+        let anime = Anime {
+            client: client.clone(),
+            ..Default::default()
+        };
+        let user = User {
+            client: client.clone(),
+            ..Default::default()
+        };
+
+        for output in [
+            format!("{:?}", client),
+            format!("{:#?}", anime),
+            format!("{:?}", user),
+        ] {
+            assert!(!output.contains("secret-token"));
+            assert!(output.contains("[redacted]"));
+        }
+        assert!(format!("{:?}", Client::default()).contains("api_token: None"));
+    }
+
+    #[test]
+    fn test_eq_ignores_token_text() {
+        assert_eq!(Client::with_token("a"), Client::with_token("b"));
+        assert_ne!(Client::with_token("a"), Client::default());
+    }
+
+    #[tokio::test]
+    async fn test_get_user_by() {
+        let transport = Arc::new(MockTransport::new());
+        for _ in 0..3 {
+            transport.push(serde_json::json!({
+                "data": {
+                    "User": {
+                        "id": 1234,
+                        "name": "1234",
+                        "donatorBadge": "Donator",
+                        "donatorTier": 0,
+                        "siteUrl": "https://anilist.co/user/1234",
+                        "statistics": {
+                            "anime": { "count": 0, "statuses": [] },
+                            "manga": { "count": 0, "statuses": [] }
+                        },
+                        "createdAt": 1600000000,
+                        "updatedAt": 1700000000
+                    }
+                }
+            }));
+        }
+        let client = Client::with_transport(transport.clone());
+
+        let user = client.get_user_by(1234).await.unwrap();
+        client.get_user_by(" 1234 ").await.unwrap();
+        client
+            .get_user_by(UserIdentifier::Name("1234".to_string()))
+            .await
+            .unwrap();
+        let requests = transport.requests();
+
+        assert_eq!(user.id, 1234);
+        assert_eq!(requests[0].variables, serde_json::json!({ "id": 1234 }));
+        assert_eq!(requests[1].variables, serde_json::json!({ "id": 1234 }));
+        assert_eq!(requests[2].variables, serde_json::json!({ "name": "1234" }));
+    }
+
+    #[tokio::test]
+    async fn test_get_followers() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": { "total": 1, "perPage": 25, "currentPage": 1, "lastPage": 1, "hasNextPage": false },
+                    "followers": [{
+                        "id": 1,
+                        "name": "Josh",
+                        "isFollowing": true,
+                        "isFollower": false,
+                        "siteUrl": "https://anilist.co/user/1"
+                    }]
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let followers = client.get_followers(5375822, 1, 25).await.unwrap();
+        let requests = transport.requests();
+
+        assert_eq!(followers.items.len(), 1);
+        assert_eq!(followers.items[0].name, "Josh");
+        assert_eq!(followers.items[0].is_following, Some(true));
+        assert_eq!(followers.items[0].is_follower, Some(false));
+        assert!(requests[0].query.contains("followers(userId: $userId"));
+        assert_eq!(requests[0].variables["userId"], 5375822);
+    }
+
+    #[tokio::test]
+    async fn test_toggle_follow_requires_token() {
+        let transport = Arc::new(MockTransport::new());
+        let client = Client::with_transport(transport.clone());
+
+        assert!(matches!(
+            client.toggle_follow(1).await,
+            Err(Error::Unauthorized)
+        ));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_toggle_follow() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": { "ToggleFollow": { "id": 1, "isFollowing": true } }
+        }));
+        let client = Client::with_transport(transport.clone()).token("token");
+
+        assert!(client.toggle_follow(1).await.unwrap());
+        assert_eq!(transport.requests()[0].variables["userId"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_toggle_follow_malformed() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": { "ToggleFollow": { "id": 1, "isFollowing": null } }
+        }));
+        let client = Client::with_transport(transport).token("token");
+
+        assert!(matches!(
+            client.toggle_follow(1).await,
+            Err(Error::Deserialization { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_toggle_like() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": { "ToggleLikeV2": { "likeCount": 12 } }
+        }));
+        transport.push(serde_json::json!({ "data": { "ToggleLikeV2": {} } }));
+        let client = Client::with_transport(transport.clone()).token("token");
+
+        assert_eq!(
+            client
+                .toggle_like(1, LikeableType::ActivityReply)
+                .await
+                .unwrap(),
+            Some(12)
+        );
+        assert_eq!(
+            client.toggle_like(2, LikeableType::Thread).await.unwrap(),
+            None
+        );
+        assert_eq!(
+            transport.requests()[0].variables,
+            serde_json::json!({ "id": 1, "type": "ACTIVITY_REPLY" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_toggle_like_requires_token() {
+        let client = Client::with_transport(MockTransport::new());
+
+        assert!(matches!(
+            client.toggle_like(1, LikeableType::Activity).await,
+            Err(Error::Unauthorized)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_search_threads() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": { "total": 1, "perPage": 10, "currentPage": 1, "lastPage": 1, "hasNextPage": false },
+                    "threads": [{
+                        "id": 10,
+                        "title": "Sousou no Frieren - Episode 1 Discussion",
+                        "body": "",
+                        "replyCount": 120,
+                        "viewCount": 5000,
+                        "isSticky": false,
+                        "isLocked": false,
+                        "siteUrl": "https://anilist.co/forum/thread/10",
+                        "createdAt": 1695945600,
+                        "updatedAt": 1695945600,
+                        "categories": [{ "id": 5, "name": "Release Discussion" }],
+                        "mediaCategories": [{ "id": 154587 }],
+                        "user": { "id": 1, "name": "Josh" }
+                    }]
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let threads = client
+            .search_threads("Frieren Episode 1", 1, 10)
+            .await
+            .unwrap();
+        let thread = &threads.items[0];
+
+        assert_eq!(thread.reply_count, 120);
+        assert_eq!(thread.categories[0].name, "Release Discussion");
+        assert_eq!(thread.media_ids, vec![154587]);
+        assert_eq!(thread.user.name, "Josh");
+        assert_eq!(
+            transport.requests()[0].variables["search"],
+            "Frieren Episode 1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_thread_comments() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": { "total": 1, "perPage": 25, "currentPage": 1, "lastPage": 1, "hasNextPage": false },
+                    "threadComments": [{
+                        "id": 1,
+                        "threadId": 10,
+                        "comment": "Great episode!",
+                        "user": { "id": 1, "name": "Josh" },
+                        "childComments": [{
+                            "id": 2,
+                            "comment": "Agreed",
+                            "user": { "id": 2, "name": "Ann" },
+                            "childComments": null
+                        }]
+                    }]
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let comments = client.get_thread_comments(10, 1, 25).await.unwrap();
+
+        assert_eq!(comments.items[0].child_comments[0].comment, "Agreed");
+        assert_eq!(transport.requests()[0].variables["threadId"], 10);
+    }
+
+    #[tokio::test]
+    async fn test_get_media_list_entry() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "User": { "id": 5375822 },
+                "Page": {
+                    "mediaList": [{
+                        "id": 100,
+                        "userId": 5375822,
+                        "mediaId": 1,
+                        "status": "CURRENT",
+                        "score": 9.5,
+                        "progress": 12,
+                        "media": {
+                            "id": 1,
+                            "type": "ANIME",
+                            "title": { "romaji": "Cowboy Bebop", "native": "カウボーイビバップ" },
+                            "episodes": 26
+                        }
+                    }]
+                }
+            }
+        }));
+        transport.push(serde_json::json!({
+            "data": { "User": { "id": 5375822 }, "Page": { "mediaList": [] } }
+        }));
+        transport.push(serde_json::json!({
+            "errors": [{ "message": "Not Found.", "status": 404 }],
+            "data": { "User": null, "Page": { "mediaList": [] } }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let entry = client
+            .get_media_list_entry(5375822, 1)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(entry.progress, Some(12));
+        assert_eq!(entry.status, Some(MediaListStatus::Current));
+        assert!(matches!(entry.media, Some(Media::Anime(_))));
+        assert_eq!(
+            transport.requests()[0].variables,
+            serde_json::json!({ "userId": 5375822, "mediaId": 1 })
+        );
+        assert_eq!(client.get_media_list_entry(5375822, 2).await.unwrap(), None);
+        assert!(matches!(
+            client.get_media_list_entry(0, 1).await,
+            Err(Error::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_anime_set_progress() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "SaveMediaListEntry": { "id": 100, "userId": 1, "mediaId": 1, "progress": 12 }
+            }
+        }));
+        let anime = Anime {
+            id: 1,
+            episodes: Some(26),
+            client: Client::with_transport(transport.clone()).token("token"),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            anime.set_progress(27).await,
+            Err(Error::InvalidProgress {
+                progress: 27,
+                total: 26
+            })
+        ));
+        assert!(transport.requests().is_empty());
+
+        let entry = anime.set_progress(12).await.unwrap();
+
+        assert_eq!(entry.progress, Some(12));
+        assert_eq!(
+            transport.requests()[0].variables,
+            serde_json::json!({ "mediaId": 1, "progress": 12 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_manga_setters_require_token() {
+        let manga = Manga {
+            id: 30013,
+            client: Client::with_transport(MockTransport::new()),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            manga.set_status(MediaListStatus::Current).await,
+            Err(Error::Unauthorized)
+        ));
+        assert!(matches!(
+            manga.set_volumes(100).await,
+            Err(Error::Unauthorized)
+        ));
+        assert!(matches!(
+            manga.set_score(120.0).await,
+            Err(Error::InvalidScore(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_anime_increment_progress() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Media": {
+                    "mediaListEntry": { "id": 100, "userId": 1, "mediaId": 1, "status": "CURRENT", "progress": 25 }
+                }
+            }
+        }));
+        transport.push(serde_json::json!({
+            "data": {
+                "SaveMediaListEntry": { "id": 100, "userId": 1, "mediaId": 1, "status": "COMPLETED", "progress": 26 }
+            }
+        }));
+        let anime = Anime {
+            id: 1,
+            episodes: Some(26),
+            client: Client::with_transport(transport.clone()).token("token"),
+            ..Default::default()
+        };
+
+        let entry = anime.increment_progress().await.unwrap();
+        let requests = transport.requests();
+
+        assert_eq!(entry.status, Some(MediaListStatus::Completed));
+        assert_eq!(requests[0].variables["mediaId"], 1);
+        assert_eq!(requests[1].variables["progress"], 26);
+        assert_eq!(requests[1].variables["status"], "COMPLETED");
+        assert!(requests[1].variables["completedAt"].is_object());
+        assert!(matches!(
+            Anime::default().increment_progress().await,
+            Err(Error::Unauthorized)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_notifications_require_token() {
+        let transport = Arc::new(MockTransport::new());
+        let client = Client::with_transport(transport.clone());
+
+        assert!(matches!(
+            client.get_unread_notification_count().await,
+            Err(Error::Unauthorized)
+        ));
+        assert!(matches!(
+            client.mark_notifications_read().await,
+            Err(Error::Unauthorized)
+        ));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_notifications() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": { "Viewer": { "unreadNotificationCount": 3 } }
+        }));
+        transport.push(serde_json::json!({
+            "data": { "Page": { "notifications": [] } }
+        }));
+        let client = Client::with_transport(transport.clone()).token("token");
+
+        assert_eq!(client.get_unread_notification_count().await.unwrap(), 3);
+        client.mark_notifications_read().await.unwrap();
+
+        let requests = transport.requests();
+        assert!(requests[0].query.contains("unreadNotificationCount"));
+        assert!(requests[1].query.contains("resetNotificationCount: true"));
+    }
+
+    #[tokio::test]
+    async fn test_get_weekly_schedule() {
+        // Monday, 2025-01-06 00:00:00 UTC.
+        let monday = 1736121600;
+        let page_info = |page: u16, has_next_page: bool| {
+            serde_json::json!({
+                "total": 3,
+                "perPage": 2,
+                "currentPage": page,
+                "lastPage": 2,
+                "hasNextPage": has_next_page,
+            })
+        };
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": page_info(1, true),
+                    "airingSchedules": [
+                        { "id": 4, "airingAt": monday + 6 * 86400, "timeUntilAiring": 0, "episode": 4, "mediaId": 4 },
+                        { "id": 1, "airingAt": monday + 7200, "timeUntilAiring": 0, "episode": 1, "mediaId": 1 },
+                        { "id": 2, "airingAt": monday + 86400 + 60, "timeUntilAiring": 0, "episode": 2, "mediaId": 2 },
+                    ]
+                }
+            }
+        }));
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": page_info(2, false),
+                    "airingSchedules": [
+                        { "id": 3, "airingAt": monday + 3600, "timeUntilAiring": 0, "episode": 3, "mediaId": 3 },
+                    ]
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let schedules: Vec<AiringSchedule> = stream::paginate(|page| {
+            client.get_airing_schedule(monday - 1, monday + 604800, page, 2)
+        })
+        .try_collect()
+        .await
+        .unwrap();
+        let days: Vec<(Weekday, Vec<u32>)> = group_by_weekday(schedules, &Utc)
+            .into_iter()
+            .map(|(weekday, schedules)| {
+                (
+                    weekday,
+                    schedules.iter().map(|schedule| schedule.id).collect(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            days,
+            vec![
+                (Weekday::Mon, vec![3, 1]),
+                (Weekday::Tue, vec![2]),
+                (Weekday::Sun, vec![4]),
+            ]
+        );
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].variables["page"], 2);
+        assert_eq!(requests[1].variables["start"], monday - 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_weekly_schedule_window() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": { "hasNextPage": false },
+                    "airingSchedules": []
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+        let week_start = DateTime::from_timestamp(1736121600, 0).unwrap();
+
+        assert!(client
+            .get_weekly_schedule(week_start)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let variables = &transport.requests()[0].variables;
+        assert_eq!(variables["start"], 1736121599);
+        assert_eq!(variables["end"], 1736121600 + 604800);
+        assert_eq!(variables["per_page"], 50);
+    }
+
+    #[tokio::test]
+    async fn test_get_media_trends() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": { "currentPage": 1, "hasNextPage": true },
+                    "mediaTrends": [
+                        { "mediaId": 1, "date": 1736121600, "trending": 10, "averageScore": 86, "popularity": 1000, "inProgress": 50, "releasing": false, "episode": null },
+                        { "mediaId": 1, "date": 1736035200, "trending": 12, "averageScore": null, "popularity": 998, "inProgress": 49, "releasing": false, "episode": null }
+                    ]
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let trends = client
+            .get_media_trends(1, 1, 2, MediaTrendSort::default())
+            .await
+            .unwrap();
+
+        assert!(trends.has_next_page());
+        assert_eq!(trends.items.len(), 2);
+        assert_eq!(trends.items[1].average_score, None);
+        assert_eq!(transport.requests()[0].variables["mediaId"], 1);
+        assert_eq!(
+            transport.requests()[0].variables["sort"],
+            serde_json::json!(["DATE_DESC"])
+        );
+    }
+
+    fn franchise_media(id: i64, start_year: i32, relations: &[(i64, &str)]) -> serde_json::Value {
+        let node = |id: i64| {
+            serde_json::json!({
+                "id": id,
+                "title": { "romaji": format!("Season {id}"), "native": "" },
+                "type": "ANIME",
+                "siteUrl": format!("https://anilist.co/anime/{id}")
+            })
+        };
+        let edges: Vec<serde_json::Value> = relations
+            .iter()
+            .map(|(id, relation_type)| {
+                serde_json::json!({
+                    "node": node(*id),
+                    "id": id,
+                    "relationType": relation_type,
+                    "isMainStudio": false
+                })
+            })
+            .collect();
+        let mut media = node(id);
+        media["format"] = serde_json::json!("TV");
+        media["status"] = serde_json::json!("FINISHED");
+        media["description"] = serde_json::json!("");
+        media["coverImage"] = serde_json::json!({});
+        media["isAdult"] = serde_json::json!(false);
+        media["startDate"] = serde_json::json!({ "year": start_year, "month": 1, "day": 1 });
+        media["relations"] = serde_json::json!({ "edges": edges });
+
+        serde_json::json!({ "data": { "Media": media } })
+    }
+
+    #[tokio::test]
+    async fn test_franchise_with_cycles() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(franchise_media(
+            2,
+            2010,
+            &[(1, "PREQUEL"), (3, "SEQUEL"), (9, "ADAPTATION")],
+        ));
+        transport.push(franchise_media(
+            1,
+            2008,
+            &[(2, "SEQUEL"), (3, "SIDE_STORY")],
+        ));
+        transport.push(franchise_media(3, 2012, &[(2, "PREQUEL"), (1, "PARENT")]));
+        let client = Client::with_transport(transport.clone());
+
+        let media = Media::from(client.get_anime(2).await.unwrap());
+        let ids: Vec<i64> = media
+            .franchise(&client, 10)
+            .await
+            .unwrap()
+            .iter()
+            .map(Media::id)
+            .collect();
+        let requests = transport.requests();
+
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].variables["id"], 1);
+        assert_eq!(requests[2].variables["id"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_franchise_max_depth() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(franchise_media(1, 2008, &[(2, "SEQUEL")]));
+        transport.push(franchise_media(2, 2010, &[(1, "PREQUEL"), (3, "SEQUEL")]));
+        let client = Client::with_transport(transport.clone());
+
+        let media = Media::from(client.get_anime(1).await.unwrap());
+        let root: Vec<i64> = media
+            .franchise(&client, 0)
+            .await
+            .unwrap()
+            .iter()
+            .map(Media::id)
+            .collect();
+        let ids: Vec<i64> = media
+            .franchise(&client, 1)
+            .await
+            .unwrap()
+            .iter()
+            .map(Media::id)
+            .collect();
+
+        assert_eq!(root, vec![1]);
+        assert_eq!(ids, vec![1, 2]);
+        assert_eq!(transport.requests().len(), 2);
+        assert!(Media::Unknown
+            .franchise(&client, 1)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prefer_html_descriptions() {
+        let anime = serde_json::json!({
+            "data": {
+                "Media": {
+                    "id": 1,
+                    "title": { "romaji": "Cowboy Bebop", "native": "" },
+                    "format": "TV",
+                    "status": "FINISHED",
+                    "description": "*Enter* a world",
+                    "coverImage": {},
+                    "isAdult": false,
+                    "siteUrl": "https://anilist.co/anime/1",
+                    "characters": {
+                        "edges": [{
+                            "node": {
+                                "id": 1,
+                                "name": { "first": "Spike", "full": "Spike Spiegel" },
+                                "image": { "large": "", "medium": "" },
+                                "siteUrl": "",
+                                "favourites": 0
+                            },
+                            "role": "MAIN"
+                        }]
+                    }
+                }
+            }
+        });
+        let transport = Arc::new(MockTransport::new());
+        transport.push(anime.clone());
+        transport.push(anime);
+        transport.push(serde_json::json!({ "data": { "GenreCollection": [] } }));
+        let client = Client::with_transport(transport.clone());
+
+        let html = client.get_anime(1).await.unwrap();
+        let markdown = client
+            .clone()
+            .prefer_html_descriptions(false)
+            .get_anime(1)
+            .await
+            .unwrap();
+        client.get_genres().await.unwrap();
+        let requests = transport.requests();
+
+        assert_eq!(html.description_format, DescriptionFormat::Html);
+        assert_eq!(markdown.description_format, DescriptionFormat::Markdown);
+        assert_eq!(
+            markdown.characters().unwrap()[0].description_format,
+            DescriptionFormat::Markdown
+        );
+        assert!(requests[0].variables.get("asHtml").is_none());
+        assert_eq!(requests[1].variables["asHtml"], false);
+        assert!(requests[2].variables.get("asHtml").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_partial_response() {
+        let warnings = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": { "GenreCollection": ["Action"], "MediaTagCollection": null },
+            "errors": [{ "message": "Restricted field", "status": 403 }]
+        }));
+        transport.push(serde_json::json!({
+            "data": { "Media": null },
+            "errors": [{ "message": "Not Found.", "status": 404 }]
+        }));
+        let client = Client::with_transport(transport.clone()).on_warnings({
+            let warnings = warnings.clone();
+            move |errors| {
+                warnings
+                    .lock()
+                    .unwrap()
+                    .extend(errors.iter().map(ToString::to_string))
+            }
+        });
+
+        assert_eq!(client.get_genres().await.unwrap(), vec!["Action"]);
+        assert!(matches!(client.get_anime(1).await, Err(Error::NotFound)));
+        assert_eq!(
+            *warnings.lock().unwrap(),
+            vec!["graphql error: `Restricted field`".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deserialization_error() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Media": {
+                    "id": 1,
+                    "title": { "romaji": "Cowboy Bebop", "native": "カウボーイビバップ" },
+                    "format": "TV",
+                    "status": "FINISHED",
+                    "description": "",
+                    "coverImage": {},
+                    "isAdult": false,
+                    "siteUrl": "https://anilist.co/anime/1",
+                    "episodes": "twenty-six"
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let error = client.get_anime(1).await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::Deserialization { ref path, id: Some(1), .. } if path == "data.Media.episodes"
+        ));
+        assert!(error
+            .to_string()
+            .starts_with("data.Media.episodes: invalid type: string \"twenty-six\""));
+    }
+
+    #[tokio::test]
+    async fn test_http_error_source() {
+        // Nothing listens on the port once the listener is dropped.
+        let address = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let client = Client::with_base_url(&format!("http://{}/", address))
+            .unwrap()
+            .with_retries(0);
+
+        match client.get_genres().await {
+            Err(error @ Error::Http(_)) => {
+                let source = std::error::Error::source(&error).unwrap();
+                assert!(source
+                    .downcast_ref::<reqwest::Error>()
+                    .unwrap()
+                    .is_connect());
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_timeout_error() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (_stream, _) = listener.accept().unwrap();
+            std::thread::sleep(Duration::from_secs(2));
+        });
+        let client = Client::with_base_url(&format!("http://{}/", address))
+            .unwrap()
+            .timeout(Duration::from_millis(100))
+            .with_retries(0);
+
+        assert!(matches!(client.get_genres().await, Err(Error::Timeout)));
+    }
+
+    /// A subscriber recording the fields of the spans and events.
+    #[cfg(feature = "tracing")]
+    #[derive(Default)]
+    struct Recorder(std::sync::Mutex<Vec<String>>);
+
+    #[cfg(feature = "tracing")]
+    impl tracing::field::Visit for &Recorder {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{}={:?}", field, value));
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for Recorder {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            span.record(&mut &*self);
+            tracing::span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+            values.record(&mut &*self);
+        }
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            event.record(&mut &*self);
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_tracing() {
+        let recorder = Arc::new(Recorder::default());
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+        let transport = Arc::new(MockTransport::new());
+        transport.push_error(Error::RateLimited {
+            retry_after: Duration::from_secs(1),
+        });
+        transport.push_error(Error::Timeout);
+        let client = Client::with_transport(transport.clone()).token("secret-token");
+
+        let _ = client.search_anime("Bebop", 1, 10).await;
+        let _ = client.toggle_follow(1).await;
+
+        let fields = recorder.0.lock().unwrap().join("\n");
+        assert!(fields.contains("operation=\"SearchAnime\""));
+        assert!(fields.contains("\"search\""));
+        assert!(fields.contains("message=rate limited"));
+        assert!(fields.contains("operation=\"ToggleFollow\""));
+        assert!(fields.contains("\"userId\""));
+        assert!(!fields.contains("secret-token"));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_operation_name() {
+        assert_eq!(
+            operation_name(include_str!("../queries/get_anime.graphql")),
+            Some("GetAnime")
+        );
+        assert_eq!(
+            operation_name(include_str!("../queries/search_user.graphql")),
+            Some("SearchUser")
+        );
+        assert_eq!(
+            operation_name(include_str!("../queries/toggle_follow.graphql")),
+            Some("ToggleFollow")
+        );
+        assert_eq!(operation_name("query { Viewer { id } }"), None);
+    }
+
+    #[test]
+    fn test_get_query() {
+        let media_types = [
+            MediaType::Anime,
+            MediaType::Manga,
+            MediaType::Character,
+            MediaType::User,
+            MediaType::Person,
+            MediaType::Studio,
+            MediaType::Unknown,
+        ];
+
+        for action in [Action::Get, Action::Search, Action::Browse] {
+            for media_type in &media_types {
+                let supported = match action {
+                    Action::Get | Action::Search => *media_type != MediaType::Unknown,
+                    Action::Browse => *media_type == MediaType::Anime,
+                };
+
+                match Client::get_query(media_type.clone(), action) {
+                    Ok(query) => {
+                        assert!(supported, "{:?} {:?}", action, media_type);
+                        assert!(query.contains("query"));
+                    }
+                    Err(Error::UnsupportedOperation {
+                        media_type: unsupported,
+                        action: name,
+                    }) => {
+                        assert!(!supported, "{:?} {:?}", action, media_type);
+                        assert_eq!(unsupported, *media_type);
+                        assert_eq!(name, action.name());
+                    }
+                    Err(error) => panic!("unexpected error: {:?}", error),
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_operation() {
+        let transport = Arc::new(MockTransport::new());
+        let client = Client::with_transport(transport.clone());
+
+        let error = client
+            .request(MediaType::Character, Action::Browse, serde_json::json!({}))
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "unsupported operation: cannot browse `Character`"
+        );
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_anime_basic() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Media": {
+                    "id": 1,
+                    "title": { "romaji": "Cowboy Bebop", "native": "カウボーイビバップ" },
+                    "format": "TV",
+                    "status": "FINISHED",
+                    "coverImage": { "large": "https://example.com/large.jpg" },
+                    "siteUrl": "https://anilist.co/anime/1"
+                }
+            }
+        }));
+        transport.push(serde_json::json!({
+            "data": {
+                "Media": {
+                    "id": 1,
+                    "title": { "romaji": "Cowboy Bebop", "native": "カウボーイビバップ" },
+                    "format": "TV",
+                    "status": "FINISHED",
+                    "description": "Enter a world",
+                    "coverImage": {},
+                    "isAdult": false,
+                    "siteUrl": "https://anilist.co/anime/1",
+                    "episodes": 26
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let anime = client.get_anime_basic(1).await.unwrap();
+
+        assert_eq!(anime.id, 1);
+        assert_eq!(anime.title.romaji(), "Cowboy Bebop");
+        assert_eq!(anime.format, crate::models::Format::Tv);
+        assert_eq!(anime.url, "https://anilist.co/anime/1");
+        assert_eq!(anime.episodes, None);
+        assert!(!anime.is_full_loaded);
+        assert!(!transport.requests()[0].query.contains("characters"));
+        assert_eq!(transport.requests()[0].variables["id"], 1);
+
+        let anime = anime.load_full().await.unwrap();
+
+        assert!(anime.is_full_loaded);
+        assert_eq!(anime.episodes, Some(26));
+        assert!(transport.requests()[1].query.contains("characters"));
+    }
+
+    /// Returns the response of a minimal anime.
+    fn cached_anime(id: i64) -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "Media": {
+                    "id": id,
+                    "title": { "romaji": "Cowboy Bebop", "native": "" },
+                    "format": "TV",
+                    "status": "FINISHED",
+                    "description": "",
+                    "coverImage": {},
+                    "isAdult": false,
+                    "siteUrl": format!("https://anilist.co/anime/{}", id)
+                }
+            }
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cache() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(cached_anime(1));
+        transport.push(cached_anime(2));
+        transport.push(cached_anime(1));
+        let client =
+            Client::with_transport(transport.clone()).with_cache(10, Duration::from_secs(60));
+
+        client.get_anime(1).await.unwrap();
+        let anime = client.clone().get_anime(1).await.unwrap();
+
+        assert_eq!(anime.id, 1);
+        assert_eq!(transport.requests().len(), 1);
+
+        client.get_anime(2).await.unwrap();
+        tokio::time::advance(Duration::from_secs(60)).await;
+        client.get_anime(1).await.unwrap();
+
+        assert_eq!(transport.requests().len(), 3);
+        assert_eq!(transport.requests()[2].variables["id"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_cache_invalidation() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(cached_anime(1));
+        transport.push(cached_anime(1));
+        transport.push(serde_json::json!({
+            "data": { "ToggleFavourite": { "anime": { "nodes": [] } } }
+        }));
+        transport.push(cached_anime(1));
+        transport.push(cached_anime(1));
+        transport.push(cached_anime(1));
+        let client = Client::with_transport(transport.clone())
+            .token("token")
+            .with_cache(10, Duration::from_secs(60));
+
+        client.get_anime(1).await.unwrap();
+        client.invalidate(MediaType::Anime, 1);
+        client.get_anime(1).await.unwrap();
+        client.toggle_favourite(MediaType::Anime, 1).await.unwrap();
+        client.get_anime(1).await.unwrap();
+        client.clear_cache();
+        let anime = client.get_anime(1).await.unwrap();
+
+        assert_eq!(transport.requests().len(), 5);
+
+        let mut anime = anime;
+        anime.is_full_loaded = false;
+        anime.load_full().await.unwrap();
+
+        assert_eq!(transport.requests().len(), 6);
+    }
+
+    /// A transport answering with a minimal anime after a delay, and
+    /// keeping track of the number of requests in flight.
+    #[derive(Debug, Default)]
+    struct SlowTransport {
+        in_flight: std::sync::atomic::AtomicUsize,
+        max_in_flight: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for SlowTransport {
+        async fn execute(
+            &self,
+            _: &str,
+            variables: &serde_json::Value,
+            _: Option<&str>,
+        ) -> Result<serde_json::Value> {
+            use std::sync::atomic::Ordering;
+
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+
+            // The later IDs answer first, to check the order is kept.
+            let id = variables["id"].as_i64().unwrap_or_default();
+            tokio::time::sleep(Duration::from_millis(100 - id as u64)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            match id {
+                0 => Err(Error::NotFound),
+                id => Ok(cached_anime(id)),
+            }
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_get_animes_concurrent() {
+        let transport = Arc::new(SlowTransport::default());
+        let client = Client::with_transport(transport.clone());
+
+        let animes = client
+            .get_animes_concurrent(&[1, 2, 0, 4, 5, 6, 7], 3)
+            .await;
+
+        let ids: Vec<_> = animes
+            .iter()
+            .map(|anime| anime.as_ref().map(|anime| anime.id).ok())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![Some(1), Some(2), None, Some(4), Some(5), Some(6), Some(7)]
+        );
+        assert!(matches!(animes[2], Err(Error::NotFound)));
+        assert_eq!(
+            transport
+                .max_in_flight
+                .load(std::sync::atomic::Ordering::SeqCst),
+            3
+        );
+        assert!(client.get_animes_concurrent(&[], 0).await.is_empty());
+    }
+
+    /// A transport answering the mutations after a delay.
+    #[derive(Debug, Default)]
+    struct SlowMutationTransport(MockTransport);
+
+    #[async_trait::async_trait]
+    impl Transport for SlowMutationTransport {
+        async fn execute(
+            &self,
+            query: &str,
+            variables: &serde_json::Value,
+            token: Option<&str>,
+        ) -> Result<serde_json::Value> {
+            if query.lines().any(|line| line.starts_with("mutation")) {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+
+            self.0.execute(query, variables, token).await
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cache_invalidation_during_mutation() {
+        let transport = Arc::new(SlowMutationTransport::default());
+        transport.0.push(cached_anime(1));
+        transport.0.push(serde_json::json!({
+            "data": { "ToggleFavourite": { "anime": { "nodes": [] } } }
+        }));
+        transport.0.push(cached_anime(1));
+        let client = Client::with_transport(transport.clone())
+            .token("token")
+            .with_cache(10, Duration::from_secs(60));
+
+        // The anime is fetched while the mutation is in flight.
+        let (toggled, fetched) =
+            tokio::join!(client.toggle_favourite(MediaType::Anime, 1), async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                client.get_anime(1).await
+            });
+        toggled.unwrap();
+        fetched.unwrap();
+        client.get_anime(1).await.unwrap();
+
+        assert_eq!(transport.0.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_character_toggle_favourite() {
+        let transport = Arc::new(MockTransport::new());
+        let toggled = serde_json::json!({ "data": { "ToggleFavourite": {} } });
+        for is_favourite in [true, false] {
+            transport.push(toggled.clone());
+            transport.push(serde_json::json!({
+                "data": { "Character": { "isFavourite": is_favourite } }
+            }));
+        }
+        let client = Client::with_transport(transport.clone());
+
+        let mut character = Character {
+            id: 1,
+            is_favourite: Some(true),
+            client: client.clone(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            character.toggle_favourite().await,
+            Err(Error::Unauthorized)
+        ));
+        assert!(transport.requests().is_empty());
+
+        // The stale state of the model is ignored.
+        character.client = client.token("token");
+        assert!(character.toggle_favourite().await.unwrap());
+        assert_eq!(character.is_favourite, Some(true));
+        assert!(!character.toggle_favourite().await.unwrap());
+        assert_eq!(character.is_favourite, Some(false));
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].variables["characterId"], 1);
+        assert_eq!(
+            requests[1].variables,
+            serde_json::json!({ "id": 1, "character": true })
+        );
+        assert_eq!(requests[2].variables["characterId"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_toggle_favourite_state() {
+        let transport = Arc::new(MockTransport::new());
+        let toggled = serde_json::json!({ "data": { "ToggleFavourite": {} } });
+        for (root, is_favourite) in [("Media", true), ("Staff", false), ("Studio", true)] {
+            transport.push(toggled.clone());
+            transport.push(serde_json::json!({
+                "data": { root: { "isFavourite": is_favourite } }
+            }));
+        }
+        let client = Client::with_transport(transport.clone()).token("token");
+
+        let mut anime = Anime {
+            id: 1,
+            client: client.clone(),
+            ..Default::default()
+        };
+        let mut person = Person {
+            id: 2,
+            client: client.clone(),
+            ..Default::default()
+        };
+        let mut studio = Studio {
+            id: 3,
+            client: client.clone(),
+            ..Default::default()
+        };
+
+        assert!(anime.toggle_favourite().await.unwrap());
+        assert!(!person.toggle_favourite().await.unwrap());
+        assert!(studio.toggle_favourite().await.unwrap());
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].variables["animeId"], 1);
+        assert_eq!(
+            requests[1].variables,
+            serde_json::json!({ "id": 1, "media": true })
+        );
+        assert_eq!(requests[2].variables["staffId"], 2);
+        assert_eq!(
+            requests[3].variables,
+            serde_json::json!({ "id": 2, "staff": true })
+        );
+        assert_eq!(requests[4].variables["studioId"], 3);
+        assert_eq!(
+            requests[5].variables,
+            serde_json::json!({ "id": 3, "studio": true })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_birthday_characters_and_staff() {
+        let page_info = serde_json::json!({
+            "total": 1, "perPage": 10, "currentPage": 1, "lastPage": 1, "hasNextPage": false
+        });
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": page_info,
+                    "characters": [{
+                        "id": 1,
+                        "name": { "first": "Spike", "full": "Spike Spiegel" },
+                        "image": { "large": "", "medium": "" },
+                        "dateOfBirth": { "year": 2044, "month": 6, "day": 26 },
+                        "age": "27",
+                        "siteUrl": "https://anilist.co/character/1",
+                        "favourites": 30000
+                    }]
+                }
+            }
+        }));
+        transport.push(serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": page_info,
+                    "staff": [{
+                        "id": 95011,
+                        "name": { "first": "Kouichi", "full": "Kouichi Yamadera" },
+                        "image": { "large": "", "medium": "" },
+                        "dateOfBirth": { "year": 1961, "month": 6, "day": 17 },
+                        "siteUrl": "https://anilist.co/staff/95011",
+                        "favourites": 2000
+                    }]
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let characters = client.get_birthday_characters(1, 10).await.unwrap();
+        let staff = client.get_birthday_staff(2, 5).await.unwrap();
+
+        assert_eq!(characters.items[0].favourites, Some(30000));
+        assert_eq!(
+            characters.items[0].date_of_birth.as_ref().unwrap().year,
+            Some(2044)
+        );
+        assert_eq!(staff.items[0].favourites, Some(2000));
+        assert_eq!(staff.items[0].date_of_birth.as_ref().unwrap().day, Some(17));
+        assert!(transport.requests()[0].query.contains("isBirthday: true"));
+        assert_eq!(transport.requests()[1].variables["page"], 2);
+        assert_eq!(transport.requests()[1].variables["per_page"], 5);
+    }
+
+    /// Returns a page of characters with the given dates of birth.
+    fn characters_page(
+        page: u16,
+        has_next_page: bool,
+        births: &[(i64, u32, u32)],
+    ) -> serde_json::Value {
+        let characters: Vec<_> = births
+            .iter()
+            .map(|(id, month, day)| {
+                serde_json::json!({
+                    "id": id,
+                    "name": { "first": "", "full": "" },
+                    "image": { "large": "", "medium": "" },
+                    "dateOfBirth": { "month": month, "day": day },
+                    "siteUrl": "",
+                    "favourites": 0
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": { "currentPage": page, "hasNextPage": has_next_page },
+                    "characters": characters
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_characters_with_birthday() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(characters_page(
+            1,
+            true,
+            &[(1, 2, 29), (2, 6, 26), (3, 2, 28)],
+        ));
+        transport.push(characters_page(2, true, &[(4, 2, 29)]));
+        transport.push(characters_page(3, false, &[(5, 2, 29)]));
+        let client = Client::with_transport(transport.clone());
+
+        let characters = client.get_characters_with_birthday(2, 29, 2).await.unwrap();
+
+        let ids: Vec<_> = characters.iter().map(|character| character.id).collect();
+        assert_eq!(ids, vec![1, 4]);
+        assert_eq!(transport.requests().len(), 2);
+        assert_eq!(transport.requests()[1].variables["page"], 2);
+        assert_eq!(transport.requests()[1].variables["per_page"], 50);
+
+        assert!(matches!(
+            client.get_characters_with_birthday(2, 30, 2).await,
+            Err(Error::InvalidDate(_))
+        ));
+        assert!(matches!(
+            client.get_characters_with_birthday(13, 1, 2).await,
+            Err(Error::InvalidDate(_))
+        ));
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    /// Returns a media list entry of the given ID.
+    fn media_list_entry(id: i64) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "userId": 1,
+            "mediaId": id,
+            "status": "CURRENT",
+            "media": { "id": id, "type": "ANIME", "title": { "romaji": "" } }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_user_media_list_chunks() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "MediaListCollection": {
+                    "hasNextChunk": true,
+                    "lists": [
+                        {
+                            "name": "Watching",
+                            "isCustomList": false,
+                            "status": "CURRENT",
+                            "entries": [media_list_entry(1), media_list_entry(2)]
+                        },
+                        {
+                            "name": "Favourites",
+                            "isCustomList": true,
+                            "entries": [media_list_entry(1)]
+                        }
+                    ]
+                }
+            }
+        }));
+        transport.push(serde_json::json!({
+            "data": {
+                "MediaListCollection": {
+                    "hasNextChunk": false,
+                    "lists": [
+                        {
+                            "name": "Watching",
+                            "isCustomList": false,
+                            "status": "CURRENT",
+                            "entries": [media_list_entry(2), media_list_entry(3)]
+                        },
+                        {
+                            "name": "Favourites",
+                            "isCustomList": true,
+                            "entries": [media_list_entry(3)]
+                        }
+                    ]
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+
+        let groups = client
+            .get_user_media_list(1, MediaType::Anime, None)
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].variables["chunk"], 1);
+        assert_eq!(requests[1].variables["chunk"], 2);
+        assert_eq!(requests[1].variables["perChunk"], 500);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].name, "Watching");
+        assert!(!groups[0].is_custom_list);
+        assert_eq!(groups[1].name, "Favourites");
+        assert!(groups[1].is_custom_list);
+
+        let ids = |group: &MediaListGroup| -> Vec<i64> {
+            group.entries.iter().map(|entry| entry.id).collect()
+        };
+        assert_eq!(ids(&groups[0]), vec![1, 2, 3]);
+        assert_eq!(ids(&groups[1]), vec![1, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_compare_lists() {
+        let list = |entries: serde_json::Value| {
+            serde_json::json!({
+                "data": {
+                    "MediaListCollection": {
+                        "lists": [{ "name": "Completed", "entries": entries }]
+                    }
+                }
+            })
+        };
+        let score_format = |format: &str| {
+            serde_json::json!({
+                "data": { "User": { "mediaListOptions": { "scoreFormat": format } } }
+            })
+        };
+
+        let mut entries_a = vec![media_list_entry(1), media_list_entry(2)];
+        entries_a[0]["score"] = 9.into();
+        entries_a[1]["score"] = 5.into();
+        let mut entries_b = vec![
+            media_list_entry(2),
+            media_list_entry(1),
+            media_list_entry(3),
+        ];
+        entries_b[0]["score"] = 50.into();
+        entries_b[1]["score"] = 80.into();
+
+        let transport = Arc::new(MockTransport::new());
+        transport.push(list(entries_a.into()));
+        transport.push(list(entries_b.into()));
+        transport.push(score_format("POINT_10"));
+        transport.push(score_format("POINT_100"));
+        let client = Client::with_transport(transport.clone());
+
+        let comparison = client.compare_lists(1, 2, MediaType::Anime).await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].variables["userId"], 1);
+        assert_eq!(requests[1].variables["userId"], 2);
+        assert_eq!(requests[3].variables["id"], 2);
+
+        assert_eq!(comparison.shared.len(), 2);
+        assert_eq!(comparison.shared[0].score_a, Some(90.0));
+        assert_eq!(comparison.shared[0].score_b, Some(80.0));
+        assert!(comparison.only_a.is_empty());
+        assert_eq!(comparison.only_b[0].media_id, 3);
+        assert!((comparison.correlation.unwrap() - 1.0).abs() < 1e-9);
+
+        assert!(matches!(
+            client.compare_lists(1, 2, MediaType::Character).await,
+            Err(Error::InvalidMediaType(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_hide_adult_content() {
+        let relation = |id: i64, is_adult: bool| {
+            serde_json::json!({
+                "node": {
+                    "id": id,
+                    "title": { "romaji": "Cowboy Bebop", "native": "" },
+                    "type": "ANIME",
+                    "isAdult": is_adult
+                },
+                "id": id,
+                "relationType": "SEQUEL",
+                "isMainStudio": false
+            })
+        };
+        let search = serde_json::json!({
+            "data": { "Page": { "pageInfo": { "currentPage": 1 }, "media": [] } }
+        });
+        let transport = Arc::new(MockTransport::new());
+        transport.push(search.clone());
+        transport.push(search);
+        transport.push(serde_json::json!({
+            "data": {
+                "Media": {
+                    "id": 1,
+                    "title": { "romaji": "Cowboy Bebop", "native": "" },
+                    "format": "TV",
+                    "status": "FINISHED",
+                    "description": "",
+                    "coverImage": {},
+                    "isAdult": false,
+                    "siteUrl": "https://anilist.co/anime/1",
+                    "relations": { "edges": [relation(2, true), relation(3, false)] }
+                }
+            }
+        }));
+        let client = Client::with_transport(transport.clone());
+        let hiding = client.clone().hide_adult_content(true);
+
+        client.search_anime("Bebop", 1, 10).await.unwrap();
+        hiding.search_anime("Bebop", 1, 10).await.unwrap();
+        let anime = hiding.get_anime(1).await.unwrap();
+
+        let requests = transport.requests();
+        assert!(requests[0].variables.get("isAdult").is_none());
+        assert_eq!(requests[1].variables["isAdult"], false);
+        assert!(requests[2].variables.get("isAdult").is_none());
+
+        let relations = anime.relations().unwrap();
+        assert_eq!(relations.len(), 1);
+        assert_eq!(relations[0].id, 3);
+    }
+
+    #[tokio::test]
+    async fn test_cache_per_token() {
+        let transport = Arc::new(MockTransport::new());
+        for is_favourite in [true, false] {
+            let mut anime = cached_anime(1);
+            anime["data"]["Media"]["isFavourite"] = is_favourite.into();
+            transport.push(anime);
+        }
+        let base =
+            Client::with_transport(transport.clone()).with_cache(10, Duration::from_secs(60));
+        let user_a = base.clone().token("token-a");
+        let user_b = base.clone().token("token-b");
+
+        assert_eq!(user_a.get_anime(1).await.unwrap().is_favourite, Some(true));
+        assert_eq!(user_b.get_anime(1).await.unwrap().is_favourite, Some(false));
+        assert_eq!(user_a.get_anime(1).await.unwrap().is_favourite, Some(true));
+        assert_eq!(transport.requests().len(), 2);
+
+        user_b.invalidate(MediaType::Anime, 1);
+        transport.push(cached_anime(1));
+        user_a.get_anime(1).await.unwrap();
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_toggle_favourite_blocked() {
+        let transport = Arc::new(MockTransport::new());
+        let client = Client::with_transport(transport.clone()).token("token");
+
+        let mut anime = Anime {
+            id: 1,
+            is_favourite_blocked: Some(true),
+            client,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            anime.toggle_favourite().await,
+            Err(Error::FavouriteBlocked {
+                media_type: MediaType::Anime,
+                id: 1
+            })
+        ));
+        assert!(transport.requests().is_empty());
     }
 }
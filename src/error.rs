@@ -3,6 +3,13 @@
 
 //! This module contains the `Error` enum.
 
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::MediaType;
+
 /// A specialized `Result` type for operations that can return an `Error`.
 ///
 /// This is defined as a convenience to avoid writing out `std::result::Result`
@@ -12,16 +19,201 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// Represents the various errors that can occur in the application.
 ///
 /// This enum defines different types of errors that can be encountered,
-/// such as invalid IDs and API errors.
+/// such as invalid IDs, missing resources and API errors.
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     /// An error indicating that the ID is invalid.
     #[error("invalid ID")]
     InvalidId,
+    /// An error indicating that the URL is not valid.
+    #[error("invalid URL: `{0}`")]
+    InvalidUrl(String),
+    /// An error indicating that a date is partial or does not exist.
+    #[error("invalid date: `{0}`")]
+    InvalidDate(String),
+    /// An error indicating that a progress exceeds the total of the media.
+    #[error("invalid progress: {progress} exceeds the total of {total}")]
+    InvalidProgress {
+        /// The progress that was given.
+        progress: u32,
+        /// The total number of episodes, chapters or volumes of the media.
+        total: u32,
+    },
+    /// An error indicating that a score is out of the range of scores.
+    #[error("invalid score: `{0}`")]
+    InvalidScore(f64),
+    /// An error indicating that the media type is not valid for the operation.
+    #[error("invalid media type: `{0:?}`")]
+    InvalidMediaType(MediaType),
+    /// An error indicating that an action is not supported for a media
+    /// type, like browsing characters.
+    #[error("unsupported operation: cannot {action} `{media_type:?}`")]
+    UnsupportedOperation {
+        /// The media type the action was requested for.
+        media_type: MediaType,
+        /// The name of the action, like `get` or `search`.
+        action: String,
+    },
+    /// An error indicating that an item is blocked from being a
+    /// favourite, like some adult media.
+    #[error("favourite blocked: `{media_type:?}` {id} cannot be a favourite")]
+    FavouriteBlocked {
+        /// The type of the item.
+        media_type: MediaType,
+        /// The ID of the item.
+        id: i64,
+    },
+    /// An error indicating that the request requires a valid API token.
+    #[error("unauthorized: a valid API token is required")]
+    Unauthorized,
+    /// An error indicating that the API rate limit was exceeded.
+    #[error("rate limited: retry after {retry_after:?}")]
+    RateLimited {
+        /// The time to wait before retrying.
+        retry_after: Duration,
+    },
+    /// An error indicating that the requested resource was not found.
+    #[error("not found")]
+    NotFound,
+    /// An error returned by the GraphQL API.
+    #[error("graphql error: `{message}`")]
+    GraphQl {
+        /// The message of the error.
+        message: String,
+        /// The HTTP status of the error, if any.
+        status: Option<u16>,
+        /// The locations in the query the error refers to.
+        locations: Vec<ErrorLocation>,
+    },
+    /// An error returned by the HTTP client, such as a DNS or TLS
+    /// failure, kept as the source of the error.
+    #[error("http error: `{0}`")]
+    Http(#[source] reqwest::Error),
+    /// An error indicating that the request timed out.
+    #[error("request timed out")]
+    Timeout,
+    /// An error indicating that the API failed with a server error.
+    #[error("server error: HTTP {0}")]
+    ServerError(u16),
+    /// An error indicating that a request kept failing after retrying it.
+    #[error("failed after {attempts} attempts: {source}")]
+    RetriesExhausted {
+        /// The number of attempts made.
+        attempts: u32,
+        /// The error of the last attempt.
+        source: Box<Error>,
+    },
     /// An error indicating that the API returned an error.
     #[error("api error: `{0}`")]
     ApiError(String),
+    /// An error indicating that a field of a response could not be
+    /// deserialized.
+    #[error(
+        "{path}: {message}{}",
+        id.map(|id| format!(" (id {id})")).unwrap_or_default()
+    )]
+    Deserialization {
+        /// The path to the field, like `data.Media.description`.
+        path: String,
+        /// The ID of the media, or of the other object, the field
+        /// belongs to, if known.
+        id: Option<i64>,
+        /// The message of the error.
+        message: String,
+    },
     /// An error indicating that the API returned an invalid response.
     #[error("Failed to parse JSON")]
     JsonParseError(#[from] serde_json::Error),
+    /// An error indicating that a file could not be read or written.
+    #[error("io error: `{0}`")]
+    Io(#[from] std::io::Error),
+}
+
+impl Error {
+    /// Converts an entry of the `errors` array of a GraphQL response
+    /// into an `Error`.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error object returned by the API.
+    pub(crate) fn from_graphql(error: &Value) -> Self {
+        let message = error["message"].as_str().unwrap_or_default().to_string();
+        let status = error["status"].as_u64().map(|status| status as u16);
+
+        match status {
+            Some(404) => Error::NotFound,
+            Some(401) => Error::Unauthorized,
+            _ if message.eq_ignore_ascii_case("invalid token") => Error::Unauthorized,
+            _ => Error::GraphQl {
+                message,
+                status,
+                locations: Vec::<ErrorLocation>::deserialize(&error["locations"])
+                    .unwrap_or_default(),
+            },
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            Error::Timeout
+        } else {
+            Error::Http(error)
+        }
+    }
+}
+
+/// Represents a location in a GraphQL query an error refers to.
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct ErrorLocation {
+    /// The line of the location.
+    pub line: u32,
+    /// The column of the location.
+    pub column: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_graphql_not_found() {
+        let error = serde_json::json!({
+            "message": "Not Found.",
+            "status": 404,
+            "locations": [{ "line": 2, "column": 3 }]
+        });
+
+        assert!(matches!(Error::from_graphql(&error), Error::NotFound));
+    }
+
+    #[test]
+    fn test_from_graphql_unauthorized() {
+        let error = serde_json::json!({ "message": "Invalid token", "status": 400 });
+
+        assert!(matches!(Error::from_graphql(&error), Error::Unauthorized));
+    }
+
+    #[test]
+    fn test_from_graphql_other() {
+        let error = serde_json::json!({
+            "message": "Variable \"$id\" got invalid value",
+            "status": 400,
+            "locations": [{ "line": 1, "column": 8 }]
+        });
+
+        match Error::from_graphql(&error) {
+            Error::GraphQl {
+                message,
+                status,
+                locations,
+            } => {
+                assert_eq!(message, "Variable \"$id\" got invalid value");
+                assert_eq!(status, Some(400));
+                assert_eq!(locations, vec![ErrorLocation { line: 1, column: 8 }]);
+            }
+            error => panic!("unexpected error: {error:?}"),
+        }
+    }
 }
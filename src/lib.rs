@@ -5,9 +5,23 @@
 
 #![deny(missing_docs)]
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod cache;
 mod client;
 mod error;
 pub mod models;
+mod parse;
+mod rate_limit;
+mod retry;
+mod stream;
+mod transport;
 
+/// Re-export of the `async_trait` attribute, to implement `Transport`.
+pub use async_trait::async_trait;
 pub use client::Client;
-pub use error::{Error, Result};
+pub use error::{Error, ErrorLocation, Result};
+pub use rate_limit::RateLimitStatus;
+pub use transport::Transport;
+#[cfg(feature = "test-util")]
+pub use transport::{MockRequest, MockTransport};
@@ -1,14 +1,28 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
 
+use std::{collections::BTreeMap, time::Duration};
+
+use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::{
-    Character, Cover, Date, Format, Link, Person, Relation, Season, Source, Status, Studio, Tag,
-    Title,
+    connection::nullable,
+    description::{render, truncate, Style},
+    link::links_of,
+    media_list::{increment_progress, validate_progress, validate_score},
+    rank::best_rank,
+    tag::{tags_by_category, tags_without_spoilers},
+    Character, CharacterConnection, CharacterRole, Cover, Date, DescriptionFormat, Format, Genre,
+    Language, Link, LinkType, MediaListEntry, MediaListEntryUpdate, MediaListStatus, MediaRank,
+    MediaStats, MediaStatus, MediaType, Person, Recommendation, Relation, RelationConnection,
+    Review, Season, Source, StreamingEpisode, StreamingSite, Studio, Tag, Title, Trailer,
 };
-use crate::{Client, Result};
+use crate::{parse, Client, Error, Result};
+
+/// The number of seconds in a week, the usual cadence of episodes.
+const WEEK: i64 = 7 * 24 * 60 * 60;
 
 /// Represents an anime with various attributes.
 ///
@@ -18,7 +32,7 @@ use crate::{Client, Result};
 /// hashtags, images, genres, synonyms, scores, popularity, tags,
 /// relations, characters, staff, studios, and other metadata.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Anime {
     /// The ID of the anime.
     pub id: i64,
@@ -29,9 +43,12 @@ pub struct Anime {
     /// The format of the anime.
     pub format: Format,
     /// The status of the anime.
-    pub status: Status,
+    pub status: MediaStatus,
     /// The description of the anime.
     pub description: String,
+    /// The format of the description.
+    #[serde(default)]
+    pub description_format: DescriptionFormat,
     /// The start date of the anime.
     pub start_date: Option<Date>,
     /// The end date of the anime.
@@ -44,8 +61,8 @@ pub struct Anime {
     pub season_int: Option<u64>,
     /// The number of episodes of the anime.
     pub episodes: Option<u16>,
-    /// The duration of the episodes of the anime.
-    pub duration: Option<u8>,
+    /// The duration of the episodes of the anime, in minutes.
+    pub duration: Option<u16>,
     /// The country of origin of the anime.
     pub country_of_origin: Option<String>,
     /// Whether the anime is licensed or not.
@@ -62,8 +79,15 @@ pub struct Anime {
     /// The banner image of the anime.
     #[serde(rename = "bannerImage")]
     pub banner: Option<String>,
+    /// The trailer of the anime, if any.
+    #[serde(default)]
+    pub trailer: Option<Trailer>,
+    /// The rankings of the anime, if any.
+    pub rankings: Option<Vec<MediaRank>>,
+    /// The score and list statistics of the anime, if any.
+    pub stats: Option<MediaStats>,
     /// The genres of the anime.
-    pub genres: Option<Vec<String>>,
+    pub genres: Option<Vec<Genre>>,
     /// The synonyms of the anime.
     pub synonyms: Option<Vec<String>>,
     /// The average score of the anime.
@@ -81,9 +105,11 @@ pub struct Anime {
     /// The tags of the anime.
     pub tags: Option<Vec<Tag>>,
     /// The relations of the anime.
-    pub(crate) relations: Value,
+    #[serde(default, deserialize_with = "nullable")]
+    pub(crate) relations: RelationConnection,
     /// The characters of the anime.
-    pub(crate) characters: Value,
+    #[serde(default, deserialize_with = "nullable")]
+    pub(crate) characters: CharacterConnection,
     /// The staff of the anime.
     #[serde(skip)]
     pub staff: Option<Vec<Person>>,
@@ -101,7 +127,13 @@ pub struct Anime {
     /// The external links of the anime.
     pub external_links: Option<Vec<Link>>,
     /// The streaming episodes of the anime.
-    pub streaming_episodes: Option<Vec<Link>>,
+    pub streaming_episodes: Option<Vec<StreamingEpisode>>,
+    /// The role of a character in the anime, when listed from the
+    /// character.
+    pub character_role: Option<CharacterRole>,
+    /// The role of a staff in the anime, like "Director", when listed
+    /// from the staff.
+    pub staff_role: Option<String>,
     /// The site URL of the anime.
     #[serde(rename = "siteUrl")]
     pub url: String,
@@ -110,21 +142,19 @@ pub struct Anime {
     #[serde(skip)]
     pub(crate) client: Client,
     /// Whether the person's data is fully loaded.
-    #[serde(default)]
+    #[serde(skip)]
     pub(crate) is_full_loaded: bool,
 }
 
 impl Anime {
     /// Loads the full details of the anime.
     ///
+    /// If the anime is already fully loaded, it is returned as is.
+    ///
     /// # Errors
     ///
     /// Returns an error if the anime details cannot be loaded.
     ///
-    /// # Panics
-    ///
-    /// Panics if the anime is already fully loaded.
-    ///
     /// # Example
     ///
     /// ```no_run
@@ -136,53 +166,502 @@ impl Anime {
     /// # }
     /// ```
     pub async fn load_full(self) -> Result<Self> {
-        if !self.is_full_loaded {
-            self.client.get_anime(self.id).await
-        } else {
-            panic!("This anime is already full loaded!")
+        if self.is_full_loaded {
+            return Ok(self);
         }
+
+        self.client.invalidate(MediaType::Anime, self.id);
+        let mut anime = self.client.get_anime(self.id).await?;
+        // The roles only come with the character or staff the anime was
+        // listed from, so keep them.
+        if anime.character_role.is_none() {
+            anime.character_role = self.character_role;
+        }
+        if anime.staff_role.is_none() {
+            anime.staff_role = self.staff_role;
+        }
+
+        Ok(anime)
+    }
+
+    /// Sets the number of episodes watched on the authenticated user's
+    /// list, adding the anime to it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `episodes` - The number of episodes watched.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidProgress` if the anime is known to have
+    /// fewer episodes, or an error if the client has no API token or if
+    /// the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// let entry = anime.set_progress(12).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_progress(&self, episodes: u16) -> Result<MediaListEntry> {
+        validate_progress(episodes as u32, self.episodes)?;
+
+        self.client
+            .save_media_list_entry(
+                self.id,
+                MediaListEntryUpdate::new().progress(episodes as u32),
+            )
+            .await
+    }
+
+    /// Marks the next episode of the anime as watched on the
+    /// authenticated user's list.
+    ///
+    /// The current entry of the anime is fetched first. An anime not on
+    /// the list yet, or only planned, is started, and a completed anime
+    /// is rewatched from the first episode. Once the last episode is
+    /// watched, if the number of episodes is known, the anime is marked
+    /// as completed today.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidProgress` if every episode was already
+    /// watched, or an error if the client has no API token or if the
+    /// requests fail.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// let entry = anime.increment_progress().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn increment_progress(&self) -> Result<MediaListEntry> {
+        let entry = self.client.get_viewer_media_list_entry(self.id).await?;
+        let update = increment_progress(entry.as_ref(), self.episodes, Date::now())?;
+
+        self.client.save_media_list_entry(self.id, update).await
+    }
+
+    /// Sets the status of the anime on the authenticated user's list,
+    /// adding the anime to it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The status of the anime on the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token or if the
+    /// request fails.
+    pub async fn set_status(&self, status: MediaListStatus) -> Result<MediaListEntry> {
+        self.client
+            .save_media_list_entry(self.id, MediaListEntryUpdate::new().status(status))
+            .await
+    }
+
+    /// Sets the score of the anime on the authenticated user's list,
+    /// adding the anime to it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The score, in the score format of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidScore` if the score is not between 0 and
+    /// 100, or an error if the client has no API token or if the request
+    /// fails.
+    pub async fn set_score(&self, score: f64) -> Result<MediaListEntry> {
+        validate_score(score)?;
+
+        self.client
+            .save_media_list_entry(self.id, MediaListEntryUpdate::new().score(score))
+            .await
+    }
+
+    /// Toggles whether the anime is a favourite of the authenticated
+    /// user, returning whether it is a favourite afterwards.
+    ///
+    /// The new state is read back from the API and stored in
+    /// `is_favourite`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FavouriteBlocked` if `is_favourite_blocked` is
+    /// set, or `Error::Unauthorized` if the client has no API token,
+    /// both without sending any request. Returns an error if the request
+    /// fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(mut anime: Anime) -> Result<()> {
+    /// let is_favourite = anime.toggle_favourite().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn toggle_favourite(&mut self) -> Result<bool> {
+        let is_favourite = self
+            .client
+            .toggle_favourite_state(MediaType::Anime, self.id, self.is_favourite_blocked)
+            .await?;
+        self.is_favourite = Some(is_favourite);
+
+        Ok(is_favourite)
+    }
+
+    /// Builds a partially loaded anime from a media node of an API
+    /// response, such as the ones returned by searches and lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `media` - The media node to build the anime from.
+    /// * `client` - The client to attach to the anime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node has no ID or an invalid title.
+    pub(crate) fn from_node(media: &Value, client: &Client) -> Result<Self> {
+        Ok(Self {
+            id: media["id"].as_i64().ok_or(Error::InvalidId)?,
+            id_mal: media["idMal"].as_i64(),
+            title: parse::deserialize(media, "title")?,
+            format: Format::deserialize(&media["format"]).unwrap_or_default(),
+            status: MediaStatus::deserialize(&media["status"]).unwrap_or_default(),
+            description: media["description"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            description_format: client.description_format(),
+            season: Season::deserialize(&media["season"]).ok(),
+            season_year: media["seasonYear"].as_u64().map(|x| x as u32),
+            episodes: media["episodes"].as_u64().map(|x| x as u16),
+            cover: Cover::deserialize(&media["coverImage"]).unwrap_or_default(),
+            banner: media["bannerImage"].as_str().map(String::from),
+            average_score: media["averageScore"].as_u64().map(|x| x as u8),
+            mean_score: media["meanScore"].as_u64().map(|x| x as u8),
+            is_adult: media["isAdult"].as_bool().unwrap_or_default(),
+            url: media["siteUrl"].as_str().unwrap_or_default().to_string(),
+
+            client: client.clone(),
+            ..Default::default()
+        })
+    }
+
+    /// Returns a page of the recommendations for the anime, sorted by
+    /// rating.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of recommendations to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the recommendations cannot be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// for recommendation in anime.recommendations(1, 10).await? {
+    ///     println!("{} ({})", recommendation.media.title(), recommendation.rating);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn recommendations(&self, page: u16, per_page: u16) -> Result<Vec<Recommendation>> {
+        self.client
+            .get_recommendations(self.id, page, per_page)
+            .await
     }
 
-    /// Returns the characters of the anime.
+    /// Returns a page of the reviews of the anime, sorted by rating.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of reviews to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reviews cannot be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// for review in anime.reviews(1, 10).await? {
+    ///     println!("{}: {}", review.user.name, review.summary);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reviews(&self, page: u16, per_page: u16) -> Result<Vec<Review>> {
+        self.client.get_reviews(self.id, page, per_page).await
+    }
+
+    /// Returns the characters of the anime, with their role set.
+    ///
+    /// Returns an empty list if the characters were not loaded, like on
+    /// an anime from a search.
+    ///
+    /// # Errors
+    ///
+    /// This function does not currently fail, the `Result` is kept for
+    /// compatibility.
     pub fn characters(&self) -> Result<Vec<Character>> {
-        let binding = Vec::new();
-        let edges = self
-            .characters
-            .as_object()
-            .and_then(|obj| obj.get("edges"))
-            .and_then(|edges| edges.as_array())
-            .unwrap_or(&binding);
-
-        let mut characters = Vec::with_capacity(edges.len());
-
-        for edge in edges {
-            let binding = serde_json::Map::new();
-            let obj = edge.as_object().unwrap_or(&binding);
-            let node = obj.get("node").unwrap_or(&Value::Null);
-            let role = obj.get("role").and_then(|role| role.as_str()).unwrap_or("");
-
-            let mut character: Character = serde_json::from_value(node.clone()).unwrap_or_default();
-            character.role = Some(role.into());
-            characters.push(character);
+        Ok(self.characters.characters())
+    }
+
+    /// Returns whether the anime is currently airing.
+    pub fn is_airing(&self) -> bool {
+        self.status == MediaStatus::Releasing
+    }
+
+    /// Returns whether the anime has finished airing.
+    pub fn is_finished(&self) -> bool {
+        self.status == MediaStatus::Finished
+    }
+
+    /// Returns the number of episodes of the anime aired so far.
+    ///
+    /// The count comes from the next airing episode when scheduled, or
+    /// from the number of episodes once finished, a movie counting as a
+    /// single episode. Returns `None` if it cannot be told, like for an
+    /// airing anime without a schedule.
+    pub fn aired_episodes(&self) -> Option<u16> {
+        if let Some(next) = &self.next_airing_episode {
+            return Some(
+                next.episode
+                    .saturating_sub(1)
+                    .try_into()
+                    .unwrap_or(u16::MAX),
+            );
         }
 
-        Ok(characters)
+        match self.status {
+            MediaStatus::Finished => self
+                .episodes
+                .or((self.format == Format::Movie).then_some(1)),
+            MediaStatus::NotYetReleased => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Returns the duration of an episode of the anime, if known.
+    pub fn episode_duration(&self) -> Option<Duration> {
+        self.duration
+            .map(|minutes| Duration::from_secs(minutes as u64 * 60))
+    }
+
+    /// Returns the time needed to watch every episode of the anime, if
+    /// known, a movie counting as a single episode.
+    pub fn total_watch_time(&self) -> Option<Duration> {
+        let episodes = self
+            .episodes
+            .or((self.format == Format::Movie).then_some(1))?;
+
+        Some(self.episode_duration()? * episodes as u32)
+    }
+
+    /// Returns the expected date of the last episode of the anime.
+    ///
+    /// The date is estimated from the airing time of the next episode,
+    /// assuming an episode airs every week, in the local time zone. The
+    /// end date is returned for a finished anime. Returns `None` if the
+    /// number of episodes or the next airing episode is unknown.
+    pub fn expected_end_date(&self) -> Option<Date> {
+        if self.is_finished() {
+            return self.end_date.clone().filter(Date::is_valid);
+        }
+
+        let next = self.next_airing_episode.as_ref()?;
+        let remaining = (self.episodes? as u32).checked_sub(next.episode)?;
+        let finale = next.at + remaining as i64 * WEEK;
+
+        DateTime::from_timestamp(finale, 0)
+            .map(|finale| Date::from(finale.with_timezone(&Local).date_naive()))
+    }
+
+    /// Returns the description of the anime as plain text.
+    ///
+    /// # Arguments
+    ///
+    /// * `hide_spoilers` - Whether the spoilers are removed, rather than
+    ///   kept as regular text.
+    pub fn description_plain(&self, hide_spoilers: bool) -> String {
+        render(&self.description, Style::Plain, hide_spoilers)
+    }
+
+    /// Returns the description of the anime as markdown.
+    ///
+    /// Italics, bold text and links are kept, and the spoilers are
+    /// masked between `||` markers, as used by Discord and Telegram.
+    ///
+    /// # Arguments
+    ///
+    /// * `hide_spoilers` - Whether the spoilers are removed, rather than
+    ///   masked.
+    pub fn description_markdown(&self, hide_spoilers: bool) -> String {
+        render(&self.description, Style::Markdown, hide_spoilers)
+    }
+
+    /// Returns a preview of the description of the anime, as plain text
+    /// without spoilers.
+    ///
+    /// The preview is cut between graphemes, ending with an ellipsis, if
+    /// longer than `max_len`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_len` - The maximum number of characters of the preview,
+    ///   the ellipsis included.
+    pub fn description_preview(&self, max_len: usize) -> String {
+        truncate(&self.description_plain(true), max_len)
+    }
+
+    /// Returns the sites streaming the anime, without duplicates.
+    ///
+    /// The sites are taken from the streaming episodes and the streaming
+    /// external links of the anime.
+    pub fn streaming_on(&self) -> Vec<StreamingSite> {
+        let episodes = self
+            .streaming_episodes
+            .iter()
+            .flatten()
+            .map(|episode| episode.site.clone());
+        let links = self
+            .external_links
+            .iter()
+            .flatten()
+            .filter(|link| link.link_type == Some(LinkType::Streaming))
+            .map(|link| StreamingSite::from(link.site.as_str()));
+
+        let mut sites = Vec::new();
+        for site in episodes.chain(links) {
+            if !sites.contains(&site) {
+                sites.push(site);
+            }
+        }
+
+        sites
+    }
+
+    /// Returns the external links of the anime of a type.
+    ///
+    /// # Arguments
+    ///
+    /// * `link_type` - The type of the links to return.
+    pub fn external_links_of(&self, link_type: LinkType) -> Vec<&Link> {
+        links_of(self.external_links.as_deref(), link_type)
+    }
+
+    /// Returns the link to the official site of the anime, if any.
+    pub fn official_site(&self) -> Option<&Link> {
+        self.external_links
+            .iter()
+            .flatten()
+            .find(|link| link.is_official_site())
+    }
+
+    /// Returns the all-time score ranking of the anime, if any.
+    pub fn best_rank(&self) -> Option<&MediaRank> {
+        best_rank(self.rankings.as_deref())
+    }
+
+    /// Returns the tags of the anime that are not spoilers.
+    ///
+    /// Returns an empty list if the tags were not loaded.
+    pub fn tags_without_spoilers(&self) -> Vec<&Tag> {
+        tags_without_spoilers(self.tags.as_deref())
+    }
+
+    /// Returns the tags of the anime, spoilers included, grouped by their
+    /// category.
+    pub fn tags_by_category(&self) -> BTreeMap<String, Vec<&Tag>> {
+        tags_by_category(self.tags.as_deref())
+    }
+
+    /// Returns the main characters of the anime, out of the loaded
+    /// characters.
+    pub fn main_characters(&self) -> Vec<Character> {
+        self.characters
+            .characters()
+            .into_iter()
+            .filter(|character| character.role == Some(CharacterRole::Main))
+            .collect()
+    }
+
+    /// Returns the characters of the anime, with their role set, along
+    /// with their voice actors of a language.
+    ///
+    /// The Japanese voice actors come with the fully loaded anime, the
+    /// others are fetched through the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language of the voice actors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::{Anime, Language}, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// let characters = anime.characters_with_voice_actors(Language::English).await?;
+    ///
+    /// for (character, voice_actors) in characters {
+    ///     println!("{}: {}", character.name.full(), voice_actors.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn characters_with_voice_actors(
+        &self,
+        language: Language,
+    ) -> Result<Vec<(Character, Vec<Person>)>> {
+        if self.is_full_loaded && language == Language::Japanese {
+            return Ok(self.characters.characters_with_voice_actors(&self.client));
+        }
+
+        self.client
+            .get_anime_characters(self.id, language, 1, 25)
+            .await
     }
 
     /// Returns the relations of the anime.
+    ///
+    /// Returns an empty list if the relations were not loaded, like on
+    /// an anime from a search.
+    ///
+    /// # Errors
+    ///
+    /// This function does not currently fail, the `Result` is kept for
+    /// compatibility.
     pub fn relations(&self) -> Result<Vec<Relation>> {
-        let binding = Vec::new();
-        let edges = self
-            .relations
-            .as_object()
-            .and_then(|obj| obj.get("edges"))
-            .and_then(|edges| edges.as_array())
-            .unwrap_or(&binding);
-
-        let relations = edges
-            .iter()
-            .map(|r| serde_json::from_value(r.clone()).unwrap_or_default())
-            .collect();
+        let mut relations = self.relations.relations();
+        for relation in &mut relations {
+            relation.client = self.client.clone();
+        }
 
         Ok(relations)
     }
@@ -192,17 +671,510 @@ impl Anime {
 ///
 /// The `AiringSchedule` struct contains information about the airing
 /// schedule of an anime, including the ID, airing date, time until
-/// airing, and the episode number.
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+/// airing, the episode number and the anime that airs.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
 pub struct AiringSchedule {
     /// The ID of the airing schedule.
     pub id: u32,
     /// The airing date.
     #[serde(rename = "airingAt")]
     pub at: i64,
-    /// Time until the airing.
+    /// Time until the airing, negative if it has already aired.
     #[serde(rename = "timeUntilAiring")]
-    pub time_until: u64,
+    pub time_until: i64,
     /// The airing episode.
     pub episode: u32,
+    /// The ID of the anime that airs.
+    #[serde(rename = "mediaId", default)]
+    pub media_id: i64,
+    /// The anime that airs, if requested.
+    #[serde(skip)]
+    pub media: Option<Box<Anime>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_characters_when_not_loaded() {
+        let anime = Anime::default();
+
+        assert_eq!(anime.characters().unwrap(), Vec::new());
+        assert_eq!(anime.relations().unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_deserialize_connections() {
+        let anime: Anime = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "title": { "romaji": "Code Geass", "native": "" },
+            "format": "TV",
+            "status": "FINISHED",
+            "description": "",
+            "coverImage": {},
+            "isAdult": false,
+            "siteUrl": "",
+            "relations": { "edges": null },
+            "characters": {
+                "edges": [
+                    { "node": null, "role": "MAIN" },
+                    { "role": "SUPPORTING" }
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(anime.characters().unwrap(), Vec::new());
+        assert_eq!(anime.relations().unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_deserialize_unknown_variants() {
+        let anime: Anime = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "title": { "romaji": "Code Geass", "native": "" },
+            "format": "HOLOGRAM",
+            "status": "REBOOTING",
+            "description": "",
+            "season": "MONSOON",
+            "source": "DREAM",
+            "coverImage": {},
+            "isAdult": false,
+            "siteUrl": "",
+            "relations": {
+                "edges": [
+                    {
+                        "id": 2,
+                        "relationType": "REMAKE",
+                        "isMainStudio": false,
+                        "node": { "id": 3, "title": { "romaji": "Code Geass R2", "native": "" }, "type": "ANIME" }
+                    }
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(anime.id, 1);
+        assert_eq!(anime.title.romaji(), "Code Geass");
+        assert_eq!(anime.format, Format::Unknown);
+        assert_eq!(anime.status, MediaStatus::Unknown);
+        assert_eq!(anime.season, Some(Season::Unknown));
+        assert_eq!(anime.source, Some(Source::Unknown));
+
+        let relations = anime.relations().unwrap();
+
+        assert_eq!(relations.len(), 1);
+        assert_eq!(
+            relations[0].relation_type,
+            crate::models::RelationType::Unknown
+        );
+    }
+
+    #[test]
+    fn test_characters_keep_role() {
+        let anime = Anime {
+            characters: CharacterConnection {
+                edges: vec![crate::models::CharacterEdge {
+                    node: Some(Character {
+                        id: 1,
+                        ..Default::default()
+                    }),
+                    role: Some(CharacterRole::Main),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let characters = anime.characters().unwrap();
+
+        assert_eq!(characters.len(), 1);
+        assert_eq!(characters[0].role, Some(CharacterRole::Main));
+    }
+
+    #[tokio::test]
+    async fn test_load_full_keeps_roles() {
+        let transport = std::sync::Arc::new(crate::transport::MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Media": {
+                    "id": 1,
+                    "title": { "romaji": "Cowboy Bebop", "native": "" },
+                    "format": "TV",
+                    "status": "FINISHED",
+                    "description": "",
+                    "coverImage": {},
+                    "isAdult": false,
+                    "siteUrl": "https://anilist.co/anime/1"
+                }
+            }
+        }));
+        let anime = Anime {
+            id: 1,
+            character_role: Some(CharacterRole::Main),
+            staff_role: Some("Director".to_string()),
+            client: Client::with_transport(transport),
+            ..Default::default()
+        };
+
+        let anime = anime.load_full().await.unwrap();
+
+        assert!(anime.is_full_loaded);
+        assert_eq!(anime.character_role, Some(CharacterRole::Main));
+        assert_eq!(anime.staff_role.as_deref(), Some("Director"));
+    }
+
+    #[tokio::test]
+    async fn test_embedded_voice_actors() {
+        let mut anime: Anime = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "title": { "romaji": "Code Geass", "native": "" },
+            "format": "TV",
+            "status": "FINISHED",
+            "description": "",
+            "coverImage": {},
+            "isAdult": false,
+            "siteUrl": "",
+            "characters": {
+                "edges": [
+                    {
+                        "node": {
+                            "id": 1,
+                            "name": { "first": "Lelouch", "full": "Lelouch Lamperouge" },
+                            "image": { "large": "", "medium": "" },
+                            "siteUrl": "",
+                            "favourites": 0
+                        },
+                        "role": "MAIN",
+                        "voiceActors": [
+                            { "id": 2, "name": { "first": "Jun", "full": "Jun Fukuyama" }, "languageV2": "Japanese" },
+                            { "id": 3, "name": { "first": "Takahiro", "full": "Takahiro Sakurai" }, "languageV2": "Japanese (Kansai)" }
+                        ]
+                    },
+                    {
+                        "node": {
+                            "id": 4,
+                            "name": { "first": "Rolo", "full": "Rolo Lamperouge" },
+                            "image": { "large": "", "medium": "" },
+                            "siteUrl": "",
+                            "favourites": 0
+                        },
+                        "role": "SUPPORTING",
+                        "voiceActors": null
+                    }
+                ]
+            }
+        }))
+        .unwrap();
+        anime.is_full_loaded = true;
+
+        let characters = anime
+            .characters_with_voice_actors(Language::Japanese)
+            .await
+            .unwrap();
+
+        assert_eq!(characters.len(), 2);
+        assert_eq!(characters[0].0.id, 1);
+        assert_eq!(characters[0].1.len(), 2);
+        assert_eq!(characters[0].1[0].id, 2);
+        // A language unknown to the crate does not drop the voice actor.
+        assert_eq!(characters[0].1[1].id, 3);
+        assert_eq!(characters[0].1[1].language, None);
+        assert!(characters[1].1.is_empty());
+
+        let main_characters = anime.main_characters();
+
+        assert_eq!(main_characters.len(), 1);
+        assert_eq!(main_characters[0].id, 1);
+    }
+
+    #[test]
+    fn test_deserialize_trailer() {
+        let mut value = serde_json::json!({
+            "id": 1,
+            "title": { "romaji": "Cowboy Bebop", "native": "" },
+            "format": "TV",
+            "status": "FINISHED",
+            "description": "",
+            "coverImage": {},
+            "isAdult": false,
+            "siteUrl": "",
+            "trailer": null
+        });
+
+        let anime: Anime = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(anime.trailer, None);
+
+        value["trailer"] = serde_json::json!({
+            "id": "qig4KOK2R2g",
+            "site": "youtube",
+            "thumbnail": "https://i.ytimg.com/vi/qig4KOK2R2g/hqdefault.jpg"
+        });
+
+        let anime: Anime = serde_json::from_value(value).unwrap();
+        assert_eq!(
+            anime.trailer.and_then(|trailer| trailer.url()).as_deref(),
+            Some("https://www.youtube.com/watch?v=qig4KOK2R2g")
+        );
+    }
+
+    #[test]
+    fn test_streaming_on() {
+        let anime: Anime = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "title": { "romaji": "Cowboy Bebop", "native": "" },
+            "format": "TV",
+            "status": "FINISHED",
+            "description": "",
+            "coverImage": {},
+            "isAdult": false,
+            "siteUrl": "",
+            "externalLinks": [
+                { "id": 1, "url": "https://www.crunchyroll.com/", "site": "Crunchyroll", "type": "STREAMING" },
+                { "id": 2, "url": "https://twitter.com/", "site": "Twitter", "type": "SOCIAL" },
+                { "id": 3, "url": "https://www.netflix.com/", "site": "Netflix", "type": "STREAMING" }
+            ],
+            "streamingEpisodes": [
+                { "title": "Episode 1", "url": "https://www.crunchyroll.com/1", "site": "Crunchyroll" },
+                { "title": "Episode 2", "url": "https://www.crunchyroll.com/2", "site": "Crunchyroll" }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            anime.streaming_on(),
+            vec![StreamingSite::Crunchyroll, StreamingSite::Netflix]
+        );
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let anime: Anime = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "idMal": 1,
+            "title": {
+                "romaji": "Cowboy Bebop",
+                "english": "Cowboy Bebop",
+                "native": "カウボーイビバップ",
+                "userPreferred": "Cowboy Bebop"
+            },
+            "format": "TV",
+            "status": "FINISHED",
+            "description": "Enter a world in the distant future...",
+            "startDate": { "year": 1998, "month": 4, "day": 3 },
+            "season": "SPRING",
+            "seasonYear": 1998,
+            "episodes": 26,
+            "duration": 24,
+            "countryOfOrigin": "JP",
+            "source": "ORIGINAL",
+            "coverImage": {
+                "extraLarge": "https://example.com/extra_large.jpg",
+                "large": "https://example.com/large.jpg",
+                "color": "#f1785d"
+            },
+            "bannerImage": "https://example.com/banner.jpg",
+            "trailer": { "id": "qig4KOK2R2g", "site": "youtube" },
+            "rankings": [
+                { "id": 1, "rank": 42, "type": "RATED", "format": "TV", "allTime": true, "context": "highest rated all time" }
+            ],
+            "stats": { "scoreDistribution": [{ "score": 100, "amount": 10 }] },
+            "genres": ["Action"],
+            "averageScore": 86,
+            "isAdult": false,
+            "nextAiringEpisode": null,
+            "externalLinks": [{ "url": "https://www.netflix.com/title/80001305", "site": "Netflix", "type": "STREAMING" }],
+            "characterRole": "MAIN",
+            "siteUrl": "https://anilist.co/anime/1",
+            "relations": {
+                "edges": [
+                    { "id": 2, "relationType": "SIDE_STORY", "isMainStudio": false, "node": { "id": 5, "title": { "romaji": "Cowboy Bebop: Tengoku no Tobira", "native": "" }, "type": "ANIME" } }
+                ]
+            },
+            "characters": {
+                "pageInfo": { "total": 1, "perPage": 25, "currentPage": 1, "lastPage": 1, "hasNextPage": false },
+                "edges": [{
+                    "role": "MAIN",
+                    "node": {
+                        "id": 1,
+                        "name": { "first": "Spike", "last": "Spiegel", "full": "Spike Spiegel" },
+                        "image": { "large": "https://example.com/spike.jpg", "medium": "" },
+                        "siteUrl": "https://anilist.co/character/1"
+                    },
+                    "voiceActors": [{ "id": 95011, "name": { "first": "Kouichi", "full": "Kouichi Yamadera" }, "languageV2": "Japanese" }]
+                }]
+            }
+        }))
+        .unwrap();
+
+        let json = serde_json::to_string(&anime).unwrap();
+
+        assert!(json.contains("\"coverImage\""));
+        assert!(!json.contains("is_full_loaded"));
+        assert_eq!(serde_json::from_str::<Anime>(&json).unwrap(), anime);
+    }
+
+    #[test]
+    fn test_aired_episodes() {
+        let airing = Anime {
+            status: MediaStatus::Releasing,
+            episodes: Some(12),
+            next_airing_episode: Some(AiringSchedule {
+                episode: 5,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let finished = Anime {
+            status: MediaStatus::Finished,
+            episodes: Some(12),
+            ..Default::default()
+        };
+        let movie = Anime {
+            status: MediaStatus::Finished,
+            format: Format::Movie,
+            ..Default::default()
+        };
+        let hiatus = Anime {
+            status: MediaStatus::Hiatus,
+            ..Default::default()
+        };
+
+        assert!(airing.is_airing());
+        assert!(!airing.is_finished());
+        assert!(finished.is_finished());
+        assert_eq!(airing.aired_episodes(), Some(4));
+        assert_eq!(finished.aired_episodes(), Some(12));
+        assert_eq!(movie.aired_episodes(), Some(1));
+        assert_eq!(hiatus.aired_episodes(), None);
+        assert_eq!(Anime::default().aired_episodes(), Some(0));
+    }
+
+    #[test]
+    fn test_expected_end_date() {
+        // Episode 10 airs at 2025-01-06 12:00:00 UTC.
+        let next_airing_episode = AiringSchedule {
+            at: 1736164800,
+            episode: 10,
+            ..Default::default()
+        };
+        let airing = Anime {
+            status: MediaStatus::Releasing,
+            episodes: Some(12),
+            next_airing_episode: Some(next_airing_episode.clone()),
+            ..Default::default()
+        };
+        let expected = DateTime::from_timestamp(1736164800 + 2 * WEEK, 0)
+            .unwrap()
+            .with_timezone(&Local)
+            .date_naive();
+
+        assert_eq!(airing.expected_end_date(), Some(Date::from(expected)));
+        assert_eq!(
+            Anime {
+                episodes: None,
+                ..airing.clone()
+            }
+            .expected_end_date(),
+            None
+        );
+        assert_eq!(
+            Anime {
+                episodes: Some(8),
+                ..airing.clone()
+            }
+            .expected_end_date(),
+            None
+        );
+        assert_eq!(
+            Anime {
+                status: MediaStatus::Finished,
+                end_date: Some(Date::new(Some(2024), Some(3), Some(30))),
+                next_airing_episode: None,
+                ..airing
+            }
+            .expected_end_date(),
+            Some(Date::new(Some(2024), Some(3), Some(30)))
+        );
+    }
+
+    #[test]
+    fn test_description() {
+        let anime = Anime {
+            description: "Lelouch <i>vi Britannia</i>.<br><br>~!He dies.!~ The end.".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            anime.description_plain(false),
+            "Lelouch vi Britannia.\n\nHe dies. The end."
+        );
+        assert_eq!(
+            anime.description_markdown(false),
+            "Lelouch _vi Britannia_.\n\n||He dies.|| The end."
+        );
+        assert_eq!(anime.description_preview(12), "Lelouch vi…");
+        assert_eq!(
+            anime.description_preview(100),
+            "Lelouch vi Britannia.\n\nThe end."
+        );
+    }
+
+    #[test]
+    fn test_long_movie_duration() {
+        let anime: Anime = serde_json::from_value(serde_json::json!({
+            "id": 100,
+            "title": { "romaji": "Long Movie", "native": "" },
+            "format": "MOVIE",
+            "status": "FINISHED",
+            "description": "",
+            "duration": 263,
+            "coverImage": {},
+            "isAdult": false,
+            "siteUrl": "https://anilist.co/anime/100"
+        }))
+        .unwrap();
+
+        assert_eq!(anime.duration, Some(263));
+        assert_eq!(
+            anime.episode_duration(),
+            Some(Duration::from_secs(263 * 60))
+        );
+        assert_eq!(
+            anime.total_watch_time(),
+            Some(Duration::from_secs(263 * 60))
+        );
+    }
+
+    #[test]
+    fn test_total_watch_time() {
+        let anime = Anime {
+            episodes: Some(26),
+            duration: Some(24),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            anime.total_watch_time(),
+            Some(Duration::from_secs(26 * 24 * 60))
+        );
+        assert_eq!(
+            Anime {
+                episodes: None,
+                ..anime.clone()
+            }
+            .total_watch_time(),
+            None
+        );
+        assert_eq!(
+            Anime {
+                duration: None,
+                ..anime
+            }
+            .total_watch_time(),
+            None
+        );
+    }
 }
@@ -6,14 +6,18 @@
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
+
 use serde_json::Value;
 
-use super::{Date, Gender, Image, Name, Person};
-use crate::{Client, Result};
+use super::{
+    connection::nullable, media::collect_pages, Date, DescriptionFormat, Gender, Image, Media,
+    MediaConnection, MediaType, Name, Person,
+};
+use crate::{parse, Client, Error, Result};
 
 /// Represents a character.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Character {
     /// The ID of the character.
     pub id: i64,
@@ -24,7 +28,11 @@ pub struct Character {
     /// The image of the character.
     pub image: Image,
     /// The description of the character.
+    #[serde(default, deserialize_with = "nullable")]
     pub description: String,
+    /// The format of the description.
+    #[serde(default)]
+    pub description_format: DescriptionFormat,
     /// The gender of the character.
     pub gender: Option<Gender>,
     /// The date of birth of the character.
@@ -33,9 +41,9 @@ pub struct Character {
     pub age: Option<String>,
     /// The blood type of the character.
     pub blood_type: Option<String>,
-    /// The medias that the character participates.
-    #[serde(rename = "media")]
-    pub(crate) medias: Option<Value>,
+    /// The media that the character appears in.
+    #[serde(rename = "media", default, deserialize_with = "nullable")]
+    pub(crate) medias: MediaConnection,
     /// Whether the character is a favorite.
     pub is_favourite: Option<bool>,
     /// Whether the character is blocked from being a favorite.
@@ -54,21 +62,19 @@ pub struct Character {
     #[serde(skip)]
     pub(crate) client: Client,
     /// Whether the person's data is fully loaded.
-    #[serde(default)]
+    #[serde(skip)]
     pub(crate) is_full_loaded: bool,
 }
 
 impl Character {
     /// Loads the full details of the character.
     ///
+    /// If the character is already fully loaded, it is returned as is.
+    ///
     /// # Errors
     ///
     /// Returns an error if the character details cannot be loaded.
     ///
-    /// # Panics
-    ///
-    /// Panics if the character is already fully loaded.
-    ///
     /// # Example
     ///
     /// ```no_run
@@ -79,14 +85,135 @@ impl Character {
     /// # Ok(())
     /// # }
     pub async fn load_full(self) -> Result<Self> {
-        if !self.is_full_loaded {
-            self.client.get_character(self.id).await
-        } else {
-            panic!("This character is already full loaded")
+        if self.is_full_loaded {
+            return Ok(self);
+        }
+
+        self.client.invalidate(MediaType::Character, self.id);
+        let mut character = self.client.get_character(self.id).await?;
+        // The role and voice actors only come with the media the
+        // character was fetched from, so keep them.
+        if character.role.is_none() {
+            character.role = self.role;
+        }
+        if character.voice_actors.is_none() {
+            character.voice_actors = self.voice_actors;
+        }
+
+        Ok(character)
+    }
+
+    /// Toggles whether the character is a favourite of the authenticated
+    /// user, returning whether it is a favourite afterwards.
+    ///
+    /// The new state is read back from the API and stored in
+    /// `is_favourite`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FavouriteBlocked` if `is_favourite_blocked` is
+    /// set, or `Error::Unauthorized` if the client has no API token,
+    /// both without sending any request. Returns an error if the request
+    /// fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Character, Result};
+    /// #
+    /// # async fn f(mut character: Character) -> Result<()> {
+    /// let is_favourite = character.toggle_favourite().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn toggle_favourite(&mut self) -> Result<bool> {
+        let is_favourite = self
+            .client
+            .toggle_favourite_state(MediaType::Character, self.id, self.is_favourite_blocked)
+            .await?;
+        self.is_favourite = Some(is_favourite);
+
+        Ok(is_favourite)
+    }
+
+    /// Builds a partially loaded character from a character node of an
+    /// API response, such as the ones returned by searches.
+    ///
+    /// # Arguments
+    ///
+    /// * `character` - The character node to build the character from.
+    /// * `client` - The client to attach to the character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node has no ID or an invalid name or image.
+    pub(crate) fn from_node(character: &Value, client: &Client) -> Result<Self> {
+        Ok(Self {
+            id: character["id"].as_i64().ok_or(Error::InvalidId)?,
+            name: parse::deserialize(character, "name")?,
+            image: parse::deserialize(character, "image")?,
+            description: character["description"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            description_format: client.description_format(),
+            gender: Gender::deserialize(&character["gender"]).ok(),
+            date_of_birth: Date::deserialize(&character["dateOfBirth"]).ok(),
+            age: character["age"].as_str().map(String::from),
+            blood_type: character["bloodType"].as_str().map(String::from),
+            url: character["siteUrl"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            favourites: character["favourites"].as_i64(),
+            is_favourite: character["isFavourite"].as_bool(),
+
+            client: client.clone(),
+            ..Default::default()
+        })
+    }
+
+    /// Returns a page of the media the character appears in, with the
+    /// role of the character set, sorted by popularity.
+    ///
+    /// The first media come with the fully loaded character, the others
+    /// are fetched through the client when needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of media to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the media cannot be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Character, Result};
+    /// #
+    /// # async fn f(character: Character) -> Result<()> {
+    /// for media in character.media(1, 10).await? {
+    ///     println!("{} ({:?})", media.title(), media.character_role());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn media(&self, page: u16, per_page: u16) -> Result<Vec<Media>> {
+        match self.medias.page(page, per_page) {
+            Some(connection) => Ok(connection.media(&self.client)),
+            None => {
+                self.client
+                    .get_character_media(self.id, page, per_page)
+                    .await
+            }
         }
     }
 
-    /// Retrieves the media associated with the chcharacterr.
+    /// Retrieves the media associated with the character.
+    ///
+    /// Every page of media is fetched, keeping only the ones of type `T`.
     ///
     /// # Errors
     ///
@@ -99,6 +226,7 @@ impl Character {
     /// # Example
     ///
     /// ```no_run
+    /// # #![allow(deprecated)]
     /// # use rust_anilist::{models::{Manga, Character}, Result};
     /// #
     /// # async fn f(character: Character) -> Result<()> {
@@ -106,29 +234,84 @@ impl Character {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_medias<T>(&self) -> Result<Vec<T>> {
-        unimplemented!()
+    #[deprecated(note = "use `Character::media` instead")]
+    pub async fn get_medias<T: TryFrom<Media>>(&self) -> Result<Vec<T>> {
+        collect_pages(|page, per_page| self.media(page, per_page)).await
+    }
+
+    /// Returns whether today is the birthday of the character.
+    ///
+    /// The year of birth is ignored, and it is never the birthday of a
+    /// character whose day or month of birth is unknown.
+    pub fn is_birthday(&self) -> bool {
+        self.is_birthday_on(&Date::now())
+    }
+
+    /// Returns whether a date is the birthday of the character.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date to compare with the date of birth.
+    fn is_birthday_on(&self, date: &Date) -> bool {
+        match &self.date_of_birth {
+            Some(birth) => {
+                birth.month.is_some()
+                    && birth.day.is_some()
+                    && birth.month == date.month
+                    && birth.day == date.day
+            }
+            None => false,
+        }
     }
 }
 
 /// Represents the role of a character in a story.
-#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+///
+/// The roles are ordered by importance, so sorting characters by their
+/// role puts the main characters first and the background ones last.
+#[derive(Debug, Default, Clone, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE", from = "String")]
 pub enum CharacterRole {
-    /// A background character.
-    #[default]
-    Background,
     /// A main character.
     Main,
     /// A supporting character.
     Supporting,
+    /// A background character.
+    #[default]
+    Background,
+    /// A role not known to this version of the crate.
+    Unknown,
+}
+
+impl CharacterRole {
+    /// Returns the name of the role.
+    pub fn name(&self) -> &str {
+        match self {
+            CharacterRole::Main => "Main",
+            CharacterRole::Supporting => "Supporting",
+            CharacterRole::Background => "Background",
+            CharacterRole::Unknown => "Unknown",
+        }
+    }
+
+    /// Returns a summary of the role.
+    pub fn summary(&self) -> &str {
+        match self {
+            CharacterRole::Main => "A primary character of the story",
+            CharacterRole::Supporting => "A supporting character of the story",
+            CharacterRole::Background => "A minor character in the background of the story",
+            CharacterRole::Unknown => "A role not known to this version of the crate",
+        }
+    }
 }
 
 impl From<&str> for CharacterRole {
     fn from(value: &str) -> Self {
-        match value {
+        match value.trim().to_uppercase().as_str() {
             "MAIN" => CharacterRole::Main,
             "SUPPORTING" => CharacterRole::Supporting,
-            _ => CharacterRole::Background,
+            "BACKGROUND" => CharacterRole::Background,
+            _ => CharacterRole::Unknown,
         }
     }
 }
@@ -141,10 +324,263 @@ impl From<String> for CharacterRole {
 
 impl Display for CharacterRole {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            CharacterRole::Background => write!(f, "Background"),
-            CharacterRole::Main => write!(f, "Main"),
-            CharacterRole::Supporting => write!(f, "Supporting"),
-        }
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{Anime, Manga},
+        transport::MockTransport,
+    };
+
+    #[tokio::test]
+    async fn test_load_full_when_already_loaded() {
+        let character = Character {
+            id: 1,
+            is_full_loaded: true,
+            ..Default::default()
+        };
+
+        assert_eq!(character.clone().load_full().await.unwrap(), character);
+    }
+
+    #[tokio::test]
+    async fn test_load_full_keeps_role() {
+        let transport = MockTransport::new();
+        transport.push(serde_json::json!({
+            "data": {
+                "Character": {
+                    "id": 1,
+                    "name": { "first": "Spike", "full": "Spike Spiegel", "alternative": [] },
+                    "image": { "large": "", "medium": "" },
+                    "description": "",
+                    "siteUrl": "https://anilist.co/character/1"
+                }
+            }
+        }));
+        let character = Character {
+            id: 1,
+            role: Some(CharacterRole::Main),
+            client: Client::with_transport(transport),
+            ..Default::default()
+        };
+
+        let character = character.load_full().await.unwrap();
+
+        assert!(character.is_full_loaded);
+        assert_eq!(character.role, Some(CharacterRole::Main));
+    }
+
+    fn media_connection(ids: std::ops::Range<i64>, has_next_page: bool) -> serde_json::Value {
+        serde_json::json!({
+            "pageInfo": { "hasNextPage": has_next_page },
+            "edges": ids
+                .map(|id| serde_json::json!({
+                    "node": { "id": id, "title": { "romaji": "", "native": "" }, "type": "ANIME" },
+                    "characterRole": "SUPPORTING"
+                }))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_media_from_embedded_page() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        let character = Character {
+            id: 1,
+            medias: serde_json::from_value(media_connection(1..6, true)).unwrap(),
+            client: Client::with_transport(transport.clone()),
+            ..Default::default()
+        };
+
+        let media = character.media(2, 2).await.unwrap();
+
+        assert_eq!(media.iter().map(Media::id).collect::<Vec<_>>(), vec![3, 4]);
+        assert_eq!(media[0].character_role(), Some(&CharacterRole::Supporting));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_media_past_embedded_page() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": { "Character": { "media": media_connection(7..9, false) } }
+        }));
+        let character = Character {
+            id: 1,
+            medias: serde_json::from_value(media_connection(1..6, true)).unwrap(),
+            client: Client::with_transport(transport.clone()),
+            ..Default::default()
+        };
+
+        let media = character.media(4, 2).await.unwrap();
+
+        assert_eq!(media.iter().map(Media::id).collect::<Vec<_>>(), vec![7, 8]);
+        assert_eq!(transport.requests()[0].variables["page"], 4);
+    }
+
+    #[tokio::test]
+    async fn test_media_when_all_embedded() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        let character = Character {
+            id: 1,
+            medias: serde_json::from_value(media_connection(1..3, false)).unwrap(),
+            client: Client::with_transport(transport.clone()),
+            ..Default::default()
+        };
+
+        assert!(character.media(2, 5).await.unwrap().is_empty());
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn test_get_medias() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        let character = Character {
+            id: 1,
+            medias: serde_json::from_value(media_connection(1..3, false)).unwrap(),
+            client: Client::with_transport(transport.clone()),
+            ..Default::default()
+        };
+
+        let animes = character.get_medias::<Anime>().await.unwrap();
+        let mangas = character.get_medias::<Manga>().await.unwrap();
+
+        assert_eq!(animes.iter().map(|a| a.id).collect::<Vec<_>>(), vec![1, 2]);
+        assert!(mangas.is_empty());
+        assert!(transport.requests().is_empty());
+    }
+
+    #[test]
+    fn test_is_birthday_on() {
+        let character = Character {
+            date_of_birth: Some(Date::new(None, Some(12), Some(5))),
+            ..Default::default()
+        };
+
+        assert!(character.is_birthday_on(&Date::new(Some(2024), Some(12), Some(5))));
+        assert!(!character.is_birthday_on(&Date::new(Some(2024), Some(12), Some(6))));
+        assert!(!character.is_birthday_on(&Date::new(Some(2024), Some(5), Some(12))));
+    }
+
+    #[test]
+    fn test_is_birthday_on_unknown_date_of_birth() {
+        let date = Date::new(Some(2024), Some(1), Some(1));
+
+        assert!(!Character::default().is_birthday_on(&date));
+
+        let character = Character {
+            date_of_birth: Some(Date::new(None, Some(1), None)),
+            ..Default::default()
+        };
+        assert!(!character.is_birthday_on(&date));
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let character: Character = serde_json::from_value(serde_json::json!({
+            "id": 417,
+            "name": {
+                "first": "Lelouch",
+                "last": "Lamperouge",
+                "full": "Lelouch Lamperouge",
+                "native": "ルルーシュ・ランペルージ",
+                "alternative": ["Zero"]
+            },
+            "image": { "large": "https://example.com/large.jpg", "medium": "https://example.com/medium.jpg" },
+            "description": "Lelouch is the exiled prince of Britannia...",
+            "gender": "Male",
+            "dateOfBirth": { "year": null, "month": 12, "day": 5 },
+            "age": "17-18",
+            "bloodType": "A",
+            "media": {
+                "edges": [
+                    { "characterRole": "MAIN", "node": { "id": 1575, "title": { "romaji": "Code Geass", "native": "" }, "type": "ANIME" } }
+                ]
+            },
+            "siteUrl": "https://anilist.co/character/417",
+            "favourites": 100
+        }))
+        .unwrap();
+
+        let json = serde_json::to_string(&character).unwrap();
+
+        assert_eq!(serde_json::from_str::<Character>(&json).unwrap(), character);
+    }
+
+    #[test]
+    fn test_from_node_with_unusual_gender() {
+        let character = Character::from_node(
+            &serde_json::json!({
+                "id": 1,
+                "name": { "first": "Haruhi", "full": "Haruhi Fujioka" },
+                "image": { "large": "", "medium": "" },
+                "gender": "Female (Male as Haruhi)",
+                "siteUrl": "",
+                "favourites": 0
+            }),
+            &Client::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            character.gender,
+            Some(Gender::Other("Female (Male as Haruhi)".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_character_role_from_str() {
+        assert_eq!(CharacterRole::from("MAIN"), CharacterRole::Main);
+        assert_eq!(CharacterRole::from("Supporting"), CharacterRole::Supporting);
+        assert_eq!(
+            CharacterRole::from(" background "),
+            CharacterRole::Background
+        );
+        assert_eq!(CharacterRole::from("CAMEO"), CharacterRole::Unknown);
+        assert_eq!(CharacterRole::from(""), CharacterRole::Unknown);
+    }
+
+    #[test]
+    fn test_character_role_serde() {
+        assert_eq!(
+            serde_json::from_value::<CharacterRole>(serde_json::json!("main")).unwrap(),
+            CharacterRole::Main
+        );
+        assert_eq!(
+            serde_json::from_value::<CharacterRole>(serde_json::json!("CAMEO")).unwrap(),
+            CharacterRole::Unknown
+        );
+        assert_eq!(
+            serde_json::to_value(CharacterRole::Supporting).unwrap(),
+            serde_json::json!("SUPPORTING")
+        );
+    }
+
+    #[test]
+    fn test_character_role_order() {
+        let mut roles = vec![
+            CharacterRole::Background,
+            CharacterRole::Unknown,
+            CharacterRole::Main,
+            CharacterRole::Supporting,
+        ];
+        roles.sort();
+
+        assert_eq!(
+            roles,
+            vec![
+                CharacterRole::Main,
+                CharacterRole::Supporting,
+                CharacterRole::Background,
+                CharacterRole::Unknown,
+            ]
+        );
+        assert_eq!(CharacterRole::Main.to_string(), "Main");
+        assert_eq!(CharacterRole::Unknown.to_string(), "Unknown");
     }
 }
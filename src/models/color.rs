@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
 
-//! This module contains the `Color` struct.
+//! This module contains the `Color` enum and the `ColorParseError` struct.
 
 use serde::{Deserialize, Serialize};
 
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 /// associated variant. Additionally, it supports custom colors defined
 /// by a hex string.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "UPPERCASE"))]
+#[serde(rename_all = "UPPERCASE")]
 pub enum Color {
     /// The blue color.
     Blue,
@@ -34,6 +34,37 @@ pub enum Color {
 }
 
 impl Color {
+    /// Creates a color from a `#RRGGBB` or `#RGB` hex string.
+    ///
+    /// Letters are accepted in either case, the hex string is kept as
+    /// given, without surrounding whitespace.
+    ///
+    /// # Arguments
+    ///
+    /// * `hex` - The hex string of the color.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ColorParseError` if the string is not a valid hex color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Color;
+    /// let color = Color::try_from_hex("#f1785d").unwrap();
+    ///
+    /// assert_eq!(color.to_rgb(), Some((241, 120, 93)));
+    /// assert!(Color::try_from_hex("f1785d").is_err());
+    /// ```
+    pub fn try_from_hex(hex: &str) -> Result<Self, ColorParseError> {
+        let hex = hex.trim();
+
+        match parse_hex(hex) {
+            Some(_) => Ok(Color::Hex(hex.to_string())),
+            None => Err(ColorParseError(hex.to_string())),
+        }
+    }
+
     /// Returns the hex value of the color.
     pub fn hex(&self) -> Option<&str> {
         match self {
@@ -41,8 +72,50 @@ impl Color {
             _ => None,
         }
     }
+
+    /// Returns the red, green and blue channels of the color.
+    ///
+    /// The predefined colors map to the values of the AniList palette,
+    /// and hex colors are parsed, returning `None` if malformed.
+    pub fn to_rgb(&self) -> Option<(u8, u8, u8)> {
+        match self {
+            Color::Blue => Some((61, 180, 242)),
+            Color::Purple => Some((192, 99, 255)),
+            Color::Pink => Some((252, 157, 214)),
+            Color::Orange => Some((239, 136, 26)),
+            Color::Red => Some((225, 51, 51)),
+            Color::Green => Some((76, 202, 81)),
+            Color::Gray => Some((103, 123, 148)),
+            Color::Hex(hex) => parse_hex(hex.trim()),
+        }
+    }
+}
+
+/// Parses a `#RRGGBB` or `#RGB` hex string into its channels.
+fn parse_hex(hex: &str) -> Option<(u8, u8, u8)> {
+    let digits = hex.strip_prefix('#')?;
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |range: std::ops::Range<usize>| u8::from_str_radix(&digits[range], 16).ok();
+
+    match digits.len() {
+        6 => Some((channel(0..2)?, channel(2..4)?, channel(4..6)?)),
+        3 => {
+            let (r, g, b) = (channel(0..1)?, channel(1..2)?, channel(2..3)?);
+
+            Some((r * 17, g * 17, b * 17))
+        }
+        _ => None,
+    }
 }
 
+/// The error returned when a string is not a valid hex color.
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+#[error("invalid hex color: {0:?}")]
+pub struct ColorParseError(pub String);
+
 impl From<&str> for Color {
     fn from(value: &str) -> Self {
         match value.trim().to_uppercase().as_str() {
@@ -131,4 +204,42 @@ mod tests {
             Color::Hex("#FF5733".to_string())
         );
     }
+
+    #[test]
+    fn test_to_rgb_predefined_colors() {
+        assert_eq!(Color::Blue.to_rgb(), Some((61, 180, 242)));
+        assert_eq!(Color::Gray.to_rgb(), Some((103, 123, 148)));
+    }
+
+    #[test]
+    fn test_to_rgb_cover_colors() {
+        let color: Color = serde_json::from_value(serde_json::json!("#f1785d")).unwrap();
+
+        assert_eq!(color, Color::Hex("#f1785d".to_string()));
+        assert_eq!(color.to_rgb(), Some((241, 120, 93)));
+        assert_eq!(Color::from("#F1785D").to_rgb(), Some((241, 120, 93)));
+        assert_eq!(Color::from("#fA0").to_rgb(), Some((255, 170, 0)));
+        assert_eq!(Color::from("#f1785").to_rgb(), None);
+    }
+
+    #[test]
+    fn test_try_from_hex() {
+        assert_eq!(
+            Color::try_from_hex(" #e4a15d "),
+            Ok(Color::Hex("#e4a15d".to_string()))
+        );
+        assert_eq!(
+            Color::try_from_hex("#E4A15D"),
+            Ok(Color::Hex("#E4A15D".to_string()))
+        );
+        assert!(Color::try_from_hex("#abc").is_ok());
+        assert_eq!(
+            Color::try_from_hex("e4a15d"),
+            Err(ColorParseError("e4a15d".to_string()))
+        );
+        assert!(Color::try_from_hex("#e4a15g").is_err());
+        assert!(Color::try_from_hex("#+1+2+3").is_err());
+        assert!(Color::try_from_hex("#é1").is_err());
+        assert!(Color::try_from_hex("blue").is_err());
+    }
 }
@@ -0,0 +1,464 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `CharacterConnection`, `MediaConnection`
+//! and `RelationConnection` structs and their related types.
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use super::{Character, CharacterRole, DescriptionFormat, Media, PageInfo, Person, Relation};
+use crate::Client;
+
+/// Represents the characters of a media.
+///
+/// The `CharacterConnection` struct holds the character edges of an
+/// anime or manga, each one linking a character to its role.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterConnection {
+    /// The pagination information of the edges, if loaded.
+    pub page_info: Option<PageInfo>,
+    /// The character edges.
+    #[serde(default, deserialize_with = "nullable")]
+    pub edges: Vec<CharacterEdge>,
+}
+
+/// Represents a character of a media, its role and its voice actors.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CharacterEdge {
+    /// The character, if any.
+    #[serde(default)]
+    pub node: Option<Character>,
+    /// The role of the character in the media.
+    #[serde(default)]
+    pub role: Option<CharacterRole>,
+    /// The voice actors of the character, if loaded.
+    #[serde(default, rename = "voiceActors", deserialize_with = "person_nodes")]
+    pub(crate) voice_actors: Vec<Person>,
+}
+
+/// Represents the media a character or staff appears in.
+///
+/// The `MediaConnection` struct holds the media edges of a character or
+/// staff, each one linking an anime or manga to their role in it, along
+/// with the pagination information of the edges.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaConnection {
+    /// The pagination information of the edges, if loaded.
+    pub page_info: Option<PageInfo>,
+    /// The media edges.
+    #[serde(default, deserialize_with = "nullable")]
+    pub edges: Vec<MediaEdge>,
+}
+
+/// Represents a media a character or staff appears in and its role.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaEdge {
+    /// The media, if any.
+    #[serde(default, deserialize_with = "media_node")]
+    pub(crate) node: Option<Media>,
+    /// The role of the character in the media, if listed from a
+    /// character.
+    #[serde(default)]
+    pub character_role: Option<CharacterRole>,
+    /// The role of the staff in the media, if listed from a staff.
+    #[serde(default)]
+    pub staff_role: Option<String>,
+}
+
+/// Represents the relations of a media.
+///
+/// The `RelationConnection` struct holds the relation edges of an anime
+/// or manga, each one linking it to a related media.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RelationConnection {
+    /// The relation edges.
+    #[serde(default, deserialize_with = "nullable")]
+    pub edges: Vec<Relation>,
+}
+
+impl CharacterConnection {
+    /// Returns the edges of a page, if they are all embedded.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of edges per page.
+    pub(crate) fn page(&self, page: u16, per_page: u16) -> Option<Self> {
+        Some(Self {
+            page_info: None,
+            edges: embedded_page(&self.edges, self.page_info.as_ref(), page, per_page)?,
+        })
+    }
+
+    /// Sets the format of the descriptions of the characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The format the descriptions were requested in.
+    pub(crate) fn set_description_format(&mut self, format: DescriptionFormat) {
+        for character in self.edges.iter_mut().filter_map(|edge| edge.node.as_mut()) {
+            character.description_format = format;
+        }
+    }
+
+    /// Returns the characters, with their role set.
+    ///
+    /// Edges without a character are skipped.
+    pub fn characters(&self) -> Vec<Character> {
+        self.edges
+            .iter()
+            .filter_map(|edge| {
+                let mut character = edge.node.clone()?;
+                character.role = edge.role.clone();
+
+                Some(character)
+            })
+            .collect()
+    }
+
+    /// Returns the characters, with their role and their voice actors
+    /// set, each paired with those voice actors.
+    ///
+    /// The voice actors are the ones of the language requested from the
+    /// API, they are not filtered again as their language may not be
+    /// known to this version of the crate. Edges without a character are
+    /// skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to attach to the voice actors.
+    pub(crate) fn characters_with_voice_actors(
+        &self,
+        client: &Client,
+    ) -> Vec<(Character, Vec<Person>)> {
+        let mut characters = Vec::with_capacity(self.edges.len());
+
+        for edge in &self.edges {
+            let Some(mut character) = edge.node.clone() else {
+                continue;
+            };
+            character.role = edge.role.clone();
+
+            let mut voice_actors = Vec::with_capacity(edge.voice_actors.len());
+            for person in &edge.voice_actors {
+                let mut person = person.clone();
+                person.description_format = client.description_format();
+                person.client = client.clone();
+                voice_actors.push(person);
+            }
+
+            character.voice_actors = Some(voice_actors.clone());
+            characters.push((character, voice_actors));
+        }
+
+        characters
+    }
+}
+
+impl MediaConnection {
+    /// Returns the edges of a page, if they are all embedded.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of edges per page.
+    pub(crate) fn page(&self, page: u16, per_page: u16) -> Option<Self> {
+        Some(Self {
+            page_info: None,
+            edges: embedded_page(&self.edges, self.page_info.as_ref(), page, per_page)?,
+        })
+    }
+
+    /// Returns the media, with the role of the character or staff set.
+    ///
+    /// Edges without a media are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to attach to the media.
+    pub(crate) fn media(&self, client: &Client) -> Vec<Media> {
+        let mut media = Vec::with_capacity(self.edges.len());
+
+        for edge in &self.edges {
+            let Some(mut item) = edge.node.clone() else {
+                continue;
+            };
+
+            attach_client(&mut item, client);
+            match &mut item {
+                Media::Anime(anime) => {
+                    anime.character_role = edge.character_role.clone();
+                    anime.staff_role = edge.staff_role.clone();
+                }
+                Media::Manga(manga) => {
+                    manga.character_role = edge.character_role.clone();
+                    manga.staff_role = edge.staff_role.clone();
+                }
+                Media::Unknown => {}
+            }
+            media.push(item);
+        }
+
+        media
+    }
+}
+
+impl RelationConnection {
+    /// Returns the relations.
+    ///
+    /// Edges without a related media are skipped.
+    pub fn relations(&self) -> Vec<Relation> {
+        self.edges
+            .iter()
+            .filter(|relation| relation.node.is_some())
+            .cloned()
+            .collect()
+    }
+
+    /// Removes the relations to media for adults.
+    pub(crate) fn remove_adult(&mut self) {
+        self.edges.retain(|relation| {
+            !matches!(
+                &relation.node,
+                Some(Media::Anime(anime)) if anime.is_adult
+            ) && !matches!(
+                &relation.node,
+                Some(Media::Manga(manga)) if manga.is_adult
+            )
+        });
+    }
+}
+
+/// Returns the edges of a page out of the embedded ones.
+///
+/// The embedded edges are the first page of a connection, so a page can
+/// only be taken from them if they cover it entirely, or if there are
+/// no more edges. Returns `None` otherwise, or if the connection was not
+/// loaded.
+///
+/// # Arguments
+///
+/// * `edges` - The embedded edges.
+/// * `page_info` - The pagination information of the embedded edges.
+/// * `page` - The page number to get.
+/// * `per_page` - The number of edges per page.
+fn embedded_page<T: Clone>(
+    edges: &[T],
+    page_info: Option<&PageInfo>,
+    page: u16,
+    per_page: u16,
+) -> Option<Vec<T>> {
+    let page_info = page_info?;
+    let start = usize::from(page.max(1) - 1) * usize::from(per_page);
+    let end = start + usize::from(per_page);
+
+    if end > edges.len() && page_info.has_next_page {
+        return None;
+    }
+
+    Some(edges[start.min(edges.len())..end.min(edges.len())].to_vec())
+}
+
+/// Deserializes a value, falling back to its default when it is null.
+pub(crate) fn nullable<'de, D, T>(deserializer: D) -> std::result::Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Attaches a client to a media, along with its description format.
+///
+/// # Arguments
+///
+/// * `media` - The media to attach the client to.
+/// * `client` - The client to attach.
+pub(crate) fn attach_client(media: &mut Media, client: &Client) {
+    match media {
+        Media::Anime(anime) => {
+            anime.description_format = client.description_format();
+            anime.client = client.clone();
+        }
+        Media::Manga(manga) => {
+            manga.description_format = client.description_format();
+            manga.client = client.clone();
+        }
+        Media::Unknown => {}
+    }
+}
+
+/// Deserializes a media node, either returned by the API or serialized
+/// by this crate, falling back to `None` when it is null.
+///
+/// The media is not attached to any client yet.
+pub(crate) fn media_node<'de, D>(deserializer: D) -> std::result::Result<Option<Media>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+
+    if value.is_null() {
+        Ok(None)
+    } else if value.get("id").is_some() {
+        Media::from_node(&value, &Client::default())
+            .map(Some)
+            .map_err(D::Error::custom)
+    } else {
+        Media::deserialize(value)
+            .map(Some)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Deserializes a list of person nodes, falling back to an empty list
+/// when it is null.
+///
+/// The persons are not attached to any client yet.
+fn person_nodes<'de, D>(deserializer: D) -> std::result::Result<Vec<Person>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<Vec<Value>>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .iter()
+        .map(|node| Person::from_node(node, &Client::default()).map_err(D::Error::custom))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_characters() {
+        let connection: CharacterConnection = serde_json::from_value(serde_json::json!({
+            "edges": [
+                {
+                    "node": {
+                        "id": 1,
+                        "name": {
+                            "first": "Lelouch",
+                            "full": "Lelouch Lamperouge",
+                            "alternative": []
+                        },
+                        "image": { "large": "", "medium": "" },
+                        "description": "",
+                        "siteUrl": ""
+                    },
+                    "role": "MAIN"
+                },
+                { "node": null, "role": "SUPPORTING" },
+                { "role": "BACKGROUND" }
+            ]
+        }))
+        .unwrap();
+
+        let characters = connection.characters();
+
+        assert_eq!(characters.len(), 1);
+        assert_eq!(characters[0].id, 1);
+        assert_eq!(characters[0].role, Some(CharacterRole::Main));
+
+        assert!(
+            serde_json::from_value::<CharacterConnection>(serde_json::json!({
+                "edges": [{ "node": { "id": "invalid" }, "role": "SUPPORTING" }]
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_null_edges() {
+        let characters: CharacterConnection =
+            serde_json::from_value(serde_json::json!({ "edges": null })).unwrap();
+        let relations: RelationConnection = serde_json::from_value(serde_json::json!({})).unwrap();
+
+        assert!(characters.characters().is_empty());
+        assert!(relations.relations().is_empty());
+    }
+
+    #[test]
+    fn test_relations() {
+        let connection: RelationConnection = serde_json::from_value(serde_json::json!({
+            "edges": [
+                { "node": { "id": 2 }, "id": 1, "relationType": "SEQUEL", "isMainStudio": false },
+                { "node": null, "id": 3, "relationType": "PREQUEL", "isMainStudio": false }
+            ]
+        }))
+        .unwrap();
+
+        let relations = connection.relations();
+
+        assert_eq!(relations.len(), 1);
+        assert_eq!(relations[0].id, 1);
+    }
+
+    #[test]
+    fn test_media() {
+        let connection: MediaConnection = serde_json::from_value(serde_json::json!({
+            "pageInfo": { "hasNextPage": false },
+            "edges": [
+                {
+                    "node": { "id": 1, "title": { "romaji": "Code Geass", "native": "" }, "type": "ANIME" },
+                    "characterRole": "MAIN"
+                },
+                { "node": null, "characterRole": "SUPPORTING" }
+            ]
+        }))
+        .unwrap();
+
+        let media = connection.media(&Client::default());
+
+        assert_eq!(media.len(), 1);
+        assert_eq!(media[0].id(), 1);
+        assert_eq!(media[0].character_role(), Some(&CharacterRole::Main));
+    }
+
+    #[test]
+    fn test_serialized_connections() {
+        let media: MediaConnection = serde_json::from_value(serde_json::json!({
+            "edges": [{
+                "node": { "id": 1, "title": { "romaji": "Code Geass", "native": "" }, "type": "ANIME" },
+                "staffRole": "Director"
+            }]
+        }))
+        .unwrap();
+        let characters: CharacterConnection = serde_json::from_value(serde_json::json!({
+            "edges": [{
+                "role": "MAIN",
+                "voiceActors": [{ "id": 2, "name": { "first": "Jun", "full": "Jun Fukuyama" } }]
+            }]
+        }))
+        .unwrap();
+
+        let serialized = serde_json::to_value(&media).unwrap();
+        assert_eq!(
+            serde_json::from_value::<MediaConnection>(serialized).unwrap(),
+            media
+        );
+        let serialized = serde_json::to_value(&characters).unwrap();
+        assert_eq!(
+            serde_json::from_value::<CharacterConnection>(serialized).unwrap(),
+            characters
+        );
+    }
+
+    #[test]
+    fn test_malformed_edges() {
+        assert!(serde_json::from_value::<RelationConnection>(
+            serde_json::json!({ "edges": [1, 2] })
+        )
+        .is_err());
+        assert!(
+            serde_json::from_value::<MediaConnection>(serde_json::json!({
+                "edges": [{ "node": { "id": 1, "title": 2, "type": "ANIME" } }]
+            }))
+            .is_err()
+        );
+    }
+}
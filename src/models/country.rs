@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `CountryOfOrigin` enum.
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the country a media originates from.
+///
+/// The `CountryOfOrigin` enum defines the countries most media on the
+/// API come from, which is how manga, manhwa and manhua are told apart.
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum CountryOfOrigin {
+    /// Japan, the origin of manga.
+    #[default]
+    #[serde(rename = "JP")]
+    Japan,
+    /// South Korea, the origin of manhwa.
+    #[serde(rename = "KR")]
+    SouthKorea,
+    /// China, the origin of manhua.
+    #[serde(rename = "CN")]
+    China,
+    /// Taiwan.
+    #[serde(rename = "TW")]
+    Taiwan,
+    /// A country not known to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl CountryOfOrigin {
+    /// Returns the name of the country.
+    pub fn name(&self) -> &str {
+        match self {
+            CountryOfOrigin::Japan => "Japan",
+            CountryOfOrigin::SouthKorea => "South Korea",
+            CountryOfOrigin::China => "China",
+            CountryOfOrigin::Taiwan => "Taiwan",
+            CountryOfOrigin::Unknown => "Unknown",
+        }
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 code of the country.
+    pub fn code(&self) -> &str {
+        match self {
+            CountryOfOrigin::Japan => "JP",
+            CountryOfOrigin::SouthKorea => "KR",
+            CountryOfOrigin::China => "CN",
+            CountryOfOrigin::Taiwan => "TW",
+            CountryOfOrigin::Unknown => "",
+        }
+    }
+}
+
+impl From<&str> for CountryOfOrigin {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "JP" => CountryOfOrigin::Japan,
+            "KR" => CountryOfOrigin::SouthKorea,
+            "CN" => CountryOfOrigin::China,
+            "TW" => CountryOfOrigin::Taiwan,
+            _ => CountryOfOrigin::Unknown,
+        }
+    }
+}
+
+impl From<String> for CountryOfOrigin {
+    fn from(value: String) -> Self {
+        CountryOfOrigin::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for CountryOfOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(CountryOfOrigin::from("jp"), CountryOfOrigin::Japan);
+        assert_eq!(CountryOfOrigin::from("KR"), CountryOfOrigin::SouthKorea);
+        assert_eq!(CountryOfOrigin::from(" cn "), CountryOfOrigin::China);
+        assert_eq!(CountryOfOrigin::from("TW"), CountryOfOrigin::Taiwan);
+        assert_eq!(CountryOfOrigin::from("BR"), CountryOfOrigin::Unknown);
+    }
+
+    #[test]
+    fn test_serde() {
+        assert_eq!(
+            serde_json::to_value(CountryOfOrigin::SouthKorea).unwrap(),
+            serde_json::json!("KR")
+        );
+        assert_eq!(
+            serde_json::from_value::<CountryOfOrigin>(serde_json::json!("CN")).unwrap(),
+            CountryOfOrigin::China
+        );
+        assert_eq!(
+            serde_json::from_value::<CountryOfOrigin>(serde_json::json!("BR")).unwrap(),
+            CountryOfOrigin::Unknown
+        );
+    }
+}
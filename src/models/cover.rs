@@ -6,13 +6,15 @@
 use serde::{Deserialize, Serialize};
 
 use crate::models::Color;
+#[cfg(feature = "download")]
+use crate::{Client, Error, Result};
 
 /// Represents the cover images of various sizes and the color of the cover.
 ///
 /// The `Cover` struct contains URLs for the cover images in different sizes
 /// (extra large, large, and medium) and an optional color.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Cover {
     /// The URL of the cover image in extra large size.
     pub extra_large: Option<String>,
@@ -24,7 +26,51 @@ pub struct Cover {
     pub color: Option<Color>,
 }
 
+/// The width, in pixels, of the medium size images.
+pub(crate) const MEDIUM_WIDTH: u32 = 100;
+/// The width, in pixels, of the large size images.
+pub(crate) const LARGE_WIDTH: u32 = 230;
+/// The width, in pixels, of the extra large size images.
+pub(crate) const EXTRA_LARGE_WIDTH: u32 = 460;
+
 impl Cover {
+    /// Returns the URLs of the available sizes of the cover image, from
+    /// the smallest to the largest.
+    pub fn urls(&self) -> impl Iterator<Item = &str> {
+        self.sizes().into_iter().filter_map(|(_, url)| url)
+    }
+
+    /// Returns the URL of the smallest version of the cover image.
+    pub fn smallest(&self) -> Option<&str> {
+        self.urls().next()
+    }
+
+    /// Returns the URL of the smallest version of the cover image that is
+    /// at least as wide as the given width, or the largest one available.
+    ///
+    /// The medium size is about 100 pixels wide, the large size about 230
+    /// and the extra large size about 460.
+    ///
+    /// # Arguments
+    ///
+    /// * `width_hint` - The width, in pixels, the image will be shown at.
+    pub fn best_for(&self, width_hint: u32) -> Option<&str> {
+        self.sizes()
+            .into_iter()
+            .find_map(|(width, url)| url.filter(|_| width >= width_hint))
+            .or_else(|| self.largest())
+    }
+
+    /// Returns the sizes of the cover image with their widths, from the
+    /// smallest to the largest.
+    fn sizes(&self) -> [(u32, Option<&str>); 3] {
+        [
+            (MEDIUM_WIDTH, self.medium.as_deref()),
+            (LARGE_WIDTH, self.large.as_deref()),
+            (EXTRA_LARGE_WIDTH, self.extra_large.as_deref()),
+        ]
+    }
+
     /// Returns the URL of the largest version of the cover image.
     pub fn largest(&self) -> Option<&str> {
         if let Some(extra_large) = self.extra_large.as_deref() {
@@ -37,6 +83,60 @@ impl Cover {
             None
         }
     }
+
+    /// Downloads the largest version of the cover image.
+    ///
+    /// Only available with the `download` feature, enabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to download the image with.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the cover has no image, or an error
+    /// if the download fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let anime = client.get_anime(1).await?;
+    /// let bytes = anime.cover.download(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "download")]
+    pub async fn download(&self, client: &Client) -> Result<Vec<u8>> {
+        let url = self.largest().ok_or(Error::NotFound)?;
+
+        client.download(url).await
+    }
+
+    /// Downloads the largest version of the cover image to a file.
+    ///
+    /// Only available with the `download` feature, enabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to download the image with.
+    /// * `path` - The path of the file to write the image to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the cover has no image, or an error
+    /// if the download fails or the file could not be written.
+    #[cfg(feature = "download")]
+    pub async fn download_to<P: AsRef<std::path::Path>>(
+        &self,
+        client: &Client,
+        path: P,
+    ) -> Result<()> {
+        let bytes = self.download(client).await?;
+        tokio::fs::write(path, bytes).await?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -90,4 +190,84 @@ mod tests {
 
         assert_eq!(cover.largest(), None);
     }
+
+    #[test]
+    fn test_smallest() {
+        let cover = Cover {
+            extra_large: Some("https://example.com/extra_large.jpg".to_string()),
+            large: Some("https://example.com/large.jpg".to_string()),
+            medium: None,
+            color: None,
+        };
+
+        assert_eq!(cover.smallest(), Some("https://example.com/large.jpg"));
+        assert_eq!(Cover::default().smallest(), None);
+    }
+
+    #[test]
+    fn test_best_for() {
+        let cover = Cover {
+            extra_large: Some("https://example.com/extra_large.jpg".to_string()),
+            large: Some("https://example.com/large.jpg".to_string()),
+            medium: Some("https://example.com/medium.jpg".to_string()),
+            color: None,
+        };
+
+        assert_eq!(cover.best_for(64), Some("https://example.com/medium.jpg"));
+        assert_eq!(cover.best_for(100), Some("https://example.com/medium.jpg"));
+        assert_eq!(cover.best_for(200), Some("https://example.com/large.jpg"));
+        assert_eq!(
+            cover.best_for(400),
+            Some("https://example.com/extra_large.jpg")
+        );
+        assert_eq!(
+            cover.best_for(1000),
+            Some("https://example.com/extra_large.jpg")
+        );
+    }
+
+    #[test]
+    fn test_best_for_with_missing_sizes() {
+        let cover = Cover {
+            extra_large: None,
+            large: Some("https://example.com/large.jpg".to_string()),
+            medium: None,
+            color: None,
+        };
+
+        assert_eq!(cover.best_for(64), Some("https://example.com/large.jpg"));
+        assert_eq!(cover.best_for(400), Some("https://example.com/large.jpg"));
+
+        let cover = Cover {
+            extra_large: Some("https://example.com/extra_large.jpg".to_string()),
+            large: None,
+            medium: Some("https://example.com/medium.jpg".to_string()),
+            color: None,
+        };
+
+        assert_eq!(
+            cover.best_for(200),
+            Some("https://example.com/extra_large.jpg")
+        );
+        assert_eq!(Cover::default().best_for(200), None);
+    }
+
+    #[test]
+    fn test_urls() {
+        let cover = Cover {
+            extra_large: Some("https://example.com/extra_large.jpg".to_string()),
+            large: None,
+            medium: Some("https://example.com/medium.jpg".to_string()),
+            color: None,
+        };
+
+        assert_eq!(
+            cover.urls().collect::<Vec<_>>(),
+            vec![
+                "https://example.com/medium.jpg",
+                "https://example.com/extra_large.jpg"
+            ]
+        );
+        assert_eq!(Cover::default().urls().count(), 0);
+    }
 }
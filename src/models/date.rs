@@ -6,8 +6,15 @@
 use chrono::{Datelike, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 
+use crate::{Error, Result};
+
 /// Represents a date.
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+///
+/// Dates may be partial, like when only the year of a release is known.
+/// They are ordered by year, then month, then day, and a missing
+/// component sorts before any known one, so `2023` sorts before
+/// `2023-05`, which sorts before `2023-05-01`.
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Date {
     /// The year of the date.
@@ -107,13 +114,11 @@ impl Date {
     }
 
     /// Returns the date as a `NaiveDate`.
-    pub fn as_date(&self) -> NaiveDate {
-        NaiveDate::from_ymd_opt(
-            self.year.unwrap_or(0),
-            self.month.unwrap_or(0),
-            self.day.unwrap_or(0),
-        )
-        .unwrap()
+    ///
+    /// A missing month or day is taken as the first one. Returns `None`
+    /// if the year is missing or the date does not exist.
+    pub fn as_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year?, self.month.unwrap_or(1), self.day.unwrap_or(1))
     }
 
     /// Returns the date as a string.
@@ -141,9 +146,12 @@ impl From<NaiveDate> for Date {
     }
 }
 
-impl From<Date> for NaiveDate {
-    fn from(date: Date) -> Self {
+impl TryFrom<Date> for NaiveDate {
+    type Error = Error;
+
+    fn try_from(date: Date) -> Result<Self> {
         date.as_date()
+            .ok_or_else(|| Error::InvalidDate(date.as_string()))
     }
 }
 
@@ -209,7 +217,7 @@ mod tests {
     #[test]
     fn test_as_date() {
         let date = Date::new(Some(2023), Some(10), Some(5));
-        let naive_date = date.as_date();
+        let naive_date = date.as_date().unwrap();
 
         assert_eq!(naive_date.year(), 2023);
         assert_eq!(naive_date.month(), 10);
@@ -232,4 +240,40 @@ mod tests {
         assert!(valid_date.is_valid());
         assert!(!invalid_date.is_valid());
     }
+
+    #[test]
+    fn test_as_date_with_partial_dates() {
+        assert_eq!(
+            Date::new(Some(2023), None, None).as_date(),
+            NaiveDate::from_ymd_opt(2023, 1, 1)
+        );
+        assert_eq!(Date::new(None, Some(5), Some(1)).as_date(), None);
+        assert_eq!(Date::new(Some(2023), Some(0), None).as_date(), None);
+        assert_eq!(Date::new(Some(2023), Some(2), Some(30)).as_date(), None);
+    }
+
+    #[test]
+    fn test_try_into_naive_date() {
+        let date: Result<NaiveDate> = Date::new(None, None, None).try_into();
+
+        assert!(matches!(date, Err(Error::InvalidDate(_))));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let year = Date::new(Some(2023), None, None);
+        let month = Date::new(Some(2023), Some(5), None);
+        let day = Date::new(Some(2023), Some(5), Some(1));
+        let next_year = Date::new(Some(2024), Some(1), Some(1));
+
+        assert!(year < month);
+        assert!(month < day);
+        assert!(day < next_year);
+        assert!(Date::default() < year);
+
+        let mut dates = vec![next_year.clone(), day.clone(), year.clone(), month.clone()];
+        dates.sort();
+
+        assert_eq!(dates, vec![year, month, day, next_year]);
+    }
 }
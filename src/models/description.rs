@@ -0,0 +1,364 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `DescriptionFormat` enum and the functions
+//! used to render the descriptions of the media.
+//!
+//! Descriptions come as a small subset of HTML, with line breaks,
+//! italics, bold text and links, and may hide spoilers either between
+//! `~!` and `!~` markers or in `markdown_spoiler` spans.
+
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Represents the format of a description, as returned by the API.
+///
+/// AniList renders descriptions to HTML by default, see
+/// `Client::prefer_html_descriptions` to get them as written instead.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DescriptionFormat {
+    /// HTML, with the markdown rendered by the API.
+    #[default]
+    Html,
+    /// Markdown, as written on AniList, which may contain some HTML.
+    Markdown,
+}
+
+impl DescriptionFormat {
+    /// Returns the name of the description format.
+    pub fn name(&self) -> &str {
+        match self {
+            DescriptionFormat::Html => "HTML",
+            DescriptionFormat::Markdown => "Markdown",
+        }
+    }
+}
+
+impl From<&str> for DescriptionFormat {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "MARKDOWN" => DescriptionFormat::Markdown,
+            _ => DescriptionFormat::Html,
+        }
+    }
+}
+
+impl From<String> for DescriptionFormat {
+    fn from(value: String) -> Self {
+        DescriptionFormat::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for DescriptionFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The format a description is rendered to.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum Style {
+    /// Plain text, without any formatting.
+    Plain,
+    /// Markdown, with the spoilers between `||` markers.
+    Markdown,
+}
+
+/// Renders an HTML description to plain text or markdown.
+///
+/// Unknown tags are dropped, keeping their text. An unterminated
+/// spoiler hides everything after it.
+///
+/// # Arguments
+///
+/// * `html` - The HTML description.
+/// * `style` - The format to render the description to.
+/// * `hide_spoilers` - Whether the spoilers are removed, rather than
+///   kept, masked in markdown.
+pub(crate) fn render(html: &str, style: Style, hide_spoilers: bool) -> String {
+    let mut output = String::new();
+    // The spans opened, and whether each one is a spoiler.
+    let mut spans = Vec::new();
+    // The targets of the links opened.
+    let mut links = Vec::new();
+    let mut spoilers = 0usize;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let hidden = hide_spoilers && spoilers > 0;
+
+        if let Some(after) = rest.strip_prefix("~!") {
+            open_spoiler(&mut output, &mut spoilers, style, hide_spoilers);
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("!~").filter(|_| spoilers > 0) {
+            close_spoiler(&mut output, &mut spoilers, style, hide_spoilers);
+            rest = after;
+        } else if let Some((tag, after)) =
+            rest.strip_prefix('<').and_then(|tag| tag.split_once('>'))
+        {
+            let (name, attributes) = tag
+                .trim()
+                .split_once(char::is_whitespace)
+                .unwrap_or((tag.trim(), ""));
+            let name = name.trim_end_matches('/').to_lowercase();
+            let markdown = style == Style::Markdown && !hidden;
+
+            match name.as_str() {
+                "br" if !hidden => output.push('\n'),
+                "/p" if !hidden => output.push_str("\n\n"),
+                "i" | "/i" | "em" | "/em" if markdown => output.push('_'),
+                "b" | "/b" | "strong" | "/strong" if markdown => output.push_str("**"),
+                "a" => {
+                    links.push(attribute(attributes, "href").unwrap_or_default());
+                    if markdown {
+                        output.push('[');
+                    }
+                }
+                "/a" => {
+                    let href = links.pop().unwrap_or_default();
+                    if markdown {
+                        output.push_str(&format!("]({href})"));
+                    }
+                }
+                "span" => {
+                    let spoiler = attributes.contains("markdown_spoiler");
+                    if spoiler {
+                        open_spoiler(&mut output, &mut spoilers, style, hide_spoilers);
+                    }
+                    spans.push(spoiler);
+                }
+                "/span" if spans.pop() == Some(true) => {
+                    close_spoiler(&mut output, &mut spoilers, style, hide_spoilers);
+                }
+                _ => {}
+            }
+            rest = after;
+        } else {
+            let first = rest.chars().next().map_or(1, char::len_utf8);
+            let end = rest[first..]
+                .find(['<', '~', '!'])
+                .map_or(rest.len(), |end| end + first);
+            if !hidden {
+                output.push_str(&decode_entities(&rest[..end]));
+            }
+            rest = &rest[end..];
+        }
+    }
+    if style == Style::Markdown && !hide_spoilers && spoilers > 0 {
+        output.push_str("||");
+    }
+
+    normalize(&output)
+}
+
+/// Truncates a text to a number of graphemes, ending it with an
+/// ellipsis if it was cut.
+///
+/// # Arguments
+///
+/// * `text` - The text to truncate.
+/// * `max_len` - The maximum number of graphemes, the ellipsis included.
+pub(crate) fn truncate(text: &str, max_len: usize) -> String {
+    if text.graphemes(true).count() <= max_len {
+        return text.to_string();
+    }
+
+    let mut truncated: String = text
+        .graphemes(true)
+        .take(max_len.saturating_sub(1))
+        .collect();
+    if max_len > 0 {
+        truncated.truncate(truncated.trim_end().len());
+        truncated.push('…');
+    }
+
+    truncated
+}
+
+/// Opens a spoiler, masking it in markdown when spoilers are shown.
+fn open_spoiler(output: &mut String, spoilers: &mut usize, style: Style, hide_spoilers: bool) {
+    if *spoilers == 0 && style == Style::Markdown && !hide_spoilers {
+        output.push_str("||");
+    }
+    *spoilers += 1;
+}
+
+/// Closes a spoiler, ending its mask once the outermost is closed.
+fn close_spoiler(output: &mut String, spoilers: &mut usize, style: Style, hide_spoilers: bool) {
+    *spoilers = spoilers.saturating_sub(1);
+    if *spoilers == 0 && style == Style::Markdown && !hide_spoilers {
+        output.push_str("||");
+    }
+}
+
+/// Returns the value of an attribute of a tag, if set.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let start = attributes.find(&format!("{name}="))? + name.len() + 1;
+    let value = &attributes[start..];
+
+    match value.chars().next()? {
+        quote @ ('"' | '\'') => value[1..].split(quote).next().map(decode_entities),
+        _ => value.split_whitespace().next().map(decode_entities),
+    }
+}
+
+/// Decodes the HTML entities of a text.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let character = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = entity.strip_prefix('#')?;
+                let code = match code.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => code.parse().ok()?,
+                };
+
+                char::from_u32(code)
+            }
+        });
+
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
+}
+
+/// Collapses the runs of spaces and of blank lines of a text, left by
+/// the removed tags and spoilers.
+fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut blank_lines = 0;
+
+    for line in text.lines() {
+        let line = line.split(' ').filter(|word| !word.is_empty());
+        let line = line.collect::<Vec<_>>().join(" ");
+        if line.is_empty() {
+            blank_lines += 1;
+            continue;
+        }
+
+        if !normalized.is_empty() {
+            normalized.push_str(if blank_lines > 0 { "\n\n" } else { "\n" });
+        }
+        normalized.push_str(&line);
+        blank_lines = 0;
+    }
+
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_plain() {
+        let html = "Lelouch is <i>exiled</i>.<br>\n<br>\n(Source: <a href=\"https://example.com\">Example</a> &amp; others)";
+
+        assert_eq!(
+            render(html, Style::Plain, false),
+            "Lelouch is exiled.\n\n(Source: Example & others)"
+        );
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let html = "<b>Lelouch</b> is <i>exiled</i>.<br><a href='https://example.com'>Example</a>";
+
+        assert_eq!(
+            render(html, Style::Markdown, false),
+            "**Lelouch** is _exiled_.\n[Example](https://example.com)"
+        );
+    }
+
+    #[test]
+    fn test_render_nested_tags() {
+        let html = "<b>Bold <i>and <a href=\"https://a.b/?x=1&amp;y=2\">linked</a></i></b> &#39;text&#x27;";
+
+        assert_eq!(
+            render(html, Style::Markdown, false),
+            "**Bold _and [linked](https://a.b/?x=1&y=2)_** 'text'"
+        );
+        assert_eq!(render(html, Style::Plain, false), "Bold and linked 'text'");
+    }
+
+    #[test]
+    fn test_render_spoilers() {
+        let html = "Suzaku is ~!the <i>Knight</i> of Zero!~ all along!";
+
+        assert_eq!(render(html, Style::Plain, true), "Suzaku is all along!");
+        assert_eq!(
+            render(html, Style::Plain, false),
+            "Suzaku is the Knight of Zero all along!"
+        );
+        assert_eq!(
+            render(html, Style::Markdown, false),
+            "Suzaku is ||the _Knight_ of Zero|| all along!"
+        );
+    }
+
+    #[test]
+    fn test_render_html_spoilers() {
+        let html = "Euphemia <span class='markdown_spoiler'><span>dies</span></span>.";
+
+        assert_eq!(render(html, Style::Plain, true), "Euphemia .");
+        assert_eq!(render(html, Style::Markdown, false), "Euphemia ||dies||.");
+    }
+
+    #[test]
+    fn test_render_unterminated_spoiler() {
+        let html = "Shirley ~!is killed by Rolo.<br>Wow!";
+
+        assert_eq!(render(html, Style::Plain, true), "Shirley");
+        assert_eq!(
+            render(html, Style::Markdown, false),
+            "Shirley ||is killed by Rolo.\nWow!||"
+        );
+        assert_eq!(render("Hello!~ world", Style::Plain, true), "Hello!~ world");
+    }
+
+    #[test]
+    fn test_render_malformed() {
+        assert_eq!(render("1 < 2 & <3", Style::Plain, false), "1 < 2 & <3");
+        assert_eq!(
+            render("&unknown; &#xZZ;", Style::Plain, false),
+            "&unknown; &#xZZ;"
+        );
+        assert_eq!(render("", Style::Plain, false), "");
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("Code Geass", 20), "Code Geass");
+        assert_eq!(truncate("Code Geass", 10), "Code Geass");
+        assert_eq!(truncate("Code Geass", 6), "Code…");
+        assert_eq!(truncate("Code Geass", 0), "");
+        // The family emoji is a single grapheme of several code points.
+        assert_eq!(truncate("👨‍👩‍👧 family", 3), "👨‍👩‍👧…");
+        assert_eq!(truncate("e\u{301}e\u{301}e\u{301}", 2), "e\u{301}…");
+    }
+}
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 /// such as TV shows, movies, specials, OVAs, ONAs, music, manga, novels,
 /// and one-shots.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Format {
     /// Represents a TV show.
     #[default]
@@ -34,6 +34,9 @@ pub enum Format {
     Novel,
     /// Represents a one-shot.
     OneShot,
+    /// A format not known to this version of the crate.
+    #[serde(other)]
+    Unknown,
 }
 
 impl Format {
@@ -50,6 +53,7 @@ impl Format {
             Format::Manga => "Manga",
             Format::Novel => "Novel",
             Format::OneShot => "One-Shot",
+            Format::Unknown => "Unknown",
         }
     }
 
@@ -66,6 +70,7 @@ impl Format {
             Format::Manga => "Professionally published manga with more than one chapter",
             Format::Novel => "Written books released as a series of light novels",
             Format::OneShot => "Manga with just one chapter",
+            Format::Unknown => "A format not known to this version of the crate",
         }
     }
 }
@@ -147,4 +152,16 @@ mod tests {
         assert_eq!(Format::from("ONE_SHOT".to_string()), Format::OneShot);
         assert_eq!(Format::from("unknown".to_string()), Format::Tv); // Default case
     }
+
+    #[test]
+    fn test_deserialize_unknown() {
+        assert_eq!(
+            serde_json::from_value::<Format>(serde_json::json!("ONE_SHOT")).unwrap(),
+            Format::OneShot
+        );
+        assert_eq!(
+            serde_json::from_value::<Format>(serde_json::json!("HOLOGRAM")).unwrap(),
+            Format::Unknown
+        );
+    }
 }
@@ -9,23 +9,103 @@ use serde::{Deserialize, Serialize};
 ///
 /// The `Gender` enum defines various gender identities, including male,
 /// female, non-binary, and other custom genders.
+///
+/// AniList stores the gender as free-form text, so any text other than
+/// the known genders is kept as is in `Gender::Other`.
 #[derive(Debug, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "PascalCase"))]
+#[serde(from = "String", into = "String")]
 pub enum Gender {
     /// Represents the male gender.
     Male,
     /// Represents the female gender.
     Female,
     /// Represents the non-binary gender.
-    #[serde(rename = "Non-binary")]
     NonBinary,
     /// Represents a custom gender specified by a string.
-    #[serde(untagged)]
     Other(String),
 }
 
+impl Gender {
+    /// Returns the name of the gender.
+    pub fn name(&self) -> &str {
+        match self {
+            Gender::Male => "Male",
+            Gender::Female => "Female",
+            Gender::NonBinary => "Non-binary",
+            Gender::Other(name) => name,
+        }
+    }
+}
+
 impl Default for Gender {
     fn default() -> Self {
         Gender::Other(String::from("Neutral"))
     }
 }
+
+impl From<&str> for Gender {
+    fn from(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "male" => Gender::Male,
+            "female" => Gender::Female,
+            "non-binary" | "nonbinary" => Gender::NonBinary,
+            _ => Gender::Other(value.trim().to_string()),
+        }
+    }
+}
+
+impl From<String> for Gender {
+    fn from(value: String) -> Self {
+        Gender::from(value.as_str())
+    }
+}
+
+impl From<Gender> for String {
+    fn from(value: Gender) -> Self {
+        value.name().to_string()
+    }
+}
+
+impl std::fmt::Display for Gender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Gender::from("Male"), Gender::Male);
+        assert_eq!(Gender::from("female"), Gender::Female);
+        assert_eq!(Gender::from("Non-binary"), Gender::NonBinary);
+        assert_eq!(
+            Gender::from("Male (Female as Haru)"),
+            Gender::Other("Male (Female as Haru)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        assert_eq!(
+            serde_json::from_value::<Gender>(serde_json::json!("Non-binary")).unwrap(),
+            Gender::NonBinary
+        );
+        assert_eq!(
+            serde_json::from_value::<Gender>(serde_json::json!("Agender")).unwrap(),
+            Gender::Other("Agender".to_string())
+        );
+        assert_eq!(
+            serde_json::to_value(Gender::NonBinary).unwrap(),
+            serde_json::json!("Non-binary")
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Gender::Female.to_string(), "Female");
+        assert_eq!(Gender::Other("Agender".to_string()).to_string(), "Agender");
+    }
+}
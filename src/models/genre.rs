@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Genre` enum.
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the genre of a media.
+///
+/// The `Genre` enum defines the genres available on AniList, and holds
+/// the name of the genre for any genre added after this version of the
+/// crate.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum Genre {
+    /// Action.
+    Action,
+    /// Adventure.
+    Adventure,
+    /// Comedy.
+    Comedy,
+    /// Drama.
+    Drama,
+    /// Ecchi.
+    Ecchi,
+    /// Fantasy.
+    Fantasy,
+    /// Horror.
+    Horror,
+    /// Mahou Shoujo.
+    MahouShoujo,
+    /// Mecha.
+    Mecha,
+    /// Music.
+    Music,
+    /// Mystery.
+    Mystery,
+    /// Psychological.
+    Psychological,
+    /// Romance.
+    Romance,
+    /// Sci-Fi.
+    SciFi,
+    /// Slice of Life.
+    SliceOfLife,
+    /// Sports.
+    Sports,
+    /// Supernatural.
+    Supernatural,
+    /// Thriller.
+    Thriller,
+    /// Hentai.
+    Hentai,
+    /// Another genre, by its name.
+    Other(String),
+}
+
+impl Genre {
+    /// Returns the name of the genre, as written by AniList.
+    pub fn name(&self) -> &str {
+        match self {
+            Genre::Action => "Action",
+            Genre::Adventure => "Adventure",
+            Genre::Comedy => "Comedy",
+            Genre::Drama => "Drama",
+            Genre::Ecchi => "Ecchi",
+            Genre::Fantasy => "Fantasy",
+            Genre::Horror => "Horror",
+            Genre::MahouShoujo => "Mahou Shoujo",
+            Genre::Mecha => "Mecha",
+            Genre::Music => "Music",
+            Genre::Mystery => "Mystery",
+            Genre::Psychological => "Psychological",
+            Genre::Romance => "Romance",
+            Genre::SciFi => "Sci-Fi",
+            Genre::SliceOfLife => "Slice of Life",
+            Genre::Sports => "Sports",
+            Genre::Supernatural => "Supernatural",
+            Genre::Thriller => "Thriller",
+            Genre::Hentai => "Hentai",
+            Genre::Other(name) => name,
+        }
+    }
+}
+
+impl Default for Genre {
+    fn default() -> Self {
+        Genre::Other(String::new())
+    }
+}
+
+impl From<&str> for Genre {
+    fn from(value: &str) -> Self {
+        // Case, spaces and dashes are ignored, so "Sci-fi" is "Sci-Fi".
+        let name: String = value
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect();
+
+        match name.as_str() {
+            "action" => Genre::Action,
+            "adventure" => Genre::Adventure,
+            "comedy" => Genre::Comedy,
+            "drama" => Genre::Drama,
+            "ecchi" => Genre::Ecchi,
+            "fantasy" => Genre::Fantasy,
+            "horror" => Genre::Horror,
+            "mahoushoujo" => Genre::MahouShoujo,
+            "mecha" => Genre::Mecha,
+            "music" => Genre::Music,
+            "mystery" => Genre::Mystery,
+            "psychological" => Genre::Psychological,
+            "romance" => Genre::Romance,
+            "scifi" => Genre::SciFi,
+            "sliceoflife" => Genre::SliceOfLife,
+            "sports" => Genre::Sports,
+            "supernatural" => Genre::Supernatural,
+            "thriller" => Genre::Thriller,
+            "hentai" => Genre::Hentai,
+            _ => Genre::Other(value.trim().to_string()),
+        }
+    }
+}
+
+impl From<String> for Genre {
+    fn from(value: String) -> Self {
+        Genre::from(value.as_str())
+    }
+}
+
+impl From<Genre> for String {
+    fn from(value: Genre) -> Self {
+        value.name().to_string()
+    }
+}
+
+impl std::str::FromStr for Genre {
+    type Err = std::convert::Infallible;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Genre::from(value))
+    }
+}
+
+impl std::fmt::Display for Genre {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Genre::from("Action"), Genre::Action);
+        assert_eq!(Genre::from("Sci-Fi"), Genre::SciFi);
+        assert_eq!(Genre::from("sci-fi"), Genre::SciFi);
+        assert_eq!(Genre::from("Slice of Life"), Genre::SliceOfLife);
+        assert_eq!(Genre::from(" mahou shoujo "), Genre::MahouShoujo);
+        assert_eq!(
+            "Isekai".parse::<Genre>().unwrap(),
+            Genre::Other("Isekai".to_string())
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Genre::SciFi.to_string(), "Sci-Fi");
+        assert_eq!(Genre::SliceOfLife.to_string(), "Slice of Life");
+        assert_eq!(Genre::Other("Isekai".to_string()).to_string(), "Isekai");
+    }
+
+    #[test]
+    fn test_serde() {
+        assert_eq!(
+            serde_json::from_value::<Vec<Genre>>(serde_json::json!([
+                "Mahou Shoujo",
+                "Sci-Fi",
+                "Isekai"
+            ]))
+            .unwrap(),
+            vec![
+                Genre::MahouShoujo,
+                Genre::SciFi,
+                Genre::Other("Isekai".to_string())
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(Genre::SliceOfLife).unwrap(),
+            serde_json::json!("Slice of Life")
+        );
+    }
+}
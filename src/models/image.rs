@@ -5,9 +5,13 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::cover::MEDIUM_WIDTH;
+#[cfg(feature = "download")]
+use crate::{Client, Error, Result};
+
 /// Represents an image with different sizes.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "lowercase"))]
+#[serde(rename_all = "lowercase")]
 pub struct Image {
     /// URL of the large version of the image.
     pub large: String,
@@ -16,6 +20,40 @@ pub struct Image {
 }
 
 impl Image {
+    /// Returns the URLs of the available sizes of the image, from the
+    /// smallest to the largest.
+    pub fn urls(&self) -> impl Iterator<Item = &str> {
+        [self.medium.as_str(), self.large.as_str()]
+            .into_iter()
+            .filter(|url| !url.is_empty())
+    }
+
+    /// Returns the URL of the smallest version of the image.
+    pub fn smallest(&self) -> &str {
+        if self.medium.is_empty() {
+            &self.large
+        } else {
+            &self.medium
+        }
+    }
+
+    /// Returns the URL of the smallest version of the image that is at
+    /// least as wide as the given width, or the largest one available.
+    ///
+    /// The medium size is about 100 pixels wide and the large size
+    /// about 230.
+    ///
+    /// # Arguments
+    ///
+    /// * `width_hint` - The width, in pixels, the image will be shown at.
+    pub fn best_for(&self, width_hint: u32) -> &str {
+        if width_hint <= MEDIUM_WIDTH {
+            self.smallest()
+        } else {
+            self.largest()
+        }
+    }
+
     /// Returns the URL of the largest version of the image.
     pub fn largest(&self) -> &str {
         if self.large.is_empty() {
@@ -24,6 +62,51 @@ impl Image {
             &self.large
         }
     }
+
+    /// Downloads the largest version of the image.
+    ///
+    /// Only available with the `download` feature, enabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to download the image with.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if there is no image, or an error if
+    /// the download fails.
+    #[cfg(feature = "download")]
+    pub async fn download(&self, client: &Client) -> Result<Vec<u8>> {
+        match self.largest() {
+            "" => Err(Error::NotFound),
+            url => client.download(url).await,
+        }
+    }
+
+    /// Downloads the largest version of the image to a file.
+    ///
+    /// Only available with the `download` feature, enabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to download the image with.
+    /// * `path` - The path of the file to write the image to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if there is no image, or an error if
+    /// the download fails or the file could not be written.
+    #[cfg(feature = "download")]
+    pub async fn download_to<P: AsRef<std::path::Path>>(
+        &self,
+        client: &Client,
+        path: P,
+    ) -> Result<()> {
+        let bytes = self.download(client).await?;
+        tokio::fs::write(path, bytes).await?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -59,4 +142,70 @@ mod tests {
 
         assert_eq!(image.largest(), "");
     }
+
+    #[test]
+    fn test_smallest() {
+        let image = Image {
+            large: "https://example.com/large.jpg".to_string(),
+            medium: "https://example.com/medium.jpg".to_string(),
+        };
+
+        assert_eq!(image.smallest(), "https://example.com/medium.jpg");
+    }
+
+    #[test]
+    fn test_smallest_with_empty_medium() {
+        let image = Image {
+            large: "https://example.com/large.jpg".to_string(),
+            medium: "".to_string(),
+        };
+
+        assert_eq!(image.smallest(), "https://example.com/large.jpg");
+        assert_eq!(Image::default().smallest(), "");
+    }
+
+    #[test]
+    fn test_best_for() {
+        let image = Image {
+            large: "https://example.com/large.jpg".to_string(),
+            medium: "https://example.com/medium.jpg".to_string(),
+        };
+
+        assert_eq!(image.best_for(48), "https://example.com/medium.jpg");
+        assert_eq!(image.best_for(100), "https://example.com/medium.jpg");
+        assert_eq!(image.best_for(150), "https://example.com/large.jpg");
+        assert_eq!(image.best_for(600), "https://example.com/large.jpg");
+    }
+
+    #[test]
+    fn test_best_for_with_missing_sizes() {
+        let image = Image {
+            large: "".to_string(),
+            medium: "https://example.com/medium.jpg".to_string(),
+        };
+
+        assert_eq!(image.best_for(150), "https://example.com/medium.jpg");
+
+        let image = Image {
+            large: "https://example.com/large.jpg".to_string(),
+            medium: "".to_string(),
+        };
+
+        assert_eq!(image.best_for(48), "https://example.com/large.jpg");
+        assert_eq!(Image::default().best_for(48), "");
+    }
+
+    #[test]
+    fn test_urls() {
+        let image = Image {
+            large: "https://example.com/large.jpg".to_string(),
+            medium: "".to_string(),
+        };
+
+        assert_eq!(
+            image.urls().collect::<Vec<_>>(),
+            vec!["https://example.com/large.jpg"]
+        );
+        assert_eq!(Image::default().urls().count(), 0);
+    }
 }
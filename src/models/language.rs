@@ -10,60 +10,86 @@ use serde::{Deserialize, Serialize};
 /// The `Language` enum defines a list of supported languages, each with
 /// an associated variant. The default language is Japanese.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "PascalCase"))]
+#[serde(rename_all(deserialize = "PascalCase", serialize = "SCREAMING_SNAKE_CASE"))]
 pub enum Language {
     /// The Japanese language.
     #[default]
+    #[serde(alias = "JAPANESE")]
     Japanese,
     /// The English language.
+    #[serde(alias = "ENGLISH")]
     English,
     /// The Korean language.
+    #[serde(alias = "KOREAN")]
     Korean,
     /// The Italian language.
+    #[serde(alias = "ITALIAN")]
     Italian,
     /// The Spanish language.
+    #[serde(alias = "SPANISH")]
     Spanish,
     /// The Portuguese language.
+    #[serde(alias = "PORTUGUESE")]
     Portuguese,
     /// The French language.
+    #[serde(alias = "FRENCH")]
     French,
     /// The German language.
+    #[serde(alias = "GERMAN")]
     German,
     /// The Hebrew language.
+    #[serde(alias = "HEBREW")]
     Hebrew,
     /// The Hungarian language.
+    #[serde(alias = "HUNGARIAN")]
     Hungarian,
     /// The Chinese language.
+    #[serde(alias = "CHINESE")]
     Chinese,
     /// The Arabic language.
+    #[serde(alias = "ARABIC")]
     Arabic,
     /// The Filipino language.
+    #[serde(alias = "FILIPINO")]
     Filipino,
     /// The Catalan language.
+    #[serde(alias = "CATALAN")]
     Catalan,
     /// The Finnish language.
+    #[serde(alias = "FINNISH")]
     Finnish,
     /// The Turkish language.
+    #[serde(alias = "TURKISH")]
     Turkish,
     /// The Dutch language.
+    #[serde(alias = "DUTCH")]
     Dutch,
     /// The Swedish language.
+    #[serde(alias = "SWEDISH")]
     Swedish,
     /// The Thai language.
+    #[serde(alias = "THAI")]
     Thai,
     /// The Tagalog language.
+    #[serde(alias = "TAGALOG")]
     Tagalog,
     /// The Malaysian language.
+    #[serde(alias = "MALAYSIAN")]
     Malaysian,
     /// The Indonesian language.
+    #[serde(alias = "INDONESIAN")]
     Indonesian,
     /// The Vietnamese language.
+    #[serde(alias = "VIETNAMESE")]
     Vietnamese,
     /// The Nepali language.
+    #[serde(alias = "NEPALI")]
     Nepali,
     /// The Hindi language.
+    #[serde(alias = "HINDI")]
     Hindi,
     /// The Urdu language.
+    #[serde(alias = "URDU")]
     Urdu,
 }
 
@@ -138,39 +164,121 @@ impl Language {
             Language::Urdu => "اردو",
         }
     }
+
+    /// Returns the language of an ISO 639-1 or ISO 639-2 code.
+    ///
+    /// The code is matched regardless of case, and the region or script
+    /// of a BCP 47 tag is ignored, so "pt-BR", "es-419" and "zh-Hant"
+    /// are all recognized. Returns `None` if the code is unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The code or tag of the language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Language;
+    /// assert_eq!(Language::from_code("pt-BR"), Some(Language::Portuguese));
+    /// assert_eq!(Language::from_code("jpn"), Some(Language::Japanese));
+    /// assert_eq!(Language::from_code("xx"), None);
+    /// ```
+    pub fn from_code(code: &str) -> Option<Language> {
+        let primary = code.trim().split(['-', '_']).next().unwrap_or_default();
+
+        let language = match primary.to_lowercase().as_str() {
+            "ja" | "jpn" => Language::Japanese,
+            "en" | "eng" => Language::English,
+            "ko" | "kor" => Language::Korean,
+            "it" | "ita" => Language::Italian,
+            "es" | "spa" => Language::Spanish,
+            "pt" | "por" => Language::Portuguese,
+            "fr" | "fre" | "fra" => Language::French,
+            "de" | "ger" | "deu" => Language::German,
+            "he" | "heb" => Language::Hebrew,
+            "hu" | "hun" => Language::Hungarian,
+            "zh" | "chi" | "zho" => Language::Chinese,
+            "ar" | "ara" => Language::Arabic,
+            "fil" => Language::Filipino,
+            "ca" | "cat" => Language::Catalan,
+            "fi" | "fin" => Language::Finnish,
+            "tr" | "tur" => Language::Turkish,
+            "nl" | "dut" | "nld" => Language::Dutch,
+            "sv" | "swe" => Language::Swedish,
+            "th" | "tha" => Language::Thai,
+            "tl" | "tgl" => Language::Tagalog,
+            "ms" | "may" | "msa" => Language::Malaysian,
+            "id" | "ind" => Language::Indonesian,
+            "vi" | "vie" => Language::Vietnamese,
+            "ne" | "nep" => Language::Nepali,
+            "hi" | "hin" => Language::Hindi,
+            "ur" | "urd" => Language::Urdu,
+            _ => return None,
+        };
+
+        Some(language)
+    }
+
+    /// Parses a language from its code or its English name.
+    ///
+    /// Codes are parsed like `from_code`, and names are matched
+    /// regardless of case. Unlike `Language::from`, which falls back to
+    /// Japanese, returns `None` if the language is unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The code or the name of the language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Language;
+    /// assert_eq!(Language::try_parse("PT-BR"), Some(Language::Portuguese));
+    /// assert_eq!(Language::try_parse("german"), Some(Language::German));
+    /// assert_eq!(Language::try_parse("Klingon"), None);
+    /// ```
+    pub fn try_parse(value: &str) -> Option<Language> {
+        if let Some(language) = Language::from_code(value) {
+            return Some(language);
+        }
+
+        let language = match value.trim().to_uppercase().as_str() {
+            "JP" | "JAPANESE" => Language::Japanese,
+            "UK" | "ENGLISH" => Language::English,
+            "KOREAN" => Language::Korean,
+            "ITALIAN" => Language::Italian,
+            "SPANISH" => Language::Spanish,
+            "PORTUGUESE" => Language::Portuguese,
+            "FRENCH" => Language::French,
+            "GERMAN" => Language::German,
+            "HEBREW" => Language::Hebrew,
+            "HUNGARIAN" => Language::Hungarian,
+            "CHINESE" => Language::Chinese,
+            "ARABIC" => Language::Arabic,
+            "FILIPINO" | "PHILIPPINE" => Language::Filipino,
+            "CATALAN" => Language::Catalan,
+            "FINNISH" => Language::Finnish,
+            "TURKISH" => Language::Turkish,
+            "DUTCH" => Language::Dutch,
+            "SWEDISH" => Language::Swedish,
+            "THAI" => Language::Thai,
+            "TAGALOG" => Language::Tagalog,
+            "MALAYSIAN" | "MALAY" => Language::Malaysian,
+            "INDONESIAN" => Language::Indonesian,
+            "VIETNAMESE" => Language::Vietnamese,
+            "NEPALI" => Language::Nepali,
+            "HINDI" => Language::Hindi,
+            "URDU" => Language::Urdu,
+            _ => return None,
+        };
+
+        Some(language)
+    }
 }
 
 impl From<&str> for Language {
     fn from(value: &str) -> Self {
-        match value.trim().to_uppercase().as_str() {
-            "JA" | "JP" | "JAPANESE" => Language::Japanese,
-            "EN" | "UK" | "ENGLISH" => Language::English,
-            "KO" | "KOREAN" => Language::Korean,
-            "IT" | "ITALIAN" => Language::Italian,
-            "ES" | "SPANISH" => Language::Spanish,
-            "PT" | "PORTUGUESE" => Language::Portuguese,
-            "FR" | "FRENCH" => Language::French,
-            "DE" | "GERMAN" => Language::German,
-            "HE" | "HEBREW" => Language::Hebrew,
-            "HU" | "HUNGARIAN" => Language::Hungarian,
-            "ZH" | "CHINESE" => Language::Chinese,
-            "AR" | "ARABIC" => Language::Arabic,
-            "FIL" | "PHILIPPINE" => Language::Filipino,
-            "CA" | "CATALAN" => Language::Catalan,
-            "FI" | "FINNISH" => Language::Finnish,
-            "TR" | "TURKISH" => Language::Turkish,
-            "NL" | "DUTCH" => Language::Dutch,
-            "SV" | "SWEDISH" => Language::Swedish,
-            "TH" | "THAI" => Language::Thai,
-            "TL" | "TAGALOG" => Language::Tagalog,
-            "MS" | "MALAYSIAN" => Language::Malaysian,
-            "ID" | "INDONESIAN" => Language::Indonesian,
-            "VI" | "VIETNAMESE" => Language::Vietnamese,
-            "NE" | "NEPALI" => Language::Nepali,
-            "HI" | "HINDI" => Language::Hindi,
-            "UR" | "URDU" => Language::Urdu,
-            _ => Language::default(),
-        }
+        Language::try_parse(value).unwrap_or_default()
     }
 }
 
@@ -368,4 +476,118 @@ mod tests {
         assert_eq!(Language::from("ur".to_string()), Language::Urdu);
         assert_eq!(Language::from("unknown".to_string()), Language::Japanese); // Default case
     }
+
+    #[test]
+    fn test_serde() {
+        assert_eq!(
+            serde_json::to_value(Language::Japanese).unwrap(),
+            serde_json::json!("JAPANESE")
+        );
+        assert_eq!(
+            serde_json::from_value::<Language>(serde_json::json!("English")).unwrap(),
+            Language::English
+        );
+    }
+
+    /// Returns the language listed after the given one, or `None` for
+    /// the last one.
+    ///
+    /// The match is exhaustive, so adding a language fails to compile
+    /// until it is listed here, and thus covered by the tests below.
+    fn next(language: &Language) -> Option<Language> {
+        match language {
+            Language::Japanese => Some(Language::English),
+            Language::English => Some(Language::Korean),
+            Language::Korean => Some(Language::Italian),
+            Language::Italian => Some(Language::Spanish),
+            Language::Spanish => Some(Language::Portuguese),
+            Language::Portuguese => Some(Language::French),
+            Language::French => Some(Language::German),
+            Language::German => Some(Language::Hebrew),
+            Language::Hebrew => Some(Language::Hungarian),
+            Language::Hungarian => Some(Language::Chinese),
+            Language::Chinese => Some(Language::Arabic),
+            Language::Arabic => Some(Language::Filipino),
+            Language::Filipino => Some(Language::Catalan),
+            Language::Catalan => Some(Language::Finnish),
+            Language::Finnish => Some(Language::Turkish),
+            Language::Turkish => Some(Language::Dutch),
+            Language::Dutch => Some(Language::Swedish),
+            Language::Swedish => Some(Language::Thai),
+            Language::Thai => Some(Language::Tagalog),
+            Language::Tagalog => Some(Language::Malaysian),
+            Language::Malaysian => Some(Language::Indonesian),
+            Language::Indonesian => Some(Language::Vietnamese),
+            Language::Vietnamese => Some(Language::Nepali),
+            Language::Nepali => Some(Language::Hindi),
+            Language::Hindi => Some(Language::Urdu),
+            Language::Urdu => None,
+        }
+    }
+
+    /// Returns all the languages.
+    fn languages() -> Vec<Language> {
+        std::iter::successors(Some(Language::Japanese), next).collect()
+    }
+
+    #[test]
+    fn test_from_code() {
+        let languages = languages();
+        assert_eq!(languages.len(), 26);
+
+        for language in languages {
+            let code = language.code();
+
+            assert_eq!(Language::from_code(code), Some(language.clone()));
+            assert_eq!(
+                Language::from_code(&code.to_uppercase()),
+                Some(language.clone())
+            );
+            assert_eq!(
+                Language::from_code(&format!("{code}-XX")),
+                Some(language.clone())
+            );
+            assert_eq!(Language::try_parse(&language.to_string()), Some(language));
+        }
+    }
+
+    #[test]
+    fn test_from_code_with_tags() {
+        assert_eq!(Language::from_code("pt-BR"), Some(Language::Portuguese));
+        assert_eq!(Language::from_code("PT-BR"), Some(Language::Portuguese));
+        assert_eq!(Language::from_code("es-419"), Some(Language::Spanish));
+        assert_eq!(Language::from_code("zh-Hant"), Some(Language::Chinese));
+        assert_eq!(Language::from_code("en_US"), Some(Language::English));
+        assert_eq!(Language::from_code("unknown"), None);
+        assert_eq!(Language::from_code(""), None);
+    }
+
+    #[test]
+    fn test_from_iso_639_2() {
+        assert_eq!(Language::from_code("jpn"), Some(Language::Japanese));
+        assert_eq!(Language::from_code("ENG"), Some(Language::English));
+        assert_eq!(Language::from_code("fre"), Some(Language::French));
+        assert_eq!(Language::from_code("fra"), Some(Language::French));
+        assert_eq!(Language::from_code("ger"), Some(Language::German));
+        assert_eq!(Language::from_code("deu"), Some(Language::German));
+        assert_eq!(Language::from_code("chi"), Some(Language::Chinese));
+        assert_eq!(Language::from_code("zho"), Some(Language::Chinese));
+        assert_eq!(Language::from_code("dut"), Some(Language::Dutch));
+        assert_eq!(Language::from_code("may"), Some(Language::Malaysian));
+        assert_eq!(Language::from_code("por-BR"), Some(Language::Portuguese));
+    }
+
+    #[test]
+    fn test_try_parse() {
+        assert_eq!(Language::try_parse("PT-BR"), Some(Language::Portuguese));
+        assert_eq!(
+            Language::try_parse("portuguese"),
+            Some(Language::Portuguese)
+        );
+        assert_eq!(Language::try_parse(" Filipino "), Some(Language::Filipino));
+        assert_eq!(Language::try_parse("jp"), Some(Language::Japanese));
+        assert_eq!(Language::try_parse("Klingon"), None);
+        assert_eq!(Language::from("PT-BR"), Language::Portuguese);
+        assert_eq!(Language::from("Klingon"), Language::Japanese);
+    }
 }
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `LikeableType` enum.
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the type of an item that can be liked.
+///
+/// The `LikeableType` enum defines the items a user can like, such as
+/// activities, activity replies, forum threads and thread comments.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LikeableType {
+    /// An activity, such as a list update or a status.
+    #[default]
+    Activity,
+    /// A reply to an activity.
+    ActivityReply,
+    /// A forum thread.
+    Thread,
+    /// A comment on a forum thread.
+    ThreadComment,
+}
+
+impl LikeableType {
+    /// Returns the name of the likeable type.
+    pub fn name(&self) -> &str {
+        match self {
+            LikeableType::Activity => "Activity",
+            LikeableType::ActivityReply => "Activity Reply",
+            LikeableType::Thread => "Thread",
+            LikeableType::ThreadComment => "Thread Comment",
+        }
+    }
+}
+
+impl From<&str> for LikeableType {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "ACTIVITY_REPLY" => LikeableType::ActivityReply,
+            "THREAD" => LikeableType::Thread,
+            "THREAD_COMMENT" => LikeableType::ThreadComment,
+            _ => LikeableType::default(),
+        }
+    }
+}
+
+impl From<String> for LikeableType {
+    fn from(value: String) -> Self {
+        LikeableType::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for LikeableType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(LikeableType::from("activity"), LikeableType::Activity);
+        assert_eq!(
+            LikeableType::from("ACTIVITY_REPLY"),
+            LikeableType::ActivityReply
+        );
+        assert_eq!(LikeableType::from(" thread "), LikeableType::Thread);
+        assert_eq!(
+            LikeableType::from("thread_comment".to_string()),
+            LikeableType::ThreadComment
+        );
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_value(LikeableType::ThreadComment).unwrap(),
+            serde_json::json!("THREAD_COMMENT")
+        );
+    }
+}
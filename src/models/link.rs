@@ -3,13 +3,14 @@
 
 //! This module contains the `Link` struct.
 
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
 use super::{Color, Language};
 
 /// Represents a link.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Link {
     /// The ID of the link.
     pub id: Option<i64>,
@@ -24,6 +25,7 @@ pub struct Link {
     /// The ID of the site of the link.
     pub site_id: Option<i64>,
     /// The type of the link.
+    #[serde(alias = "type")]
     pub link_type: Option<LinkType>,
     /// The language of the link.
     pub language: Option<Language>,
@@ -37,9 +39,34 @@ pub struct Link {
     pub is_disabled: Option<bool>,
 }
 
+impl Link {
+    /// Returns the name to display for the link.
+    ///
+    /// Falls back from the title of the link to its site, and then to
+    /// the host of its URL.
+    pub fn display_name(&self) -> String {
+        if let Some(title) = self.title.as_deref().filter(|title| !title.is_empty()) {
+            return title.to_string();
+        }
+        if !self.site.is_empty() {
+            return self.site.clone();
+        }
+
+        Url::parse(&self.url)
+            .ok()
+            .and_then(|url| url.host_str().map(String::from))
+            .unwrap_or_else(|| self.url.clone())
+    }
+
+    /// Returns whether the link is the official site of the media.
+    pub fn is_official_site(&self) -> bool {
+        self.site.trim().eq_ignore_ascii_case("official site")
+    }
+}
+
 /// Represents the type of link.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "UPPERCASE"))]
+#[serde(rename_all = "UPPERCASE")]
 pub enum LinkType {
     /// The info link type.
     #[default]
@@ -48,6 +75,9 @@ pub enum LinkType {
     Streaming,
     /// The social link type.
     Social,
+    /// A link type not known to this version of the crate.
+    #[serde(other)]
+    Unknown,
 }
 
 impl std::fmt::Display for LinkType {
@@ -56,6 +86,93 @@ impl std::fmt::Display for LinkType {
             LinkType::Info => write!(f, "Info"),
             LinkType::Streaming => write!(f, "Streaming"),
             LinkType::Social => write!(f, "Social"),
+            LinkType::Unknown => write!(f, "Unknown"),
         }
     }
 }
+
+impl From<&str> for LinkType {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "INFO" => LinkType::Info,
+            "STREAMING" => LinkType::Streaming,
+            "SOCIAL" => LinkType::Social,
+            _ => LinkType::Unknown,
+        }
+    }
+}
+
+impl From<String> for LinkType {
+    fn from(value: String) -> Self {
+        LinkType::from(value.as_str())
+    }
+}
+
+/// Returns the links of a type out of the external links of a media.
+///
+/// # Arguments
+///
+/// * `links` - The external links of the media, if loaded.
+/// * `link_type` - The type of the links to return.
+pub(crate) fn links_of(links: Option<&[Link]>, link_type: LinkType) -> Vec<&Link> {
+    links
+        .unwrap_or_default()
+        .iter()
+        .filter(|link| link.link_type.as_ref() == Some(&link_type))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn links() -> Vec<Link> {
+        serde_json::from_value(serde_json::json!([
+            { "id": 1, "url": "https://www.cowboy-bebop.net/", "site": "Official Site", "type": "INFO", "language": "Japanese" },
+            { "id": 2, "url": "https://www.crunchyroll.com/series/GYVNM8476", "site": "Crunchyroll", "type": "STREAMING" },
+            { "id": 3, "url": "https://www.netflix.com/title/80001305", "site": "Netflix", "type": "STREAMING", "language": "English" },
+            { "id": 4, "url": "https://twitter.com/bebop_anime", "site": "Twitter", "type": "SOCIAL" },
+            { "id": 5, "url": "https://example.com/bebop", "site": "", "type": "NEWSLETTER" }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_links_of() {
+        let links = links();
+
+        let streaming = links_of(Some(&links), LinkType::Streaming);
+        assert_eq!(
+            streaming
+                .iter()
+                .map(|link| link.site.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Crunchyroll", "Netflix"]
+        );
+        assert_eq!(links_of(Some(&links), LinkType::Social).len(), 1);
+        assert_eq!(links_of(Some(&links), LinkType::Unknown)[0].id, Some(5));
+        assert!(links_of(None, LinkType::Info).is_empty());
+    }
+
+    #[test]
+    fn test_official_site() {
+        let links = links();
+
+        assert!(links[0].is_official_site());
+        assert!(!links[1].is_official_site());
+    }
+
+    #[test]
+    fn test_display_name() {
+        let mut links = links();
+
+        assert_eq!(links[1].display_name(), "Crunchyroll");
+        assert_eq!(links[4].display_name(), "example.com");
+
+        links[1].title = Some("Cowboy Bebop on Crunchyroll".to_string());
+        assert_eq!(links[1].display_name(), "Cowboy Bebop on Crunchyroll");
+
+        links[4].url = "not a url".to_string();
+        assert_eq!(links[4].display_name(), "not a url");
+    }
+}
@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `ListComparison` struct and its related types.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{MediaListEntry, MediaListGroup, MediaListStatus, ScoreFormat};
+
+/// Represents the comparison of the media lists of two users.
+///
+/// The `ListComparison` struct contains the media both users have on
+/// their lists, the media only one of them has, and how much their
+/// scores agree. Entries are ordered by the ID of their media.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListComparison {
+    /// The media on the lists of both users.
+    pub shared: Vec<SharedEntry>,
+    /// The entries of the media only the first user has.
+    pub only_a: Vec<MediaListEntry>,
+    /// The entries of the media only the second user has.
+    pub only_b: Vec<MediaListEntry>,
+    /// The Pearson correlation of the scores of the media both users
+    /// scored, from -1 to 1.
+    ///
+    /// It is `None` when fewer than two media were scored by both users,
+    /// or when one of them gave the same score to all of them.
+    pub correlation: Option<f64>,
+}
+
+/// Represents a media on the lists of two users.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SharedEntry {
+    /// The ID of the media.
+    pub media_id: i64,
+    /// The entry of the first user.
+    pub a: MediaListEntry,
+    /// The entry of the second user.
+    pub b: MediaListEntry,
+    /// The score of the first user on a 0 to 100 scale, if scored.
+    pub score_a: Option<f64>,
+    /// The score of the second user on a 0 to 100 scale, if scored.
+    pub score_b: Option<f64>,
+}
+
+impl ListComparison {
+    /// Compares the media lists of two users.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The media list of the first user.
+    /// * `format_a` - The score format of the first user.
+    /// * `b` - The media list of the second user.
+    /// * `format_b` - The score format of the second user.
+    pub(crate) fn new(
+        a: &[MediaListGroup],
+        format_a: ScoreFormat,
+        b: &[MediaListGroup],
+        format_b: ScoreFormat,
+    ) -> Self {
+        let mut entries_a = entries_by_media(a);
+        let entries_b = entries_by_media(b);
+
+        let mut comparison = Self::default();
+
+        for (media_id, b) in entries_b {
+            match entries_a.remove(&media_id) {
+                Some(a) => comparison.shared.push(SharedEntry {
+                    media_id,
+                    score_a: normalized_score(&a, format_a),
+                    score_b: normalized_score(&b, format_b),
+                    a,
+                    b,
+                }),
+                None => comparison.only_b.push(b),
+            }
+        }
+        comparison.only_a = entries_a.into_values().collect();
+        comparison.shared.sort_by_key(|entry| entry.media_id);
+
+        let scores: Vec<(f64, f64)> = comparison
+            .shared
+            .iter()
+            .filter_map(|entry| Some((entry.score_a?, entry.score_b?)))
+            .collect();
+        comparison.correlation = pearson(&scores);
+
+        comparison
+    }
+
+    /// Returns the shared media the first user completed and the second
+    /// user plans to consume.
+    pub fn completed_by_a_planned_by_b(&self) -> Vec<&SharedEntry> {
+        self.shared
+            .iter()
+            .filter(|entry| completed_and_planned(&entry.a, &entry.b))
+            .collect()
+    }
+
+    /// Returns the shared media the second user completed and the first
+    /// user plans to consume.
+    pub fn completed_by_b_planned_by_a(&self) -> Vec<&SharedEntry> {
+        self.shared
+            .iter()
+            .filter(|entry| completed_and_planned(&entry.b, &entry.a))
+            .collect()
+    }
+}
+
+/// Returns the entries of a media list by the ID of their media.
+///
+/// An entry on several custom lists is only kept once.
+fn entries_by_media(groups: &[MediaListGroup]) -> BTreeMap<i64, MediaListEntry> {
+    let mut entries = BTreeMap::new();
+
+    for entry in groups.iter().flat_map(|group| &group.entries) {
+        entries
+            .entry(entry.media_id)
+            .or_insert_with(|| entry.clone());
+    }
+
+    entries
+}
+
+/// Returns the score of an entry on a 0 to 100 scale, if scored.
+fn normalized_score(entry: &MediaListEntry, format: ScoreFormat) -> Option<f64> {
+    entry
+        .score
+        .filter(|score| *score > 0.0)
+        .map(|score| format.normalize(score))
+}
+
+/// Returns whether the first entry is completed and the second planned.
+fn completed_and_planned(completed: &MediaListEntry, planned: &MediaListEntry) -> bool {
+    completed.status == Some(MediaListStatus::Completed)
+        && planned.status == Some(MediaListStatus::Planning)
+}
+
+/// Returns the Pearson correlation of pairs of scores.
+fn pearson(scores: &[(f64, f64)]) -> Option<f64> {
+    if scores.len() < 2 {
+        return None;
+    }
+
+    let count = scores.len() as f64;
+    let mean_a = scores.iter().map(|(a, _)| a).sum::<f64>() / count;
+    let mean_b = scores.iter().map(|(_, b)| b).sum::<f64>() / count;
+
+    let (mut covariance, mut variance_a, mut variance_b) = (0.0, 0.0, 0.0);
+    for (a, b) in scores {
+        covariance += (a - mean_a) * (b - mean_b);
+        variance_a += (a - mean_a).powi(2);
+        variance_b += (b - mean_b).powi(2);
+    }
+
+    if variance_a == 0.0 || variance_b == 0.0 {
+        return None;
+    }
+
+    Some(covariance / (variance_a * variance_b).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(media_id: i64, status: MediaListStatus, score: f64) -> MediaListEntry {
+        MediaListEntry {
+            id: media_id,
+            media_id,
+            status: Some(status),
+            score: Some(score),
+            ..Default::default()
+        }
+    }
+
+    fn list(entries: Vec<MediaListEntry>) -> Vec<MediaListGroup> {
+        vec![MediaListGroup {
+            name: "Completed".to_string(),
+            entries,
+            ..Default::default()
+        }]
+    }
+
+    #[test]
+    fn test_list_comparison() {
+        let a = list(vec![
+            entry(1, MediaListStatus::Completed, 9.0),
+            entry(2, MediaListStatus::Completed, 7.0),
+            entry(3, MediaListStatus::Completed, 5.0),
+            entry(4, MediaListStatus::Completed, 8.0),
+        ]);
+        let b = list(vec![
+            entry(3, MediaListStatus::Completed, 2.0),
+            entry(1, MediaListStatus::Completed, 4.0),
+            entry(2, MediaListStatus::Completed, 3.0),
+            entry(4, MediaListStatus::Planning, 0.0),
+            entry(5, MediaListStatus::Current, 0.0),
+        ]);
+
+        let comparison = ListComparison::new(&a, ScoreFormat::Point10, &b, ScoreFormat::Point5);
+
+        let shared: Vec<_> = comparison.shared.iter().map(|e| e.media_id).collect();
+        assert_eq!(shared, vec![1, 2, 3, 4]);
+        assert!(comparison.only_a.is_empty());
+        assert_eq!(comparison.only_b.len(), 1);
+        assert_eq!(comparison.only_b[0].media_id, 5);
+
+        assert_eq!(comparison.shared[0].score_a, Some(90.0));
+        assert_eq!(comparison.shared[0].score_b, Some(80.0));
+        assert_eq!(comparison.shared[3].score_b, None);
+
+        // The scores of media 1 to 3 agree perfectly once normalized.
+        assert!((comparison.correlation.unwrap() - 1.0).abs() < 1e-9);
+
+        let recommended = comparison.completed_by_a_planned_by_b();
+        assert_eq!(recommended.len(), 1);
+        assert_eq!(recommended[0].media_id, 4);
+        assert!(comparison.completed_by_b_planned_by_a().is_empty());
+    }
+
+    #[test]
+    fn test_list_comparison_without_correlation() {
+        let a = list(vec![entry(1, MediaListStatus::Completed, 8.0)]);
+        let b = list(vec![
+            entry(1, MediaListStatus::Completed, 8.0),
+            entry(1, MediaListStatus::Completed, 8.0),
+        ]);
+
+        let comparison = ListComparison::new(&a, ScoreFormat::Point10, &b, ScoreFormat::Point10);
+
+        assert_eq!(comparison.shared.len(), 1);
+        assert_eq!(comparison.correlation, None);
+    }
+
+    #[test]
+    fn test_pearson() {
+        assert_eq!(pearson(&[(1.0, 2.0)]), None);
+        assert_eq!(pearson(&[(1.0, 2.0), (1.0, 3.0)]), None);
+
+        let inverse = pearson(&[(10.0, 90.0), (50.0, 50.0), (90.0, 10.0)]).unwrap();
+        assert!((inverse + 1.0).abs() < 1e-9);
+    }
+}
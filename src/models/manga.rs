@@ -3,13 +3,24 @@
 
 //! This module contains the `Manga` struct and its related types.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::{
-    Character, Cover, Date, Format, Link, Person, Relation, Source, Status, Studio, Tag, Title,
+    connection::nullable,
+    description::{render, truncate, Style},
+    link::links_of,
+    media_list::{validate_progress, validate_score},
+    rank::best_rank,
+    tag::{tags_by_category, tags_without_spoilers},
+    Character, CharacterConnection, CharacterRole, Cover, Date, DescriptionFormat, Format, Genre,
+    Link, LinkType, MediaListEntry, MediaListEntryUpdate, MediaListStatus, MediaRank, MediaStats,
+    MediaStatus, MediaType, Person, Recommendation, Relation, RelationConnection, Review, Source,
+    Studio, Tag, Title, Trailer,
 };
-use crate::{Client, Result};
+use crate::{parse, Client, Error, Result};
 
 /// Represents a manga with various attributes.
 ///
@@ -19,7 +30,7 @@ use crate::{Client, Result};
 /// hashtags, images, genres, synonyms, scores, popularity, tags,
 /// relations, characters, staff, studios, and other metadata.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Manga {
     /// The ID of the manga.
     pub id: i64,
@@ -30,9 +41,12 @@ pub struct Manga {
     /// The format of the manga.
     pub format: Format,
     /// The status of the manga.
-    pub status: Status,
+    pub status: MediaStatus,
     /// The description of the manga.
     pub description: String,
+    /// The format of the description.
+    #[serde(default)]
+    pub description_format: DescriptionFormat,
     /// The start date of the manga.
     pub start_date: Option<Date>,
     /// The end date of the manga.
@@ -57,8 +71,15 @@ pub struct Manga {
     /// The banner image of the manga.
     #[serde(rename = "bannerImage")]
     pub banner: Option<String>,
+    /// The trailer of the manga, if any.
+    #[serde(default)]
+    pub trailer: Option<Trailer>,
+    /// The rankings of the manga, if any.
+    pub rankings: Option<Vec<MediaRank>>,
+    /// The score and list statistics of the manga, if any.
+    pub stats: Option<MediaStats>,
     /// The genres of the manga.
-    pub genres: Option<Vec<String>>,
+    pub genres: Option<Vec<Genre>>,
     /// The synonyms of the manga.
     pub synonyms: Option<Vec<String>>,
     /// The average score of the manga.
@@ -76,9 +97,11 @@ pub struct Manga {
     /// The tags of the manga.
     pub tags: Option<Vec<Tag>>,
     /// The relations of the manga.
-    pub(crate) relations: Value,
+    #[serde(default, deserialize_with = "nullable")]
+    pub(crate) relations: RelationConnection,
     /// The characters of the manga.
-    pub(crate) characters: Value,
+    #[serde(default, deserialize_with = "nullable")]
+    pub(crate) characters: CharacterConnection,
     /// The staff of the manga.
     #[serde(skip)]
     pub staff: Option<Vec<Person>>,
@@ -93,6 +116,12 @@ pub struct Manga {
     pub is_adult: bool,
     /// The external links of the manga.
     pub external_links: Option<Vec<Link>>,
+    /// The role of a character in the manga, when listed from the
+    /// character.
+    pub character_role: Option<CharacterRole>,
+    /// The role of a staff in the manga, like "Director", when listed
+    /// from the staff.
+    pub staff_role: Option<String>,
     /// The site URL of the manga.
     #[serde(rename = "siteUrl")]
     pub url: String,
@@ -101,21 +130,19 @@ pub struct Manga {
     #[serde(skip)]
     pub(crate) client: Client,
     /// Whether the person's data is fully loaded.
-    #[serde(default)]
+    #[serde(skip)]
     pub(crate) is_full_loaded: bool,
 }
 
 impl Manga {
     /// Loads the full details of the manga.
     ///
+    /// If the manga is already fully loaded, it is returned as is.
+    ///
     /// # Errors
     ///
     /// Returns an error if the manga details cannot be loaded.
     ///
-    /// # Panics
-    ///
-    /// Panics if the manga is already fully loaded.
-    ///
     /// # Example
     ///
     /// ```no_run
@@ -127,54 +154,395 @@ impl Manga {
     /// # }
     /// ```
     pub async fn load_full(self) -> Result<Self> {
-        if !self.is_full_loaded {
-            self.client.get_manga(self.id).await
-        } else {
-            panic!("This manga is already full loaded")
+        if self.is_full_loaded {
+            return Ok(self);
+        }
+
+        self.client.invalidate(MediaType::Manga, self.id);
+        let mut manga = self.client.get_manga(self.id).await?;
+        // The roles only come with the character or staff the manga was
+        // listed from, so keep them.
+        if manga.character_role.is_none() {
+            manga.character_role = self.character_role;
         }
+        if manga.staff_role.is_none() {
+            manga.staff_role = self.staff_role;
+        }
+
+        Ok(manga)
+    }
+
+    /// Sets the number of chapters read on the authenticated user's
+    /// list, adding the manga to it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `chapters` - The number of chapters read.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidProgress` if the manga is known to have
+    /// fewer chapters, or an error if the client has no API token or if
+    /// the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Manga, Result};
+    /// #
+    /// # async fn f(manga: Manga) -> Result<()> {
+    /// let entry = manga.set_progress(1100).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_progress(&self, chapters: u16) -> Result<MediaListEntry> {
+        validate_progress(chapters as u32, self.chapters)?;
+
+        self.client
+            .save_media_list_entry(
+                self.id,
+                MediaListEntryUpdate::new().progress(chapters as u32),
+            )
+            .await
     }
 
-    /// Returns the characters of the manga.
+    /// Sets the number of volumes read on the authenticated user's list,
+    /// adding the manga to it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `volumes` - The number of volumes read.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidProgress` if the manga is known to have
+    /// fewer volumes, or an error if the client has no API token or if
+    /// the request fails.
+    pub async fn set_volumes(&self, volumes: u16) -> Result<MediaListEntry> {
+        validate_progress(volumes as u32, self.volumes)?;
+
+        self.client
+            .save_media_list_entry(
+                self.id,
+                MediaListEntryUpdate::new().progress_volumes(volumes as u32),
+            )
+            .await
+    }
+
+    /// Sets the status of the manga on the authenticated user's list,
+    /// adding the manga to it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The status of the manga on the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token or if the
+    /// request fails.
+    pub async fn set_status(&self, status: MediaListStatus) -> Result<MediaListEntry> {
+        self.client
+            .save_media_list_entry(self.id, MediaListEntryUpdate::new().status(status))
+            .await
+    }
+
+    /// Sets the score of the manga on the authenticated user's list,
+    /// adding the manga to it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The score, in the score format of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidScore` if the score is not between 0 and
+    /// 100, or an error if the client has no API token or if the request
+    /// fails.
+    pub async fn set_score(&self, score: f64) -> Result<MediaListEntry> {
+        validate_score(score)?;
+
+        self.client
+            .save_media_list_entry(self.id, MediaListEntryUpdate::new().score(score))
+            .await
+    }
+
+    /// Toggles whether the manga is a favourite of the authenticated
+    /// user, returning whether it is a favourite afterwards.
+    ///
+    /// The new state is read back from the API and stored in
+    /// `is_favourite`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FavouriteBlocked` if `is_favourite_blocked` is
+    /// set, or `Error::Unauthorized` if the client has no API token,
+    /// both without sending any request. Returns an error if the request
+    /// fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Manga, Result};
+    /// #
+    /// # async fn f(mut manga: Manga) -> Result<()> {
+    /// let is_favourite = manga.toggle_favourite().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn toggle_favourite(&mut self) -> Result<bool> {
+        let is_favourite = self
+            .client
+            .toggle_favourite_state(MediaType::Manga, self.id, self.is_favourite_blocked)
+            .await?;
+        self.is_favourite = Some(is_favourite);
+
+        Ok(is_favourite)
+    }
+
+    /// Builds a partially loaded manga from a media node of an API
+    /// response, such as the ones returned by searches and lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `media` - The media node to build the manga from.
+    /// * `client` - The client to attach to the manga.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node has no ID or an invalid title.
+    pub(crate) fn from_node(media: &Value, client: &Client) -> Result<Self> {
+        Ok(Self {
+            id: media["id"].as_i64().ok_or(Error::InvalidId)?,
+            id_mal: media["idMal"].as_i64(),
+            title: parse::deserialize(media, "title")?,
+            format: Format::deserialize(&media["format"]).unwrap_or_default(),
+            status: MediaStatus::deserialize(&media["status"]).unwrap_or_default(),
+            description: media["description"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            description_format: client.description_format(),
+            chapters: media["chapters"].as_u64().map(|x| x as u16),
+            volumes: media["volumes"].as_u64().map(|x| x as u16),
+            cover: Cover::deserialize(&media["coverImage"]).unwrap_or_default(),
+            banner: media["bannerImage"].as_str().map(String::from),
+            average_score: media["averageScore"].as_u64().map(|x| x as u8),
+            mean_score: media["meanScore"].as_u64().map(|x| x as u8),
+            is_adult: media["isAdult"].as_bool().unwrap_or_default(),
+            url: media["siteUrl"].as_str().unwrap_or_default().to_string(),
+
+            client: client.clone(),
+            ..Default::default()
+        })
+    }
+
+    /// Returns a page of the recommendations for the manga, sorted by
+    /// rating.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of recommendations to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the recommendations cannot be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Manga, Result};
+    /// #
+    /// # async fn f(manga: Manga) -> Result<()> {
+    /// for recommendation in manga.recommendations(1, 10).await? {
+    ///     println!("{} ({})", recommendation.media.title(), recommendation.rating);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn recommendations(&self, page: u16, per_page: u16) -> Result<Vec<Recommendation>> {
+        self.client
+            .get_recommendations(self.id, page, per_page)
+            .await
+    }
+
+    /// Returns a page of the reviews of the manga, sorted by rating.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of reviews to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reviews cannot be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Manga, Result};
+    /// #
+    /// # async fn f(manga: Manga) -> Result<()> {
+    /// for review in manga.reviews(1, 10).await? {
+    ///     println!("{}: {}", review.user.name, review.summary);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reviews(&self, page: u16, per_page: u16) -> Result<Vec<Review>> {
+        self.client.get_reviews(self.id, page, per_page).await
+    }
+
+    /// Returns the characters of the manga, with their role set.
+    ///
+    /// Returns an empty list if the characters were not loaded, like on
+    /// a manga from a search.
+    ///
+    /// # Errors
+    ///
+    /// This function does not currently fail, the `Result` is kept for
+    /// compatibility.
     pub fn characters(&self) -> Result<Vec<Character>> {
-        let binding = Vec::new();
-        let edges = self
-            .characters
-            .as_object()
-            .and_then(|obj| obj.get("edges"))
-            .and_then(|edges| edges.as_array())
-            .unwrap_or(&binding);
-
-        let mut characters = Vec::with_capacity(edges.len());
-
-        for edge in edges {
-            let binding = serde_json::Map::new();
-            let obj = edge.as_object().unwrap_or(&binding);
-            let node = obj.get("node").unwrap_or(&Value::Null);
-            let role = obj.get("role").and_then(|role| role.as_str()).unwrap_or("");
-
-            let mut character: Character = serde_json::from_value(node.clone()).unwrap_or_default();
-            character.role = Some(role.into());
-            characters.push(character);
-        }
+        Ok(self.characters.characters())
+    }
+
+    /// Returns the description of the manga as plain text.
+    ///
+    /// # Arguments
+    ///
+    /// * `hide_spoilers` - Whether the spoilers are removed, rather than
+    ///   kept as regular text.
+    pub fn description_plain(&self, hide_spoilers: bool) -> String {
+        render(&self.description, Style::Plain, hide_spoilers)
+    }
+
+    /// Returns the description of the manga as markdown.
+    ///
+    /// Italics, bold text and links are kept, and the spoilers are
+    /// masked between `||` markers, as used by Discord and Telegram.
+    ///
+    /// # Arguments
+    ///
+    /// * `hide_spoilers` - Whether the spoilers are removed, rather than
+    ///   masked.
+    pub fn description_markdown(&self, hide_spoilers: bool) -> String {
+        render(&self.description, Style::Markdown, hide_spoilers)
+    }
+
+    /// Returns a preview of the description of the manga, as plain text
+    /// without spoilers.
+    ///
+    /// The preview is cut between graphemes, ending with an ellipsis, if
+    /// longer than `max_len`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_len` - The maximum number of characters of the preview,
+    ///   the ellipsis included.
+    pub fn description_preview(&self, max_len: usize) -> String {
+        truncate(&self.description_plain(true), max_len)
+    }
+
+    /// Returns the external links of the manga of a type.
+    ///
+    /// # Arguments
+    ///
+    /// * `link_type` - The type of the links to return.
+    pub fn external_links_of(&self, link_type: LinkType) -> Vec<&Link> {
+        links_of(self.external_links.as_deref(), link_type)
+    }
+
+    /// Returns the link to the official site of the manga, if any.
+    pub fn official_site(&self) -> Option<&Link> {
+        self.external_links
+            .iter()
+            .flatten()
+            .find(|link| link.is_official_site())
+    }
 
-        Ok(characters)
+    /// Returns the all-time score ranking of the manga, if any.
+    pub fn best_rank(&self) -> Option<&MediaRank> {
+        best_rank(self.rankings.as_deref())
+    }
+
+    /// Returns the tags of the manga that are not spoilers.
+    ///
+    /// Returns an empty list if the tags were not loaded.
+    pub fn tags_without_spoilers(&self) -> Vec<&Tag> {
+        tags_without_spoilers(self.tags.as_deref())
+    }
+
+    /// Returns the tags of the manga, spoilers included, grouped by their
+    /// category.
+    pub fn tags_by_category(&self) -> BTreeMap<String, Vec<&Tag>> {
+        tags_by_category(self.tags.as_deref())
+    }
+
+    /// Returns the main characters of the manga, out of the loaded
+    /// characters.
+    pub fn main_characters(&self) -> Vec<Character> {
+        self.characters
+            .characters()
+            .into_iter()
+            .filter(|character| character.role == Some(CharacterRole::Main))
+            .collect()
     }
 
     /// Returns the relations of the manga.
+    ///
+    /// Returns an empty list if the relations were not loaded, like on
+    /// a manga from a search.
+    ///
+    /// # Errors
+    ///
+    /// This function does not currently fail, the `Result` is kept for
+    /// compatibility.
     pub fn relations(&self) -> Result<Vec<Relation>> {
-        let binding = Vec::new();
-        let edges = self
-            .relations
-            .as_object()
-            .and_then(|obj| obj.get("edges"))
-            .and_then(|edges| edges.as_array())
-            .unwrap_or(&binding);
-
-        let relations = edges
-            .iter()
-            .map(|r| serde_json::from_value(r.clone()).unwrap_or_default())
-            .collect();
+        let mut relations = self.relations.relations();
+        for relation in &mut relations {
+            relation.client = self.client.clone();
+        }
 
         Ok(relations)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_round_trip() {
+        let manga: Manga = serde_json::from_value(serde_json::json!({
+            "id": 30013,
+            "title": { "romaji": "One Piece", "english": "One Piece", "native": "ONE PIECE" },
+            "format": "MANGA",
+            "status": "RELEASING",
+            "description": "Gol D. Roger was known as the Pirate King...",
+            "startDate": { "year": 1997, "month": 7, "day": 22 },
+            "endDate": { "year": null, "month": null, "day": null },
+            "chapters": null,
+            "countryOfOrigin": "JP",
+            "source": "ORIGINAL",
+            "coverImage": { "large": "https://example.com/large.jpg", "color": "#e4a15d" },
+            "bannerImage": null,
+            "tags": [{
+                "id": 29,
+                "name": "Magic",
+                "description": "Prominently features magical elements or the use of magic.",
+                "category": "Theme-Fantasy",
+                "isGeneralSpoiler": false,
+                "isAdult": false
+            }],
+            "isAdult": false,
+            "externalLinks": [{ "url": "https://www.viz.com/one-piece", "site": "Viz", "type": "INFO" }],
+            "staffRole": "Story & Art",
+            "siteUrl": "https://anilist.co/manga/30013"
+        }))
+        .unwrap();
+
+        let json = serde_json::to_string(&manga).unwrap();
+
+        assert_eq!(serde_json::from_str::<Manga>(&json).unwrap(), manga);
+    }
+}
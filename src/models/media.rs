@@ -1,11 +1,26 @@
 // SPDX-License-Identifier: MIT↴
 // Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>↴
 
-//! This module contains the `Media` enum.
+//! This module contains the `Media` enum and the `MediaKey` struct.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    future::Future,
+};
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Anime, CharacterRole, Date, Format, Manga, MediaType, Relation, RelationType};
+use crate::{client::MAX_PER_PAGE, Client, Error, Result};
 
-use super::{Anime, Format, Manga};
+/// The relations followed when walking the franchise of a media.
+const FRANCHISE_RELATIONS: [RelationType; 4] = [
+    RelationType::Prequel,
+    RelationType::Sequel,
+    RelationType::Parent,
+    RelationType::SideStory,
+];
 
 /// Represents different types of media.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
@@ -22,6 +37,25 @@ pub enum Media {
 }
 
 impl Media {
+    /// Builds a partially loaded media from a media node of an API
+    /// response, using its `type` field to pick the variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `media` - The media node to build the media from.
+    /// * `client` - The client to attach to the media.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node has no ID or an invalid title.
+    pub(crate) fn from_node(media: &Value, client: &Client) -> Result<Self> {
+        match media["type"].as_str() {
+            Some("ANIME") => Ok(Media::Anime(Anime::from_node(media, client)?)),
+            Some("MANGA") => Ok(Media::Manga(Manga::from_node(media, client)?)),
+            _ => Ok(Media::Unknown),
+        }
+    }
+
     /// Returns the id of the media.
     pub fn id(&self) -> i64 {
         match self {
@@ -40,6 +74,26 @@ impl Media {
         }
     }
 
+    /// Returns the role of a character in the media, when listed from
+    /// the character.
+    pub fn character_role(&self) -> Option<&CharacterRole> {
+        match self {
+            Media::Anime(anime) => anime.character_role.as_ref(),
+            Media::Manga(manga) => manga.character_role.as_ref(),
+            Media::Unknown => None,
+        }
+    }
+
+    /// Returns the role of a staff in the media, like "Director", when
+    /// listed from the staff.
+    pub fn staff_role(&self) -> Option<&str> {
+        match self {
+            Media::Anime(anime) => anime.staff_role.as_deref(),
+            Media::Manga(manga) => manga.staff_role.as_deref(),
+            Media::Unknown => None,
+        }
+    }
+
     /// Returns the format of the media.
     pub fn format(&self) -> Option<&Format> {
         match self {
@@ -48,6 +102,237 @@ impl Media {
             Media::Unknown => None,
         }
     }
+
+    /// Returns the start date of the media.
+    pub fn start_date(&self) -> Option<&Date> {
+        match self {
+            Media::Anime(anime) => anime.start_date.as_ref(),
+            Media::Manga(manga) => manga.start_date.as_ref(),
+            Media::Unknown => None,
+        }
+    }
+
+    /// Returns the whole franchise of the media, the media included.
+    ///
+    /// The prequels, sequels, parent and side stories are followed
+    /// breadth-first, each media being fully loaded through the client
+    /// once, and up to `max_depth` relations away from the media. The
+    /// franchise is ordered by start date, the media with an unknown
+    /// start date coming last.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client used to load the related media.
+    /// * `max_depth` - The maximum number of relations to follow from
+    ///   the media.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if loading any of the media fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Media, Client, Result};
+    /// #
+    /// # async fn f(client: Client) -> Result<()> {
+    /// let media = Media::from(client.get_anime(1575).await?);
+    /// let franchise = media.franchise(&client, 5).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn franchise(&self, client: &Client, max_depth: usize) -> Result<Vec<Media>> {
+        if matches!(self, Media::Unknown) {
+            return Ok(Vec::new());
+        }
+
+        let mut seen = HashSet::from([self.id()]);
+        let mut queue = VecDeque::from([(self.clone(), 0)]);
+        let mut franchise = Vec::new();
+
+        while let Some((media, depth)) = queue.pop_front() {
+            let media = media.load_full_with(client).await?;
+
+            if depth < max_depth {
+                for relation in media.relations() {
+                    if !FRANCHISE_RELATIONS.contains(&relation.relation_type) {
+                        continue;
+                    }
+
+                    let related = relation.media();
+                    if !matches!(related, Media::Unknown) && seen.insert(related.id()) {
+                        queue.push_back((related, depth + 1));
+                    }
+                }
+            }
+
+            franchise.push(media);
+        }
+
+        franchise.sort_by_key(|media| {
+            let date = media.start_date();
+
+            (
+                date.and_then(Date::year).unwrap_or(i32::MAX),
+                date.and_then(Date::month).unwrap_or(u32::MAX),
+                date.and_then(Date::day).unwrap_or(u32::MAX),
+            )
+        });
+
+        Ok(franchise)
+    }
+
+    /// Returns the relations of the media, if loaded.
+    fn relations(&self) -> Vec<Relation> {
+        match self {
+            Media::Anime(anime) => anime.relations().unwrap_or_default(),
+            Media::Manga(manga) => manga.relations().unwrap_or_default(),
+            Media::Unknown => Vec::new(),
+        }
+    }
+
+    /// Loads the full details of the media through a client, unless
+    /// already loaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client used to load the media.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    async fn load_full_with(self, client: &Client) -> Result<Self> {
+        match self {
+            Media::Anime(anime) if anime.is_full_loaded => Ok(Media::Anime(anime)),
+            Media::Anime(anime) => Ok(Media::Anime(client.get_anime(anime.id).await?)),
+            Media::Manga(manga) if manga.is_full_loaded => Ok(Media::Manga(manga)),
+            Media::Manga(manga) => Ok(Media::Manga(client.get_manga(manga.id).await?)),
+            Media::Unknown => Ok(Media::Unknown),
+        }
+    }
+}
+
+/// Collects the media of every page of a paginated loader, keeping the
+/// ones of type `T`.
+///
+/// # Arguments
+///
+/// * `load` - The loader of a page, given the page number and the
+///   number of media per page.
+///
+/// # Errors
+///
+/// Returns an error if any page cannot be loaded.
+pub(crate) async fn collect_pages<T, F, Fut>(mut load: F) -> Result<Vec<T>>
+where
+    T: TryFrom<Media>,
+    F: FnMut(u16, u16) -> Fut,
+    Fut: Future<Output = Result<Vec<Media>>>,
+{
+    let per_page = MAX_PER_PAGE as u16;
+    let mut collected = Vec::new();
+
+    for page in 1.. {
+        let media = load(page, per_page).await?;
+        let is_last_page = media.len() < MAX_PER_PAGE;
+        collected.extend(
+            media
+                .into_iter()
+                .filter_map(|media| T::try_from(media).ok()),
+        );
+
+        if is_last_page {
+            break;
+        }
+    }
+
+    Ok(collected)
+}
+
+/// Represents the identity of a media, its type and its ID.
+///
+/// Unlike the media themselves, whose equality compares every field,
+/// two keys are equal when they identify the same media, whether it was
+/// fully loaded or not. This makes it suited to deduplicate media, as
+/// the key of a `HashSet` or a `HashMap`.
+///
+/// # Example
+///
+/// ```
+/// # use std::collections::HashSet;
+/// # use rust_anilist::models::{Anime, MediaKey};
+/// let mut anime = Anime::default();
+/// anime.id = 1;
+///
+/// let mut seen = HashSet::new();
+///
+/// assert!(seen.insert(MediaKey::from(&anime)));
+/// assert!(!seen.insert(MediaKey::from(&anime.clone())));
+/// ```
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct MediaKey {
+    /// The type of the media.
+    pub media_type: MediaType,
+    /// The ID of the media.
+    pub id: i64,
+}
+
+impl MediaKey {
+    /// Creates a new media key.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of the media.
+    /// * `id` - The ID of the media.
+    pub fn new(media_type: MediaType, id: i64) -> Self {
+        Self { media_type, id }
+    }
+}
+
+impl TryFrom<Media> for Anime {
+    type Error = Error;
+
+    fn try_from(media: Media) -> Result<Self> {
+        match media {
+            Media::Anime(anime) => Ok(anime),
+            Media::Manga(_) => Err(Error::InvalidMediaType(MediaType::Manga)),
+            Media::Unknown => Err(Error::InvalidMediaType(MediaType::Unknown)),
+        }
+    }
+}
+
+impl TryFrom<Media> for Manga {
+    type Error = Error;
+
+    fn try_from(media: Media) -> Result<Self> {
+        match media {
+            Media::Manga(manga) => Ok(manga),
+            Media::Anime(_) => Err(Error::InvalidMediaType(MediaType::Anime)),
+            Media::Unknown => Err(Error::InvalidMediaType(MediaType::Unknown)),
+        }
+    }
+}
+
+impl From<&Anime> for MediaKey {
+    fn from(anime: &Anime) -> Self {
+        MediaKey::new(MediaType::Anime, anime.id)
+    }
+}
+
+impl From<&Manga> for MediaKey {
+    fn from(manga: &Manga) -> Self {
+        MediaKey::new(MediaType::Manga, manga.id)
+    }
+}
+
+impl From<&Media> for MediaKey {
+    fn from(media: &Media) -> Self {
+        match media {
+            Media::Anime(anime) => MediaKey::from(anime),
+            Media::Manga(manga) => MediaKey::from(manga),
+            Media::Unknown => MediaKey::new(MediaType::Unknown, 0),
+        }
+    }
 }
 
 impl From<Anime> for Media {
@@ -61,3 +346,45 @@ impl From<Manga> for Media {
         Media::Manga(manga)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_media_key() {
+        let anime = Anime {
+            id: 1,
+            ..Default::default()
+        };
+        let loaded = Anime {
+            id: 1,
+            is_full_loaded: true,
+            ..Default::default()
+        };
+        let manga = Manga {
+            id: 1,
+            ..Default::default()
+        };
+
+        assert_ne!(anime, loaded);
+        assert_eq!(MediaKey::from(&anime), MediaKey::from(&loaded));
+        assert_ne!(MediaKey::from(&anime), MediaKey::from(&manga));
+        assert_eq!(
+            MediaKey::from(&Media::from(manga.clone())),
+            MediaKey::from(&manga)
+        );
+
+        let keys: HashSet<MediaKey> = [
+            MediaKey::from(&anime),
+            MediaKey::from(&loaded),
+            MediaKey::from(&manga),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(keys.len(), 2);
+    }
+}
@@ -0,0 +1,490 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `MediaListEntry` struct and its related types.
+
+use serde::{Deserialize, Serialize};
+
+use super::{Date, Media, MediaListStatus};
+use crate::{Error, Result};
+
+/// Represents a group of entries of a user's media list.
+///
+/// The `MediaListGroup` struct contains the entries of one of the
+/// lists of a user, which can be a status list (e.g. watching,
+/// completed) or a custom list created by the user.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaListGroup {
+    /// The name of the list.
+    pub name: String,
+    /// Whether the list is a custom list or not.
+    #[serde(default)]
+    pub is_custom_list: bool,
+    /// The status of the list, if it is a status list.
+    pub status: Option<MediaListStatus>,
+    /// The entries of the list.
+    #[serde(skip)]
+    pub entries: Vec<MediaListEntry>,
+}
+
+/// Represents an entry of a user's media list.
+///
+/// The `MediaListEntry` struct contains the progress of a user on a
+/// media, including its status, score, progress, repeat count, notes,
+/// custom lists, the dates the user started and completed it and the
+/// media itself.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaListEntry {
+    /// The ID of the entry.
+    pub id: i64,
+    /// The ID of the user owning the entry.
+    pub user_id: i64,
+    /// The ID of the media of the entry.
+    pub media_id: i64,
+    /// The status of the entry.
+    pub status: Option<MediaListStatus>,
+    /// The score of the entry.
+    pub score: Option<f64>,
+    /// The amount of episodes or chapters consumed.
+    pub progress: Option<u32>,
+    /// The amount of volumes read.
+    pub progress_volumes: Option<u32>,
+    /// The amount of times the media has been repeated.
+    pub repeat: Option<u32>,
+    /// The priority of planned entries.
+    pub priority: Option<u32>,
+    /// Whether the entry is private or not.
+    pub private: Option<bool>,
+    /// The notes of the entry.
+    pub notes: Option<String>,
+    /// The custom lists the entry is on.
+    pub custom_lists: Option<Vec<CustomList>>,
+    /// Whether the entry is hidden from the status lists or not.
+    pub hidden_from_status_lists: Option<bool>,
+    /// The date the user started the media.
+    pub started_at: Option<Date>,
+    /// The date the user completed the media.
+    pub completed_at: Option<Date>,
+    /// The updated date of the entry.
+    pub updated_at: Option<i64>,
+    /// The created date of the entry.
+    pub created_at: Option<i64>,
+    /// The media of the entry.
+    #[serde(skip)]
+    pub media: Option<Media>,
+}
+
+/// Checks that a progress does not exceed the total of a media, when
+/// the total is known.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidProgress` if the progress exceeds the total.
+pub(crate) fn validate_progress(progress: u32, total: Option<u16>) -> Result<()> {
+    match total {
+        Some(total) if progress > total as u32 => Err(Error::InvalidProgress {
+            progress,
+            total: total as u32,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that a score is within the range of scores, from 0 to 100.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidScore` if the score is out of the range.
+pub(crate) fn validate_score(score: f64) -> Result<()> {
+    if (0.0..=100.0).contains(&score) {
+        Ok(())
+    } else {
+        Err(Error::InvalidScore(score))
+    }
+}
+
+/// Builds the update marking one more episode or chapter of a media as
+/// consumed.
+///
+/// A media not on the list yet, or only planned, is started, and a
+/// completed media is rewatched from the start. The media is completed
+/// once the progress reaches its total, if known, counting one more
+/// repeat when it was being rewatched.
+///
+/// # Arguments
+///
+/// * `entry` - The current entry of the media, if on the list.
+/// * `total` - The total number of episodes or chapters, if known.
+/// * `today` - The date to start or complete the media on.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidProgress` if the new progress exceeds the
+/// total.
+pub(crate) fn increment_progress(
+    entry: Option<&MediaListEntry>,
+    total: Option<u16>,
+    today: Date,
+) -> Result<MediaListEntryUpdate> {
+    let status = entry.and_then(|entry| entry.status.clone());
+    let current = entry.and_then(|entry| entry.progress).unwrap_or_default();
+
+    let (progress, status) = match status {
+        Some(MediaListStatus::Completed) => (1, MediaListStatus::Repeating),
+        Some(MediaListStatus::Repeating) => (current + 1, MediaListStatus::Repeating),
+        _ => (current + 1, MediaListStatus::Current),
+    };
+    validate_progress(progress, total)?;
+
+    let mut update = MediaListEntryUpdate::new().progress(progress);
+    if matches!(
+        entry.and_then(|entry| entry.status.as_ref()),
+        None | Some(MediaListStatus::Planning)
+    ) {
+        update = update.started_at(today.clone());
+    }
+
+    if total.is_some_and(|total| progress == total as u32) {
+        update = update
+            .status(MediaListStatus::Completed)
+            .completed_at(today);
+
+        if status == MediaListStatus::Repeating {
+            let repeat = entry.and_then(|entry| entry.repeat).unwrap_or_default();
+            update = update.repeat(repeat + 1);
+        }
+    } else {
+        update = update.status(status);
+    }
+
+    Ok(update)
+}
+
+/// Represents a custom list of a user and whether an entry is on it.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CustomList {
+    /// The name of the custom list.
+    pub name: String,
+    /// Whether the entry is on the custom list or not.
+    pub enabled: bool,
+}
+
+/// Represents the changes to apply to a media list entry.
+///
+/// Every field is optional, only the fields that are set are sent to
+/// the API, leaving the others untouched.
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::models::{MediaListEntryUpdate, MediaListStatus};
+/// let update = MediaListEntryUpdate::new()
+///     .status(MediaListStatus::Completed)
+///     .score(8.5)
+///     .progress(24);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaListEntryUpdate {
+    /// The status of the entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<MediaListStatus>,
+    /// The score of the entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    /// The amount of episodes or chapters consumed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<u32>,
+    /// The amount of volumes read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_volumes: Option<u32>,
+    /// The amount of times the media has been repeated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat: Option<u32>,
+    /// The notes of the entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    /// The date the user started the media.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<Date>,
+    /// The date the user completed the media.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Date>,
+    /// Whether the entry is private or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+}
+
+impl MediaListEntryUpdate {
+    /// Creates a new update with no changes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the status of the entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The status of the entry.
+    pub fn status(mut self, status: MediaListStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the score of the entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The score of the entry, in the user's score format.
+    pub fn score(mut self, score: f64) -> Self {
+        self.score = Some(score);
+        self
+    }
+
+    /// Sets the amount of episodes or chapters consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `progress` - The amount of episodes or chapters consumed.
+    pub fn progress(mut self, progress: u32) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Sets the amount of volumes read.
+    ///
+    /// # Arguments
+    ///
+    /// * `progress_volumes` - The amount of volumes read.
+    pub fn progress_volumes(mut self, progress_volumes: u32) -> Self {
+        self.progress_volumes = Some(progress_volumes);
+        self
+    }
+
+    /// Sets the amount of times the media has been repeated.
+    ///
+    /// # Arguments
+    ///
+    /// * `repeat` - The amount of times the media has been repeated.
+    pub fn repeat(mut self, repeat: u32) -> Self {
+        self.repeat = Some(repeat);
+        self
+    }
+
+    /// Sets the notes of the entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `notes` - The notes of the entry.
+    pub fn notes(mut self, notes: &str) -> Self {
+        self.notes = Some(notes.to_string());
+        self
+    }
+
+    /// Sets the date the user started the media.
+    ///
+    /// # Arguments
+    ///
+    /// * `started_at` - The date the user started the media.
+    pub fn started_at(mut self, started_at: Date) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+
+    /// Sets the date the user completed the media.
+    ///
+    /// # Arguments
+    ///
+    /// * `completed_at` - The date the user completed the media.
+    pub fn completed_at(mut self, completed_at: Date) -> Self {
+        self.completed_at = Some(completed_at);
+        self
+    }
+
+    /// Sets whether the entry is private or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `private` - Whether the entry is private or not.
+    pub fn private(mut self, private: bool) -> Self {
+        self.private = Some(private);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_omits_unset_fields() {
+        let update = MediaListEntryUpdate::new().progress(3);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({ "progress": 3 })
+        );
+    }
+
+    #[test]
+    fn test_update_serializes_graphql_names() {
+        let update = MediaListEntryUpdate::new()
+            .status(MediaListStatus::Current)
+            .progress_volumes(2)
+            .started_at(Date::new(Some(2023), Some(10), None));
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "status": "CURRENT",
+                "progressVolumes": 2,
+                "startedAt": { "year": 2023, "month": 10, "day": null },
+            })
+        );
+    }
+
+    #[test]
+    fn test_entry_deserialize() {
+        let json = r#"{
+            "id": 1,
+            "userId": 2,
+            "mediaId": 3,
+            "status": "COMPLETED",
+            "score": 8.5,
+            "progress": 24,
+            "progressVolumes": null,
+            "repeat": 0,
+            "priority": 0,
+            "private": false,
+            "notes": null,
+            "customLists": [{ "name": "Favourites", "enabled": true }],
+            "hiddenFromStatusLists": false,
+            "startedAt": { "year": 2023, "month": null, "day": null },
+            "completedAt": { "year": null, "month": null, "day": null },
+            "updatedAt": 1700000000,
+            "createdAt": 1600000000
+        }"#;
+        let entry: MediaListEntry = serde_json::from_str(json).unwrap();
+
+        assert_eq!(entry.media_id, 3);
+        assert_eq!(entry.status, Some(MediaListStatus::Completed));
+        assert_eq!(entry.score, Some(8.5));
+        assert_eq!(entry.started_at, Some(Date::new(Some(2023), None, None)));
+        assert_eq!(
+            entry.custom_lists,
+            Some(vec![CustomList {
+                name: "Favourites".to_string(),
+                enabled: true,
+            }])
+        );
+    }
+
+    #[test]
+    fn test_validate_progress() {
+        assert!(validate_progress(12, Some(26)).is_ok());
+        assert!(validate_progress(26, Some(26)).is_ok());
+        assert!(validate_progress(1000, None).is_ok());
+        assert!(matches!(
+            validate_progress(27, Some(26)),
+            Err(Error::InvalidProgress {
+                progress: 27,
+                total: 26
+            })
+        ));
+    }
+
+    #[test]
+    fn test_validate_score() {
+        assert!(validate_score(0.0).is_ok());
+        assert!(validate_score(8.5).is_ok());
+        assert!(validate_score(100.0).is_ok());
+        assert!(matches!(validate_score(-1.0), Err(Error::InvalidScore(_))));
+        assert!(matches!(validate_score(101.0), Err(Error::InvalidScore(_))));
+        assert!(validate_score(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_increment_progress_starts_media() {
+        let today = Date::new(Some(2024), Some(1), Some(1));
+        let update = increment_progress(None, Some(12), today.clone()).unwrap();
+
+        assert_eq!(update.progress, Some(1));
+        assert_eq!(update.status, Some(MediaListStatus::Current));
+        assert_eq!(update.started_at, Some(today));
+        assert_eq!(update.completed_at, None);
+    }
+
+    #[test]
+    fn test_increment_progress_completes_media() {
+        let today = Date::new(Some(2024), Some(1), Some(1));
+        let entry = MediaListEntry {
+            status: Some(MediaListStatus::Current),
+            progress: Some(11),
+            ..Default::default()
+        };
+        let update = increment_progress(Some(&entry), Some(12), today.clone()).unwrap();
+
+        assert_eq!(update.progress, Some(12));
+        assert_eq!(update.status, Some(MediaListStatus::Completed));
+        assert_eq!(update.started_at, None);
+        assert_eq!(update.completed_at, Some(today));
+        assert_eq!(update.repeat, None);
+    }
+
+    #[test]
+    fn test_increment_progress_with_unknown_total() {
+        let entry = MediaListEntry {
+            status: Some(MediaListStatus::Paused),
+            progress: Some(1000),
+            ..Default::default()
+        };
+        let update = increment_progress(Some(&entry), None, Date::default()).unwrap();
+
+        assert_eq!(update.progress, Some(1001));
+        assert_eq!(update.status, Some(MediaListStatus::Current));
+        assert_eq!(update.completed_at, None);
+    }
+
+    #[test]
+    fn test_increment_progress_rewatches_media() {
+        let today = Date::new(Some(2024), Some(1), Some(1));
+        let completed = MediaListEntry {
+            status: Some(MediaListStatus::Completed),
+            progress: Some(12),
+            repeat: Some(1),
+            ..Default::default()
+        };
+        let update = increment_progress(Some(&completed), Some(12), today.clone()).unwrap();
+
+        assert_eq!(update.progress, Some(1));
+        assert_eq!(update.status, Some(MediaListStatus::Repeating));
+
+        let repeating = MediaListEntry {
+            status: Some(MediaListStatus::Repeating),
+            progress: Some(11),
+            repeat: Some(1),
+            ..Default::default()
+        };
+        let update = increment_progress(Some(&repeating), Some(12), today.clone()).unwrap();
+
+        assert_eq!(update.status, Some(MediaListStatus::Completed));
+        assert_eq!(update.repeat, Some(2));
+        assert_eq!(update.completed_at, Some(today));
+    }
+
+    #[test]
+    fn test_increment_progress_beyond_total() {
+        let entry = MediaListEntry {
+            status: Some(MediaListStatus::Current),
+            progress: Some(12),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            increment_progress(Some(&entry), Some(12), Date::default()),
+            Err(Error::InvalidProgress { progress: 13, .. })
+        ));
+    }
+}
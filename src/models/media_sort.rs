@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `MediaSort` enum.
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the order in which media are sorted.
+///
+/// The `MediaSort` enum defines the sort orders supported by the API
+/// when listing media, such as by trending, popularity, score or start
+/// date, either ascending or descending. New sort orders may be added
+/// in minor releases, so matches on it need a wildcard arm.
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum MediaSort {
+    /// Sort by ID, ascending.
+    Id,
+    /// Sort by ID, descending.
+    IdDesc,
+    /// Sort by romaji title, ascending.
+    TitleRomaji,
+    /// Sort by romaji title, descending.
+    TitleRomajiDesc,
+    /// Sort by english title, ascending.
+    TitleEnglish,
+    /// Sort by english title, descending.
+    TitleEnglishDesc,
+    /// Sort by native title, ascending.
+    TitleNative,
+    /// Sort by native title, descending.
+    TitleNativeDesc,
+    /// Sort by type, ascending.
+    Type,
+    /// Sort by type, descending.
+    TypeDesc,
+    /// Sort by format, ascending.
+    Format,
+    /// Sort by format, descending.
+    FormatDesc,
+    /// Sort by start date, ascending.
+    StartDate,
+    /// Sort by start date, descending.
+    StartDateDesc,
+    /// Sort by end date, ascending.
+    EndDate,
+    /// Sort by end date, descending.
+    EndDateDesc,
+    /// Sort by score, ascending.
+    Score,
+    /// Sort by score, descending.
+    ScoreDesc,
+    /// Sort by popularity, ascending.
+    Popularity,
+    /// Sort by popularity, descending.
+    #[default]
+    PopularityDesc,
+    /// Sort by trending, ascending.
+    Trending,
+    /// Sort by trending, descending.
+    TrendingDesc,
+    /// Sort by episodes, ascending.
+    Episodes,
+    /// Sort by episodes, descending.
+    EpisodesDesc,
+    /// Sort by duration, ascending.
+    Duration,
+    /// Sort by duration, descending.
+    DurationDesc,
+    /// Sort by status, ascending.
+    Status,
+    /// Sort by status, descending.
+    StatusDesc,
+    /// Sort by chapters, ascending.
+    Chapters,
+    /// Sort by chapters, descending.
+    ChaptersDesc,
+    /// Sort by volumes, ascending.
+    Volumes,
+    /// Sort by volumes, descending.
+    VolumesDesc,
+    /// Sort by last update, ascending.
+    UpdatedAt,
+    /// Sort by last update, descending.
+    UpdatedAtDesc,
+    /// Sort by how closely the title matches the search.
+    SearchMatch,
+    /// Sort by favourites, ascending.
+    Favourites,
+    /// Sort by favourites, descending.
+    FavouritesDesc,
+}
+
+impl MediaSort {
+    /// Returns the name of the sort order.
+    pub fn name(&self) -> &str {
+        match self {
+            MediaSort::Id => "ID",
+            MediaSort::IdDesc => "ID (Descending)",
+            MediaSort::TitleRomaji => "Romaji Title",
+            MediaSort::TitleRomajiDesc => "Romaji Title (Descending)",
+            MediaSort::TitleEnglish => "English Title",
+            MediaSort::TitleEnglishDesc => "English Title (Descending)",
+            MediaSort::TitleNative => "Native Title",
+            MediaSort::TitleNativeDesc => "Native Title (Descending)",
+            MediaSort::Type => "Type",
+            MediaSort::TypeDesc => "Type (Descending)",
+            MediaSort::Format => "Format",
+            MediaSort::FormatDesc => "Format (Descending)",
+            MediaSort::StartDate => "Start Date",
+            MediaSort::StartDateDesc => "Start Date (Descending)",
+            MediaSort::EndDate => "End Date",
+            MediaSort::EndDateDesc => "End Date (Descending)",
+            MediaSort::Score => "Score",
+            MediaSort::ScoreDesc => "Score (Descending)",
+            MediaSort::Popularity => "Popularity",
+            MediaSort::PopularityDesc => "Popularity (Descending)",
+            MediaSort::Trending => "Trending",
+            MediaSort::TrendingDesc => "Trending (Descending)",
+            MediaSort::Episodes => "Episodes",
+            MediaSort::EpisodesDesc => "Episodes (Descending)",
+            MediaSort::Duration => "Duration",
+            MediaSort::DurationDesc => "Duration (Descending)",
+            MediaSort::Status => "Status",
+            MediaSort::StatusDesc => "Status (Descending)",
+            MediaSort::Chapters => "Chapters",
+            MediaSort::ChaptersDesc => "Chapters (Descending)",
+            MediaSort::Volumes => "Volumes",
+            MediaSort::VolumesDesc => "Volumes (Descending)",
+            MediaSort::UpdatedAt => "Updated At",
+            MediaSort::UpdatedAtDesc => "Updated At (Descending)",
+            MediaSort::SearchMatch => "Search Match",
+            MediaSort::Favourites => "Favourites",
+            MediaSort::FavouritesDesc => "Favourites (Descending)",
+        }
+    }
+}
+
+impl From<&str> for MediaSort {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "ID" => MediaSort::Id,
+            "ID_DESC" => MediaSort::IdDesc,
+            "TITLE_ROMAJI" => MediaSort::TitleRomaji,
+            "TITLE_ROMAJI_DESC" => MediaSort::TitleRomajiDesc,
+            "TITLE_ENGLISH" => MediaSort::TitleEnglish,
+            "TITLE_ENGLISH_DESC" => MediaSort::TitleEnglishDesc,
+            "TITLE_NATIVE" => MediaSort::TitleNative,
+            "TITLE_NATIVE_DESC" => MediaSort::TitleNativeDesc,
+            "TYPE" => MediaSort::Type,
+            "TYPE_DESC" => MediaSort::TypeDesc,
+            "FORMAT" => MediaSort::Format,
+            "FORMAT_DESC" => MediaSort::FormatDesc,
+            "START_DATE" => MediaSort::StartDate,
+            "START_DATE_DESC" => MediaSort::StartDateDesc,
+            "END_DATE" => MediaSort::EndDate,
+            "END_DATE_DESC" => MediaSort::EndDateDesc,
+            "SCORE" => MediaSort::Score,
+            "SCORE_DESC" => MediaSort::ScoreDesc,
+            "POPULARITY" => MediaSort::Popularity,
+            "POPULARITY_DESC" => MediaSort::PopularityDesc,
+            "TRENDING" => MediaSort::Trending,
+            "TRENDING_DESC" => MediaSort::TrendingDesc,
+            "EPISODES" => MediaSort::Episodes,
+            "EPISODES_DESC" => MediaSort::EpisodesDesc,
+            "DURATION" => MediaSort::Duration,
+            "DURATION_DESC" => MediaSort::DurationDesc,
+            "STATUS" => MediaSort::Status,
+            "STATUS_DESC" => MediaSort::StatusDesc,
+            "CHAPTERS" => MediaSort::Chapters,
+            "CHAPTERS_DESC" => MediaSort::ChaptersDesc,
+            "VOLUMES" => MediaSort::Volumes,
+            "VOLUMES_DESC" => MediaSort::VolumesDesc,
+            "UPDATED_AT" => MediaSort::UpdatedAt,
+            "UPDATED_AT_DESC" => MediaSort::UpdatedAtDesc,
+            "SEARCH_MATCH" => MediaSort::SearchMatch,
+            "FAVOURITES" => MediaSort::Favourites,
+            "FAVOURITES_DESC" => MediaSort::FavouritesDesc,
+            _ => MediaSort::default(),
+        }
+    }
+}
+
+impl From<String> for MediaSort {
+    fn from(value: String) -> Self {
+        MediaSort::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for MediaSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(MediaSort::from("trending_desc"), MediaSort::TrendingDesc);
+        assert_eq!(MediaSort::from("SCORE"), MediaSort::Score);
+        assert_eq!(MediaSort::from("Start_Date_Desc"), MediaSort::StartDateDesc);
+        assert_eq!(MediaSort::from("title_native"), MediaSort::TitleNative);
+        assert_eq!(MediaSort::from("SEARCH_MATCH"), MediaSort::SearchMatch);
+        assert_eq!(MediaSort::from("unknown"), MediaSort::PopularityDesc); // Default case
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_value(MediaSort::TrendingDesc).unwrap(),
+            serde_json::json!("TRENDING_DESC")
+        );
+        assert_eq!(
+            serde_json::to_value(MediaSort::StartDateDesc).unwrap(),
+            serde_json::json!("START_DATE_DESC")
+        );
+        assert_eq!(
+            serde_json::to_value(MediaSort::TitleRomaji).unwrap(),
+            serde_json::json!("TITLE_ROMAJI")
+        );
+        assert_eq!(
+            serde_json::to_value(MediaSort::EpisodesDesc).unwrap(),
+            serde_json::json!("EPISODES_DESC")
+        );
+        assert_eq!(
+            serde_json::to_value(MediaSort::SearchMatch).unwrap(),
+            serde_json::json!("SEARCH_MATCH")
+        );
+    }
+}
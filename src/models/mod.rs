@@ -6,50 +6,97 @@
 mod anime;
 mod character;
 mod color;
+mod connection;
+mod country;
 mod cover;
 mod date;
+mod description;
 mod format;
 mod gender;
+mod genre;
 mod image;
 mod language;
+mod like;
 mod link;
+mod list_comparison;
 mod manga;
 mod media;
+mod media_list;
+mod media_sort;
 mod name;
 mod notification;
+mod page;
 mod person;
+mod rank;
+mod recommendation;
 mod relation;
+mod review;
+mod score_format;
+mod search;
 mod season;
 mod source;
+mod stats;
 mod status;
+mod streaming;
 mod studio;
 mod tag;
+mod thread;
 mod title;
+mod trailer;
+mod trend;
 mod user;
 
-pub use anime::Anime;
+pub use anime::{AiringSchedule, Anime};
 pub use character::{Character, CharacterRole};
-pub use color::Color;
+pub use color::{Color, ColorParseError};
+pub use connection::{
+    CharacterConnection, CharacterEdge, MediaConnection, MediaEdge, RelationConnection,
+};
+pub use country::CountryOfOrigin;
 pub use cover::Cover;
 pub use date::Date;
+pub use description::DescriptionFormat;
 pub use format::Format;
 pub use gender::Gender;
+pub use genre::Genre;
 pub use image::Image;
 pub use language::Language;
+pub use like::LikeableType;
 pub use link::{Link, LinkType};
+pub use list_comparison::{ListComparison, SharedEntry};
 pub use manga::Manga;
-pub use media::Media;
-pub use name::Name;
+pub use media::{Media, MediaKey};
+pub use media_list::{CustomList, MediaListEntry, MediaListEntryUpdate, MediaListGroup};
+pub use media_sort::MediaSort;
+pub use name::{Name, NameOrder};
 pub use notification::{Notification, NotificationOption, NotificationType};
+pub use page::{Page, PageInfo};
 pub use person::Person;
+pub use rank::{MediaRank, MediaRankType};
+pub use recommendation::{Recommendation, RecommendationRating};
 pub use relation::{Relation, RelationType};
+pub use review::Review;
+pub use score_format::ScoreFormat;
+pub use search::{AnimeSearchOptions, MangaSearchOptions};
 pub use season::Season;
 pub use source::Source;
-pub use status::Status;
+pub use stats::{MediaStats, ScoreDistribution, StatusDistribution};
+#[allow(deprecated)]
+pub use status::{MediaListStatus, MediaStatus, Status};
+pub use streaming::{StreamingEpisode, StreamingSite};
 pub use studio::Studio;
 pub use tag::Tag;
+pub use thread::{Thread, ThreadCategory, ThreadComment};
 pub use title::Title;
-pub use user::User;
+pub use trailer::Trailer;
+pub use trend::{MediaTrend, MediaTrendSort};
+pub use user::{
+    Favourites, User, UserFormatStatistic, UserGenreStatistic, UserIdentifier,
+    UserReleaseYearStatistic, UserStaffStatistic, UserStatisticTypes, UserStatistics,
+    UserStatusStatistic, UserStudioStatistic, UserTagStatistic, UserTitleLanguage,
+};
+
+pub(crate) use user::FavouriteCategory;
 
 use serde::{Deserialize, Serialize};
 
@@ -57,7 +104,11 @@ use serde::{Deserialize, Serialize};
 ///
 /// The `MediaType` enum defines various types of media, such as anime,
 /// manga, character, user, person, studio, and an unknown type.
+///
+/// It serializes to the names used by the API, like `ANIME` and `MANGA`,
+/// so it can be passed directly as a GraphQL variable.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum MediaType {
     /// An anime.
     Anime,
@@ -73,5 +124,88 @@ pub enum MediaType {
     Studio,
     /// Unknown type.
     #[default]
+    #[serde(other)]
     Unknown,
 }
+
+impl MediaType {
+    /// Returns the name of the media type.
+    pub fn name(&self) -> &str {
+        match self {
+            MediaType::Anime => "Anime",
+            MediaType::Manga => "Manga",
+            MediaType::Character => "Character",
+            MediaType::User => "User",
+            MediaType::Person => "Person",
+            MediaType::Studio => "Studio",
+            MediaType::Unknown => "Unknown",
+        }
+    }
+}
+
+impl From<&str> for MediaType {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "ANIME" => MediaType::Anime,
+            "MANGA" => MediaType::Manga,
+            "CHARACTER" => MediaType::Character,
+            "USER" => MediaType::User,
+            "PERSON" => MediaType::Person,
+            "STUDIO" => MediaType::Studio,
+            _ => MediaType::default(),
+        }
+    }
+}
+
+impl From<String> for MediaType {
+    fn from(value: String) -> Self {
+        MediaType::from(value.as_str())
+    }
+}
+
+impl std::str::FromStr for MediaType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(MediaType::from(value))
+    }
+}
+
+impl std::fmt::Display for MediaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_type_serde() {
+        assert_eq!(
+            serde_json::to_value(MediaType::Anime).unwrap(),
+            serde_json::json!("ANIME")
+        );
+        assert_eq!(
+            serde_json::json!({ "type": MediaType::Manga }),
+            serde_json::json!({ "type": "MANGA" })
+        );
+        assert_eq!(
+            serde_json::from_value::<MediaType>(serde_json::json!("MANGA")).unwrap(),
+            MediaType::Manga
+        );
+        assert_eq!(
+            serde_json::from_value::<MediaType>(serde_json::json!("NOVEL")).unwrap(),
+            MediaType::Unknown
+        );
+    }
+
+    #[test]
+    fn test_media_type_from_str() {
+        assert_eq!("anime".parse::<MediaType>().unwrap(), MediaType::Anime);
+        assert_eq!(MediaType::from("MANGA"), MediaType::Manga);
+        assert_eq!(MediaType::from("unknown"), MediaType::Unknown);
+        assert_eq!(MediaType::Studio.to_string(), "Studio");
+    }
+}
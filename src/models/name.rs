@@ -5,9 +5,11 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::connection::nullable;
+
 /// Represents a name.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Name {
     /// The first name.
     pub first: String,
@@ -20,37 +22,105 @@ pub struct Name {
     /// The native name, if any.
     native: Option<String>,
     /// Alternative names.
+    #[serde(default, deserialize_with = "nullable")]
     alternative: Vec<String>,
     /// Alternative names that may contain spoilers.
-    alternative_spoiler: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    alternative_spoiler: Vec<String>,
     /// The name preferred by the user, if any.
     user_preferred: Option<String>,
 }
 
+/// Represents the order the parts of a name are written in.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum NameOrder {
+    /// The given names first and the family name last, e.g. "Spike
+    /// Spiegel".
+    #[default]
+    Western,
+    /// The family name first and the given names last, e.g. "Spiegel
+    /// Spike".
+    Native,
+}
+
 impl Name {
     /// Returns the full name.
-    pub fn full(&self) -> String {
-        self.full.clone()
+    pub fn full(&self) -> &str {
+        &self.full
     }
 
     /// Returns the native name, if any.
-    pub fn native(&self) -> Option<String> {
-        self.native.clone()
+    pub fn native(&self) -> Option<&str> {
+        self.native.as_deref()
     }
 
     /// Returns the alternative names.
-    pub fn alternative(&self) -> Vec<String> {
-        self.alternative.clone()
+    pub fn alternative(&self) -> &[String] {
+        &self.alternative
     }
 
     /// Returns the alternative names that may contain spoilers.
-    pub fn spoiler(&self) -> Option<Vec<String>> {
-        self.alternative_spoiler.clone()
+    pub fn spoiler(&self) -> &[String] {
+        &self.alternative_spoiler
     }
 
     /// Returns the name preferred by the user, if any.
-    pub fn user_preferred(&self) -> Option<String> {
-        self.user_preferred.clone()
+    pub fn user_preferred(&self) -> Option<&str> {
+        self.user_preferred.as_deref()
+    }
+
+    /// Returns the first, middle and last names in the given order.
+    ///
+    /// Missing parts are skipped, and the full name is returned when
+    /// all of them are missing.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order to write the parts of the name in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::{Name, NameOrder};
+    /// let name: Name = serde_json::from_value(serde_json::json!({
+    ///     "first": "Spike",
+    ///     "last": "Spiegel",
+    ///     "full": "Spike Spiegel"
+    /// }))?;
+    ///
+    /// assert_eq!(name.formatted(NameOrder::Western), "Spike Spiegel");
+    /// assert_eq!(name.formatted(NameOrder::Native), "Spiegel Spike");
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn formatted(&self, order: NameOrder) -> String {
+        let (first, middle, last) = (
+            Some(self.first.as_str()),
+            self.middle.as_deref(),
+            self.last.as_deref(),
+        );
+        let ordered = match order {
+            NameOrder::Western => [first, middle, last],
+            NameOrder::Native => [last, first, middle],
+        };
+
+        let parts: Vec<&str> = ordered
+            .into_iter()
+            .flatten()
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect();
+
+        if parts.is_empty() {
+            self.full.clone()
+        } else {
+            parts.join(" ")
+        }
+    }
+}
+
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.user_preferred().unwrap_or(&self.full))
     }
 }
 
@@ -67,7 +137,7 @@ mod tests {
             full: "John Doe Smith".to_string(),
             native: Some("ジョン ドウ スミス".to_string()),
             alternative: vec!["Johnny".to_string()],
-            alternative_spoiler: Some(vec!["J.D.".to_string()]),
+            alternative_spoiler: vec!["J.D.".to_string()],
             user_preferred: Some("John Smith".to_string()),
         };
 
@@ -83,11 +153,11 @@ mod tests {
             full: "John Doe Smith".to_string(),
             native: Some("ジョン ドウ スミス".to_string()),
             alternative: vec!["Johnny".to_string()],
-            alternative_spoiler: Some(vec!["J.D.".to_string()]),
+            alternative_spoiler: vec!["J.D.".to_string()],
             user_preferred: Some("John Smith".to_string()),
         };
 
-        assert_eq!(name.native(), Some("ジョン ドウ スミス".to_string()));
+        assert_eq!(name.native(), Some("ジョン ドウ スミス"));
     }
 
     #[test]
@@ -99,11 +169,11 @@ mod tests {
             full: "John Doe Smith".to_string(),
             native: Some("ジョン ドウ スミス".to_string()),
             alternative: vec!["Johnny".to_string()],
-            alternative_spoiler: Some(vec!["J.D.".to_string()]),
+            alternative_spoiler: vec!["J.D.".to_string()],
             user_preferred: Some("John Smith".to_string()),
         };
 
-        assert_eq!(name.alternative(), vec!["Johnny".to_string()]);
+        assert_eq!(name.alternative(), ["Johnny".to_string()]);
     }
 
     #[test]
@@ -115,11 +185,11 @@ mod tests {
             full: "John Doe Smith".to_string(),
             native: Some("ジョン ドウ スミス".to_string()),
             alternative: vec!["Johnny".to_string()],
-            alternative_spoiler: Some(vec!["J.D.".to_string()]),
+            alternative_spoiler: vec!["J.D.".to_string()],
             user_preferred: Some("John Smith".to_string()),
         };
 
-        assert_eq!(name.spoiler(), Some(vec!["J.D.".to_string()]));
+        assert_eq!(name.spoiler(), ["J.D.".to_string()]);
     }
 
     #[test]
@@ -131,10 +201,68 @@ mod tests {
             full: "John Doe Smith".to_string(),
             native: Some("ジョン ドウ スミス".to_string()),
             alternative: vec!["Johnny".to_string()],
-            alternative_spoiler: Some(vec!["J.D.".to_string()]),
+            alternative_spoiler: vec!["J.D.".to_string()],
             user_preferred: Some("John Smith".to_string()),
         };
 
-        assert_eq!(name.user_preferred(), Some("John Smith".to_string()));
+        assert_eq!(name.user_preferred(), Some("John Smith"));
+    }
+
+    #[test]
+    fn test_formatted() {
+        let name = Name {
+            first: "John".to_string(),
+            middle: Some("Doe".to_string()),
+            last: Some("Smith".to_string()),
+            full: "John Doe Smith".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(name.formatted(NameOrder::Western), "John Doe Smith");
+        assert_eq!(name.formatted(NameOrder::Native), "Smith John Doe");
+    }
+
+    #[test]
+    fn test_formatted_with_missing_parts() {
+        let name = Name {
+            first: "Faye".to_string(),
+            middle: Some(" ".to_string()),
+            full: "Faye".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(name.formatted(NameOrder::Native), "Faye");
+
+        let name = Name {
+            full: "Ein".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(name.formatted(NameOrder::Western), "Ein");
+    }
+
+    #[test]
+    fn test_display() {
+        let mut name = Name {
+            first: "John".to_string(),
+            full: "John Doe Smith".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(name.to_string(), "John Doe Smith");
+
+        name.user_preferred = Some("John Smith".to_string());
+        assert_eq!(name.to_string(), "John Smith");
+    }
+
+    #[test]
+    fn test_deserialize_null_alternatives() {
+        let name: Name = serde_json::from_value(serde_json::json!({
+            "first": "Spike",
+            "full": "Spike Spiegel",
+            "alternative": null,
+            "alternativeSpoiler": null
+        }))
+        .unwrap();
+
+        assert!(name.alternative().is_empty());
+        assert!(name.spoiler().is_empty());
     }
 }
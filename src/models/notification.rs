@@ -11,7 +11,7 @@ pub struct Notification {}
 
 /// Represents the options for a notification.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct NotificationOption {
     /// The type of the notification.
     notification_type: NotificationType,
@@ -21,7 +21,7 @@ pub struct NotificationOption {
 
 /// Represents the type of a notification.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum NotificationType {
     /// Notification for an activity message.
     #[default]
@@ -52,6 +52,9 @@ pub enum NotificationType {
     MediaMerge,
     /// Notification for a media deletion.
     MediaDeletion,
+    /// A notification type not known to this version of the crate.
+    #[serde(other)]
+    Unknown,
 }
 
 impl std::fmt::Display for NotificationType {
@@ -71,6 +74,7 @@ impl std::fmt::Display for NotificationType {
             NotificationType::MediaDataChange => write!(f, "Media Data Change"),
             NotificationType::MediaMerge => write!(f, "Media Merge"),
             NotificationType::MediaDeletion => write!(f, "Media Deletion"),
+            NotificationType::Unknown => write!(f, "Unknown"),
         }
     }
 }
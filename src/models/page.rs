@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Page` struct and its related types.
+
+use serde::{Deserialize, Serialize};
+
+/// Represents a page of results.
+///
+/// The `Page` struct contains the items returned by a paginated query
+/// along with the pagination information needed to request more pages.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Page<T> {
+    /// The pagination information of the page.
+    pub page_info: PageInfo,
+    /// The items of the page.
+    pub items: Vec<T>,
+}
+
+impl<T> Page<T> {
+    /// Returns the number of items in the page.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns whether the page has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns whether there is a page after this one.
+    pub fn has_next_page(&self) -> bool {
+        self.page_info.has_next_page
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// Represents the pagination information of a page.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    /// The total number of items.
+    #[serde(default)]
+    pub total: u32,
+    /// The number of items per page.
+    #[serde(default)]
+    pub per_page: u32,
+    /// The current page number.
+    #[serde(default)]
+    pub current_page: u32,
+    /// The last page number.
+    #[serde(default)]
+    pub last_page: u32,
+    /// Whether there is a page after the current one.
+    #[serde(default)]
+    pub has_next_page: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_info_deserialize() {
+        let json = r#"{
+            "total": 5000,
+            "perPage": 10,
+            "currentPage": 2,
+            "lastPage": 500,
+            "hasNextPage": true
+        }"#;
+        let page_info: PageInfo = serde_json::from_str(json).unwrap();
+
+        assert_eq!(page_info.total, 5000);
+        assert_eq!(page_info.per_page, 10);
+        assert_eq!(page_info.current_page, 2);
+        assert_eq!(page_info.last_page, 500);
+        assert!(page_info.has_next_page);
+    }
+
+    #[test]
+    fn test_has_next_page() {
+        let page = Page {
+            page_info: PageInfo {
+                has_next_page: true,
+                ..Default::default()
+            },
+            items: vec![1, 2, 3],
+        };
+
+        assert!(page.has_next_page());
+        assert_eq!(page.len(), 3);
+        assert!(!page.is_empty());
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let page = Page {
+            page_info: PageInfo::default(),
+            items: vec![1, 2, 3],
+        };
+
+        assert_eq!(page.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+}
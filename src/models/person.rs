@@ -2,37 +2,47 @@
 // Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use super::{Character, Date, Gender, Image, Language, Name};
-use crate::{Client, Result};
+use super::{
+    connection::nullable, media::collect_pages, Character, CharacterConnection, Date,
+    DescriptionFormat, Gender, Image, Language, Media, MediaConnection, MediaType, Name,
+};
+use crate::{parse, Client, Error, Result};
 
 /// Represents a person.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Person {
     /// The ID of the person.
     pub id: i64,
     /// The name of the person.
     pub name: Name,
-    /// The language of the person.
+    /// The language of the person, if any.
     #[serde(rename = "languageV2")]
-    pub language: Language,
+    pub language: Option<Language>,
     /// The image of the person, if any.
     pub image: Option<Image>,
     /// The description of the person, if any.
     pub description: Option<String>,
+    /// The format of the description.
+    #[serde(default)]
+    pub description_format: DescriptionFormat,
     /// The primary occupations of the person, if any.
     pub primary_occupations: Option<Vec<String>>,
-    /// The gender of the person.
-    pub gender: Gender,
+    /// The gender of the person, if any.
+    pub gender: Option<Gender>,
     /// The date of birth of the person, if any.
     pub date_of_birth: Option<Date>,
     /// The date of death of the person, if any.
     pub date_of_death: Option<Date>,
     /// The age of the person, if any.
     pub age: Option<i64>,
-    // The years the person was active, if any.
-    // pub years_active: Option<(u64, u64)>,
+    /// The years the person was active, if any.
+    ///
+    /// Holds the start year, followed by the end year if the person is
+    /// no longer active.
+    pub years_active: Option<Vec<u32>>,
     /// The hometown of the person, if any.
     pub home_town: Option<String>,
     /// The blood type of the person, if any.
@@ -44,11 +54,14 @@ pub struct Person {
     /// The URL of the person's site.
     #[serde(rename = "siteUrl")]
     pub url: String,
-    /// The characters associated with the person, if any.
-    #[serde(skip)]
-    pub characters: Option<Vec<Character>>,
-    /// The number of favorites the person has.
-    pub favourites: i64,
+    /// The characters voiced by the person.
+    #[serde(default, deserialize_with = "nullable")]
+    pub(crate) characters: CharacterConnection,
+    /// The media the person worked on.
+    #[serde(default, deserialize_with = "nullable")]
+    pub(crate) staff_media: MediaConnection,
+    /// The number of favorites the person has, if any.
+    pub favourites: Option<i64>,
     /// The moderator notes for the person, if any.
     pub mod_notes: Option<String>,
 
@@ -56,21 +69,19 @@ pub struct Person {
     #[serde(skip)]
     pub(crate) client: Client,
     /// Whether the person's data is fully loaded.
-    #[serde(default)]
+    #[serde(skip)]
     pub(crate) is_full_loaded: bool,
 }
 
 impl Person {
     /// Loads the full details of the person.
     ///
+    /// If the person is already fully loaded, it is returned as is.
+    ///
     /// # Errors
     ///
     /// Returns an error if the person details cannot be loaded.
     ///
-    /// # Panics
-    ///
-    /// Panics if the person is already fully loaded.
-    ///
     /// # Example
     ///
     /// ```no_run
@@ -82,15 +93,175 @@ impl Person {
     /// # }
     /// ```
     pub async fn load_full(self) -> Result<Self> {
-        if !self.is_full_loaded {
-            self.client.get_person(self.id).await
-        } else {
-            panic!("This person is already full loaded")
+        if self.is_full_loaded {
+            return Ok(self);
+        }
+
+        self.client.invalidate(MediaType::Person, self.id);
+        self.client.get_person(self.id).await
+    }
+
+    /// Toggles whether the person is a favourite of the authenticated
+    /// user, returning whether it is a favourite afterwards.
+    ///
+    /// The new state is read back from the API and stored in
+    /// `is_favourite`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::FavouriteBlocked` if `is_favourite_blocked` is
+    /// set, or `Error::Unauthorized` if the client has no API token,
+    /// both without sending any request. Returns an error if the request
+    /// fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Person, Result};
+    /// #
+    /// # async fn f(mut person: Person) -> Result<()> {
+    /// let is_favourite = person.toggle_favourite().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn toggle_favourite(&mut self) -> Result<bool> {
+        let is_favourite = self
+            .client
+            .toggle_favourite_state(MediaType::Person, self.id, self.is_favourite_blocked)
+            .await?;
+        self.is_favourite = Some(is_favourite);
+
+        Ok(is_favourite)
+    }
+
+    /// Builds a partially loaded person from a staff node of an API
+    /// response, such as the ones returned by searches.
+    ///
+    /// # Arguments
+    ///
+    /// * `person` - The staff node to build the person from.
+    /// * `client` - The client to attach to the person.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node has no ID or an invalid name.
+    pub(crate) fn from_node(person: &Value, client: &Client) -> Result<Self> {
+        Ok(Self {
+            id: person["id"].as_i64().ok_or(Error::InvalidId)?,
+            name: parse::deserialize(person, "name")?,
+            language: Language::deserialize(&person["languageV2"]).ok(),
+            image: Image::deserialize(&person["image"]).ok(),
+            primary_occupations: Option::<Vec<String>>::deserialize(&person["primaryOccupations"])
+                .unwrap_or_default(),
+            gender: Gender::deserialize(&person["gender"]).ok(),
+            date_of_birth: Date::deserialize(&person["dateOfBirth"]).ok(),
+            age: person["age"].as_i64(),
+            url: person["siteUrl"].as_str().unwrap_or_default().to_string(),
+            favourites: person["favourites"].as_i64(),
+            is_favourite: person["isFavourite"].as_bool(),
+            years_active: Option::<Vec<u32>>::deserialize(&person["yearsActive"])
+                .unwrap_or_default(),
+            description_format: client.description_format(),
+
+            client: client.clone(),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the years the person was active, like "2009–present".
+    ///
+    /// Returns `None` if the years are unknown.
+    pub fn active_years(&self) -> Option<String> {
+        match self.years_active.as_deref()? {
+            [start] => Some(format!("{start}–present")),
+            [start, end, ..] if start == end => Some(start.to_string()),
+            [start, end, ..] => Some(format!("{start}–{end}")),
+            [] => None,
+        }
+    }
+
+    /// Returns a page of the characters voiced by the person, with
+    /// their role set.
+    ///
+    /// The first characters come with the fully loaded person, the
+    /// others are fetched through the client when needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the characters cannot be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Person, Result};
+    /// #
+    /// # async fn f(person: Person) -> Result<()> {
+    /// for character in person.characters(1, 10).await? {
+    ///     println!("{}", character.name.full());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn characters(&self, page: u16, per_page: u16) -> Result<Vec<Character>> {
+        let mut characters = match self.characters.page(page, per_page) {
+            Some(connection) => connection.characters(),
+            None => {
+                self.client
+                    .get_person_characters(self.id, page, per_page)
+                    .await?
+            }
+        };
+        for character in &mut characters {
+            character.client = self.client.clone();
+        }
+
+        Ok(characters)
+    }
+
+    /// Returns a page of the media the person worked on, with their
+    /// staff role set, like "Director" or "Original Creator".
+    ///
+    /// The first media come with the fully loaded person, the others
+    /// are fetched through the client when needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of media to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the media cannot be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Person, Result};
+    /// #
+    /// # async fn f(person: Person) -> Result<()> {
+    /// for media in person.staff_media(1, 10).await? {
+    ///     println!("{} ({:?})", media.title(), media.staff_role());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn staff_media(&self, page: u16, per_page: u16) -> Result<Vec<Media>> {
+        match self.staff_media.page(page, per_page) {
+            Some(connection) => Ok(connection.media(&self.client)),
+            None => self.client.get_person_media(self.id, page, per_page).await,
         }
     }
 
     /// Retrieves the media associated with the person.
     ///
+    /// Every page of the media the person worked on is fetched, keeping
+    /// only the ones of type `T`.
+    ///
     /// # Errors
     ///
     /// Returns an error if the media cannot be retrieved.
@@ -102,6 +273,7 @@ impl Person {
     /// # Example
     ///
     /// ```no_run
+    /// # #![allow(deprecated)]
     /// # use rust_anilist::{models::{Anime, Person}, Result};
     /// #
     /// # async fn f(person: Person) -> Result<()> {
@@ -109,12 +281,16 @@ impl Person {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_medias<T>(&self) -> Result<Vec<T>> {
-        unimplemented!()
+    #[deprecated(note = "use `Person::staff_media` instead")]
+    pub async fn get_medias<T: TryFrom<Media>>(&self) -> Result<Vec<T>> {
+        collect_pages(|page, per_page| self.staff_media(page, per_page)).await
     }
 
     /// Retrieves the media associated with a character.
     ///
+    /// Every page of the media the character appears in is fetched,
+    /// keeping only the ones of type `T`.
+    ///
     /// # Arguments
     ///
     /// * `character_id` - The ID of the character whose media is to be retrieved.
@@ -130,6 +306,7 @@ impl Person {
     /// # Example
     ///
     /// ```no_run
+    /// # #![allow(deprecated)]
     /// # use rust_anilist::{models::{Manga, Person}, Result};
     /// #
     /// # async fn f(person: Person) -> Result<()> {
@@ -137,7 +314,169 @@ impl Person {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_character_medias<T>(&self, _character_id: i64) -> Result<Vec<T>> {
-        unimplemented!()
+    #[deprecated(note = "use `Person::characters` and `Character::media` instead")]
+    pub async fn get_character_medias<T: TryFrom<Media>>(
+        &self,
+        character_id: i64,
+    ) -> Result<Vec<T>> {
+        collect_pages(|page, per_page| {
+            self.client
+                .get_character_media(character_id, page, per_page)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{Anime, CharacterRole, Manga},
+        transport::MockTransport,
+    };
+
+    #[tokio::test]
+    async fn test_characters_from_embedded_page() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        let person = Person {
+            id: 1,
+            characters: serde_json::from_value(serde_json::json!({
+                "pageInfo": { "hasNextPage": false },
+                "edges": [{
+                    "node": {
+                        "id": 1,
+                        "name": { "first": "Spike", "full": "Spike Spiegel", "alternative": [] },
+                        "image": { "large": "", "medium": "" },
+                        "description": null,
+                        "siteUrl": "https://anilist.co/character/1"
+                    },
+                    "role": "MAIN"
+                }]
+            }))
+            .unwrap(),
+            client: Client::with_transport(transport.clone()),
+            ..Default::default()
+        };
+
+        let characters = person.characters(1, 10).await.unwrap();
+
+        assert_eq!(characters.len(), 1);
+        assert_eq!(characters[0].role, Some(CharacterRole::Main));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_staff_media_past_embedded_page() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Staff": {
+                    "staffMedia": {
+                        "pageInfo": { "hasNextPage": false },
+                        "edges": [{
+                            "node": { "id": 1, "title": { "romaji": "", "native": "" }, "type": "ANIME" },
+                            "staffRole": "Director"
+                        }]
+                    }
+                }
+            }
+        }));
+        let person = Person {
+            id: 1,
+            client: Client::with_transport(transport.clone()),
+            ..Default::default()
+        };
+
+        let media = person.staff_media(1, 10).await.unwrap();
+
+        assert_eq!(media.len(), 1);
+        assert_eq!(media[0].staff_role(), Some("Director"));
+        assert_eq!(transport.requests()[0].variables["id"], 1);
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn test_get_medias() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        transport.push(serde_json::json!({
+            "data": {
+                "Character": {
+                    "media": {
+                        "edges": [{
+                            "node": { "id": 2, "title": { "romaji": "", "native": "" }, "type": "MANGA" },
+                            "characterRole": "MAIN"
+                        }]
+                    }
+                }
+            }
+        }));
+        let person = Person {
+            id: 1,
+            staff_media: serde_json::from_value(serde_json::json!({
+                "pageInfo": { "hasNextPage": false },
+                "edges": [{
+                    "node": { "id": 1, "title": { "romaji": "", "native": "" }, "type": "ANIME" },
+                    "staffRole": "Director"
+                }]
+            }))
+            .unwrap(),
+            client: Client::with_transport(transport.clone()),
+            ..Default::default()
+        };
+
+        let animes = person.get_medias::<Anime>().await.unwrap();
+        let mangas = person.get_character_medias::<Manga>(3).await.unwrap();
+
+        assert_eq!(animes.len(), 1);
+        assert_eq!(animes[0].staff_role.as_deref(), Some("Director"));
+        assert_eq!(mangas.len(), 1);
+        assert_eq!(mangas[0].id, 2);
+        assert_eq!(transport.requests()[0].variables["id"], 3);
+    }
+
+    #[test]
+    fn test_active_years() {
+        let mut person = Person::default();
+        assert_eq!(person.active_years(), None);
+
+        person.years_active = Some(vec![]);
+        assert_eq!(person.active_years(), None);
+
+        person.years_active = Some(vec![2009]);
+        assert_eq!(person.active_years().as_deref(), Some("2009–present"));
+
+        person.years_active = Some(vec![1998, 2015]);
+        assert_eq!(person.active_years().as_deref(), Some("1998–2015"));
+    }
+
+    #[test]
+    fn test_deserialize_sparse_person() {
+        let person: Person = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "name": { "first": "Yoko", "full": "Yoko Kanno" },
+            "languageV2": null,
+            "image": null,
+            "description": null,
+            "primaryOccupations": [],
+            "gender": null,
+            "dateOfBirth": { "year": 1963, "month": 3, "day": 18 },
+            "dateOfDeath": { "year": null, "month": null, "day": null },
+            "age": null,
+            "yearsActive": [1986],
+            "homeTown": null,
+            "bloodType": null,
+            "siteUrl": "https://anilist.co/staff/1",
+            "favourites": null
+        }))
+        .unwrap();
+
+        assert_eq!(person.language, None);
+        assert_eq!(person.gender, None);
+        assert_eq!(person.favourites, None);
+        assert_eq!(person.years_active, Some(vec![1986]));
+        assert_eq!(
+            person.date_of_birth,
+            Some(Date::new(Some(1963), Some(3), Some(18)))
+        );
     }
 }
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `MediaRank` struct and its related types.
+
+use serde::{Deserialize, Serialize};
+
+use super::{Format, Season};
+
+/// Represents a ranking of a media.
+///
+/// The `MediaRank` struct contains the position of a media in a ranking,
+/// like "#5 highest rated of Spring 2021", along with what the ranking
+/// is based on and its scope.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaRank {
+    /// The ID of the rank.
+    pub id: i64,
+    /// The position of the media in the ranking.
+    pub rank: u32,
+    /// What the ranking is based on.
+    #[serde(rename = "type")]
+    pub rank_type: MediaRankType,
+    /// The format the ranking is restricted to.
+    pub format: Format,
+    /// The year the ranking is restricted to, if any.
+    pub year: Option<u32>,
+    /// The season the ranking is restricted to, if any.
+    pub season: Option<Season>,
+    /// Whether the ranking is over all time.
+    #[serde(default)]
+    pub all_time: Option<bool>,
+    /// The description of the ranking, like "highest rated".
+    pub context: String,
+}
+
+impl MediaRank {
+    /// Returns whether the ranking is over all time.
+    pub fn is_all_time(&self) -> bool {
+        self.all_time.unwrap_or_default()
+    }
+}
+
+/// Represents what a ranking of a media is based on.
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MediaRankType {
+    /// Ranked by score.
+    #[default]
+    Rated,
+    /// Ranked by popularity.
+    Popular,
+    /// A ranking not known to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl MediaRankType {
+    /// Returns the name of the rank type.
+    pub fn name(&self) -> &str {
+        match self {
+            MediaRankType::Rated => "Rated",
+            MediaRankType::Popular => "Popular",
+            MediaRankType::Unknown => "Unknown",
+        }
+    }
+}
+
+impl From<&str> for MediaRankType {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "RATED" => MediaRankType::Rated,
+            "POPULAR" => MediaRankType::Popular,
+            _ => MediaRankType::Unknown,
+        }
+    }
+}
+
+impl From<String> for MediaRankType {
+    fn from(value: String) -> Self {
+        MediaRankType::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for MediaRankType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Returns the all-time score ranking out of the rankings of a media.
+///
+/// # Arguments
+///
+/// * `rankings` - The rankings of the media, if loaded.
+pub(crate) fn best_rank(rankings: Option<&[MediaRank]>) -> Option<&MediaRank> {
+    rankings?
+        .iter()
+        .find(|rank| rank.rank_type == MediaRankType::Rated && rank.is_all_time())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_seasonal_rank() {
+        let rank: MediaRank = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "rank": 5,
+            "type": "RATED",
+            "format": "TV",
+            "year": 2021,
+            "season": "SPRING",
+            "allTime": false,
+            "context": "highest rated"
+        }))
+        .unwrap();
+
+        assert_eq!(rank.rank, 5);
+        assert_eq!(rank.rank_type, MediaRankType::Rated);
+        assert_eq!(rank.season, Some(Season::Spring));
+        assert_eq!(rank.year, Some(2021));
+        assert!(!rank.is_all_time());
+    }
+
+    #[test]
+    fn test_deserialize_all_time_rank() {
+        let rankings: Vec<MediaRank> = serde_json::from_value(serde_json::json!([
+            {
+                "id": 1,
+                "rank": 12,
+                "type": "POPULAR",
+                "format": "TV",
+                "year": null,
+                "season": null,
+                "allTime": true,
+                "context": "most popular all time"
+            },
+            {
+                "id": 2,
+                "rank": 3,
+                "type": "RATED",
+                "format": "TV",
+                "year": null,
+                "season": null,
+                "allTime": true,
+                "context": "highest rated all time"
+            }
+        ]))
+        .unwrap();
+
+        let best = best_rank(Some(&rankings)).unwrap();
+
+        assert_eq!(best.id, 2);
+        assert_eq!(best.rank, 3);
+        assert_eq!(best_rank(None), None);
+    }
+}
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Recommendation` struct and its related
+//! types.
+
+use serde::{Deserialize, Serialize};
+
+use super::Media;
+
+/// Represents a recommendation of a media for another one.
+///
+/// The `Recommendation` struct contains the recommended media, the net
+/// votes of the recommendation and, if authenticated, the vote of the
+/// user.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Recommendation {
+    /// The ID of the recommendation.
+    pub id: i64,
+    /// The recommended media.
+    pub media: Media,
+    /// The net votes of the recommendation.
+    pub rating: i32,
+    /// The vote of the user, if authenticated.
+    pub user_rating: Option<RecommendationRating>,
+}
+
+/// Represents the vote of a user on a recommendation.
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecommendationRating {
+    /// The user did not vote.
+    #[default]
+    NoRating,
+    /// The user voted up.
+    RateUp,
+    /// The user voted down.
+    RateDown,
+}
+
+impl RecommendationRating {
+    /// Returns the name of the rating.
+    pub fn name(&self) -> &str {
+        match self {
+            RecommendationRating::NoRating => "No Rating",
+            RecommendationRating::RateUp => "Rate Up",
+            RecommendationRating::RateDown => "Rate Down",
+        }
+    }
+}
+
+impl From<&str> for RecommendationRating {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "RATE_UP" => RecommendationRating::RateUp,
+            "RATE_DOWN" => RecommendationRating::RateDown,
+            _ => RecommendationRating::default(),
+        }
+    }
+}
+
+impl From<String> for RecommendationRating {
+    fn from(value: String) -> Self {
+        RecommendationRating::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for RecommendationRating {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rating_from_str() {
+        assert_eq!(
+            RecommendationRating::from("RATE_UP"),
+            RecommendationRating::RateUp
+        );
+        assert_eq!(
+            RecommendationRating::from("rate_down"),
+            RecommendationRating::RateDown
+        );
+        assert_eq!(
+            RecommendationRating::from("unknown"),
+            RecommendationRating::NoRating
+        );
+    }
+
+    #[test]
+    fn test_rating_deserialize() {
+        assert_eq!(
+            serde_json::from_value::<RecommendationRating>(serde_json::json!("RATE_UP")).unwrap(),
+            RecommendationRating::RateUp
+        );
+    }
+}
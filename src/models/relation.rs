@@ -4,9 +4,12 @@
 //! This module contains the `Relation` struct and its related types.
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
-use super::{Anime, Cover, Format, Manga, Media, Status, Title};
+use super::{
+    connection::{attach_client, media_node},
+    Media,
+};
+use crate::Client;
 
 /// Represents a relation between different media types.
 ///
@@ -15,56 +18,34 @@ use super::{Anime, Cover, Format, Manga, Media, Status, Title};
 /// the related media, relation ID, relation type, and whether it is
 /// the main studio.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Relation {
-    /// The related media.
-    pub(crate) node: Value,
+    /// The related media, if any.
+    #[serde(default, deserialize_with = "media_node")]
+    pub(crate) node: Option<Media>,
     /// The ID of the relation.
     pub id: i64,
     /// The type of relation (e.g., adaptation, sequel).
     pub relation_type: RelationType,
     /// Whether the relation is the main studio.
     pub is_main_studio: bool,
+
+    /// The client used to fetch additional data.
+    #[serde(skip)]
+    pub(crate) client: Client,
 }
 
 impl Relation {
     /// Returns the related media.
+    ///
+    /// The related media is partially loaded and holds the client of the
+    /// media it was taken from, so it can be fully loaded afterwards.
+    /// Returns `Media::Unknown` if the relation has no media.
     pub fn media(&self) -> Media {
-        let media = self.node.clone();
-
-        match self.node["type"].as_str() {
-            Some("ANIME") => Media::Anime(Anime {
-                id: media["id"].as_i64().unwrap(),
-                id_mal: media["idMal"].as_i64(),
-                title: Title::deserialize(&media["title"]).unwrap(),
-                format: Format::deserialize(&media["format"]).unwrap(),
-                status: Status::deserialize(&media["status"]).unwrap(),
-                description: media["description"].as_str().unwrap().to_string(),
-                cover: Cover::deserialize(&media["coverImage"]).unwrap(),
-                banner: media["bannerImage"].as_str().map(String::from),
-                average_score: media["averageScore"].as_u64().map(|x| x as u8),
-                mean_score: media["meanScore"].as_u64().map(|x| x as u8),
-                url: media["siteUrl"].as_str().unwrap().to_string(),
+        let mut media = self.node.clone().unwrap_or_default();
+        attach_client(&mut media, &self.client);
 
-                ..Default::default()
-            }),
-            Some("MANGA") => Media::Manga(Manga {
-                id: media["id"].as_i64().unwrap(),
-                id_mal: media["idMal"].as_i64(),
-                title: Title::deserialize(&media["title"]).unwrap(),
-                format: Format::deserialize(&media["format"]).unwrap(),
-                status: Status::deserialize(&media["status"]).unwrap(),
-                description: media["description"].as_str().unwrap().to_string(),
-                cover: Cover::deserialize(&media["coverImage"]).unwrap(),
-                banner: media["bannerImage"].as_str().map(String::from),
-                average_score: media["averageScore"].as_u64().map(|x| x as u8),
-                mean_score: media["meanScore"].as_u64().map(|x| x as u8),
-                url: media["siteUrl"].as_str().unwrap().to_string(),
-
-                ..Default::default()
-            }),
-            _ => Media::Unknown,
-        }
+        media
     }
 }
 
@@ -74,7 +55,7 @@ impl Relation {
 /// can exist between different media, such as adaptations, sequels,
 /// prequels, and more.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RelationType {
     /// The media is an adaptation of another work.
     Adaptation,
@@ -103,6 +84,9 @@ pub enum RelationType {
     Compilation,
     /// The media contains another work.
     Contains,
+    /// A relation type not known to this version of the crate.
+    #[serde(other)]
+    Unknown,
 }
 
 impl RelationType {
@@ -124,6 +108,7 @@ impl RelationType {
             RelationType::Source => "The source material the media was adapted from",
             RelationType::Compilation => "A compilation of the media",
             RelationType::Contains => "A media that contains the relation",
+            RelationType::Unknown => "A relation not known to this version of the crate",
         }
     }
 }
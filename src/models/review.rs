@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Review` struct.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::User;
+use crate::{Client, Error, Result};
+
+/// Represents a review of a media.
+///
+/// The `Review` struct contains a review written by a user, including
+/// its summary, body, score, rating and the timestamps of its creation
+/// and last update.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Review {
+    /// The ID of the review.
+    pub id: i64,
+    /// The summary of the review.
+    pub summary: String,
+    /// The body of the review, in markdown.
+    pub body: String,
+    /// The body of the review, in HTML, if loaded.
+    pub body_html: Option<String>,
+    /// The score given to the media, out of 100.
+    pub score: u8,
+    /// The number of users who liked the review.
+    pub rating: i32,
+    /// The number of users who rated the review.
+    pub rating_amount: i32,
+    /// The author of the review.
+    pub user: User,
+    /// The ID of the reviewed media.
+    pub media_id: i64,
+    /// The creation date of the review, as a UNIX timestamp.
+    pub created_at: i64,
+    /// The last update date of the review, as a UNIX timestamp.
+    pub updated_at: i64,
+}
+
+impl Review {
+    /// Builds a review from a review node of an API response.
+    ///
+    /// # Arguments
+    ///
+    /// * `review` - The review node to build the review from.
+    /// * `client` - The client to attach to the author.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node or its author has no ID.
+    pub(crate) fn from_node(review: &Value, client: &Client) -> Result<Self> {
+        Ok(Self {
+            id: review["id"].as_i64().ok_or(Error::InvalidId)?,
+            summary: review["summary"].as_str().unwrap_or_default().to_string(),
+            body: review["body"].as_str().unwrap_or_default().to_string(),
+            body_html: review["bodyHtml"].as_str().map(String::from),
+            score: review["score"].as_u64().unwrap_or_default() as u8,
+            rating: review["rating"].as_i64().unwrap_or_default() as i32,
+            rating_amount: review["ratingAmount"].as_i64().unwrap_or_default() as i32,
+            user: User::from_node(&review["user"], client)?,
+            media_id: review["mediaId"].as_i64().unwrap_or_default(),
+            created_at: review["createdAt"].as_i64().unwrap_or_default(),
+            updated_at: review["updatedAt"].as_i64().unwrap_or_default(),
+        })
+    }
+}
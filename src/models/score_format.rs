@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `ScoreFormat` enum.
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the format a user gives scores in.
+///
+/// The `ScoreFormat` enum defines the scoring systems supported by the
+/// API, from a 100 point scale down to a 3 point smiley scale, and
+/// converts scores between them and a common 0 to 100 scale.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum ScoreFormat {
+    /// Scores from 0 to 100.
+    #[default]
+    #[serde(rename = "POINT_100")]
+    Point100,
+    /// Scores from 0 to 10.
+    #[serde(rename = "POINT_10")]
+    Point10,
+    /// Scores from 0.0 to 10.0, with one decimal.
+    #[serde(rename = "POINT_10_DECIMAL")]
+    Point10Decimal,
+    /// Scores from 0 to 5 stars.
+    #[serde(rename = "POINT_5")]
+    Point5,
+    /// Scores from 1 to 3, shown as smileys.
+    #[serde(rename = "POINT_3")]
+    Point3,
+}
+
+impl ScoreFormat {
+    /// Returns the name of the score format.
+    pub fn name(&self) -> &str {
+        match self {
+            ScoreFormat::Point100 => "100 Point",
+            ScoreFormat::Point10 => "10 Point",
+            ScoreFormat::Point10Decimal => "10 Point Decimal",
+            ScoreFormat::Point5 => "5 Star",
+            ScoreFormat::Point3 => "3 Point Smiley",
+        }
+    }
+
+    /// Converts a score in this format to a 0 to 100 scale.
+    ///
+    /// A score of zero means the media was not scored and is kept as
+    /// zero. Smiley scores are mapped to 35, 60 and 85, like the API
+    /// does.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - The score in this format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::ScoreFormat;
+    /// assert_eq!(ScoreFormat::Point5.normalize(4.0), 80.0);
+    /// assert_eq!(ScoreFormat::Point3.normalize(3.0), 85.0);
+    /// ```
+    pub fn normalize(&self, raw: f64) -> f64 {
+        if raw <= 0.0 {
+            return 0.0;
+        }
+
+        let score = match self {
+            ScoreFormat::Point100 => raw,
+            ScoreFormat::Point10 | ScoreFormat::Point10Decimal => raw * 10.0,
+            ScoreFormat::Point5 => raw * 20.0,
+            ScoreFormat::Point3 => match raw.round() as u8 {
+                1 => 35.0,
+                2 => 60.0,
+                _ => 85.0,
+            },
+        };
+
+        score.min(100.0)
+    }
+
+    /// Converts a score on a 0 to 100 scale to this format.
+    ///
+    /// Scores are rounded to the precision of the format. A score of
+    /// zero is kept as zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The score on a 0 to 100 scale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::ScoreFormat;
+    /// assert_eq!(ScoreFormat::Point10Decimal.denormalize(87.0), 8.7);
+    /// assert_eq!(ScoreFormat::Point3.denormalize(61.0), 3.0);
+    /// ```
+    pub fn denormalize(&self, score: f64) -> f64 {
+        let score = score.clamp(0.0, 100.0);
+        if score == 0.0 {
+            return 0.0;
+        }
+
+        match self {
+            ScoreFormat::Point100 => score.round(),
+            ScoreFormat::Point10 => (score / 10.0).round().max(1.0),
+            ScoreFormat::Point10Decimal => (score.round() / 10.0).max(0.1),
+            ScoreFormat::Point5 => (score / 20.0).round().max(1.0),
+            ScoreFormat::Point3 => {
+                if score >= 61.0 {
+                    3.0
+                } else if score >= 36.0 {
+                    2.0
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+}
+
+impl From<&str> for ScoreFormat {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "POINT_100" => ScoreFormat::Point100,
+            "POINT_10" => ScoreFormat::Point10,
+            "POINT_10_DECIMAL" => ScoreFormat::Point10Decimal,
+            "POINT_5" => ScoreFormat::Point5,
+            "POINT_3" => ScoreFormat::Point3,
+            _ => ScoreFormat::default(),
+        }
+    }
+}
+
+impl From<String> for ScoreFormat {
+    fn from(value: String) -> Self {
+        ScoreFormat::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for ScoreFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            ScoreFormat::from("POINT_10_DECIMAL"),
+            ScoreFormat::Point10Decimal
+        );
+        assert_eq!(ScoreFormat::from("point_3"), ScoreFormat::Point3);
+        assert_eq!(ScoreFormat::from("unknown"), ScoreFormat::Point100); // Default case
+    }
+
+    #[test]
+    fn test_deserialize() {
+        assert_eq!(
+            serde_json::from_value::<ScoreFormat>(serde_json::json!("POINT_10_DECIMAL")).unwrap(),
+            ScoreFormat::Point10Decimal
+        );
+        assert_eq!(
+            serde_json::to_value(ScoreFormat::Point5).unwrap(),
+            serde_json::json!("POINT_5")
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(ScoreFormat::Point100.normalize(73.0), 73.0);
+        assert_eq!(ScoreFormat::Point10.normalize(7.0), 70.0);
+        assert_eq!(ScoreFormat::Point10Decimal.normalize(7.5), 75.0);
+        assert_eq!(ScoreFormat::Point5.normalize(5.0), 100.0);
+        assert_eq!(ScoreFormat::Point10.normalize(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_denormalize() {
+        assert_eq!(ScoreFormat::Point100.denormalize(72.6), 73.0);
+        assert_eq!(ScoreFormat::Point10.denormalize(74.0), 7.0);
+        assert_eq!(ScoreFormat::Point10Decimal.denormalize(74.0), 7.4);
+        assert_eq!(ScoreFormat::Point5.denormalize(3.0), 1.0);
+        assert_eq!(ScoreFormat::Point5.denormalize(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_point_3_mapping() {
+        assert_eq!(ScoreFormat::Point3.denormalize(0.0), 0.0);
+        assert_eq!(ScoreFormat::Point3.denormalize(1.0), 1.0);
+        assert_eq!(ScoreFormat::Point3.denormalize(35.0), 1.0);
+        assert_eq!(ScoreFormat::Point3.denormalize(36.0), 2.0);
+        assert_eq!(ScoreFormat::Point3.denormalize(60.0), 2.0);
+        assert_eq!(ScoreFormat::Point3.denormalize(61.0), 3.0);
+        assert_eq!(ScoreFormat::Point3.denormalize(100.0), 3.0);
+
+        for raw in [1.0, 2.0, 3.0] {
+            let score = ScoreFormat::Point3.normalize(raw);
+            assert_eq!(ScoreFormat::Point3.denormalize(score), raw);
+        }
+    }
+}
@@ -0,0 +1,481 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `AnimeSearchOptions` and `MangaSearchOptions`
+//! structs.
+
+use serde::{Serialize, Serializer};
+
+use super::{CountryOfOrigin, Date, Format, MediaSort, MediaStatus, Season};
+
+/// Represents the filters of an anime search.
+///
+/// The `AnimeSearchOptions` struct maps onto the media arguments of the
+/// API. Filters left unset are omitted from the request entirely.
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::models::{AnimeSearchOptions, Format, Genre, MediaSort, Season};
+/// let options = AnimeSearchOptions::new()
+///     .genre_in([Genre::Action, Genre::Drama])
+///     .season(Season::Fall)
+///     .season_year(2023)
+///     .format_in([Format::Tv])
+///     .sort([MediaSort::ScoreDesc]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct AnimeSearchOptions {
+    /// The title to search for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    /// The genres the anime must have at least one of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genre_in: Option<Vec<String>>,
+    /// The tags the anime must have at least one of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_in: Option<Vec<String>>,
+    /// The year of the season the anime aired in.
+    #[serde(rename = "seasonYear", skip_serializing_if = "Option::is_none")]
+    pub season_year: Option<u32>,
+    /// The season the anime aired in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<Season>,
+    /// The formats the anime must have one of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format_in: Option<Vec<Format>>,
+    /// The statuses the anime must have one of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_in: Option<Vec<MediaStatus>>,
+    /// Whether the anime is for adults or not.
+    #[serde(rename = "isAdult", skip_serializing_if = "Option::is_none")]
+    pub is_adult: Option<bool>,
+    /// The average score the anime must be above.
+    #[serde(
+        rename = "averageScore_greater",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub average_score_greater: Option<u8>,
+    /// The date the anime must have started after.
+    #[serde(
+        rename = "startDate_greater",
+        serialize_with = "fuzzy_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_date_greater: Option<Date>,
+    /// The date the anime must have started before.
+    #[serde(
+        rename = "startDate_lesser",
+        serialize_with = "fuzzy_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_date_lesser: Option<Date>,
+    /// The order in which the results are sorted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<Vec<MediaSort>>,
+}
+
+impl AnimeSearchOptions {
+    /// Creates new options with no filters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title to search for.
+    ///
+    /// # Arguments
+    ///
+    /// * `search` - The title to search for.
+    pub fn search(mut self, search: &str) -> Self {
+        self.search = Some(search.to_string());
+        self
+    }
+
+    /// Sets the genres the anime must have at least one of.
+    ///
+    /// # Arguments
+    ///
+    /// * `genres` - The genres to filter by, either as `Genre` or by
+    ///   their names.
+    pub fn genre_in<I, S>(mut self, genres: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.genre_in = Some(genres.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the tags the anime must have at least one of.
+    ///
+    /// # Arguments
+    ///
+    /// * `tags` - The tags to filter by.
+    pub fn tag_in<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tag_in = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the year of the season the anime aired in.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year of the season.
+    pub fn season_year(mut self, year: u32) -> Self {
+        self.season_year = Some(year);
+        self
+    }
+
+    /// Sets the season the anime aired in.
+    ///
+    /// # Arguments
+    ///
+    /// * `season` - The season to filter by.
+    pub fn season(mut self, season: Season) -> Self {
+        self.season = Some(season);
+        self
+    }
+
+    /// Sets the formats the anime must have one of.
+    ///
+    /// # Arguments
+    ///
+    /// * `formats` - The formats to filter by.
+    pub fn format_in(mut self, formats: impl IntoIterator<Item = Format>) -> Self {
+        self.format_in = Some(formats.into_iter().collect());
+        self
+    }
+
+    /// Sets the statuses the anime must have one of.
+    ///
+    /// # Arguments
+    ///
+    /// * `statuses` - The statuses to filter by.
+    pub fn status_in(mut self, statuses: impl IntoIterator<Item = MediaStatus>) -> Self {
+        self.status_in = Some(statuses.into_iter().collect());
+        self
+    }
+
+    /// Sets whether the anime is for adults or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_adult` - Whether the anime is for adults or not.
+    pub fn is_adult(mut self, is_adult: bool) -> Self {
+        self.is_adult = Some(is_adult);
+        self
+    }
+
+    /// Sets the average score the anime must be above.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The average score, from 0 to 100.
+    pub fn average_score_greater(mut self, score: u8) -> Self {
+        self.average_score_greater = Some(score);
+        self
+    }
+
+    /// Sets the date the anime must have started after.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date to compare against.
+    pub fn start_date_greater(mut self, date: Date) -> Self {
+        self.start_date_greater = Some(date);
+        self
+    }
+
+    /// Sets the date the anime must have started before.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date to compare against.
+    pub fn start_date_lesser(mut self, date: Date) -> Self {
+        self.start_date_lesser = Some(date);
+        self
+    }
+
+    /// Sets the order in which the results are sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sort` - The sort orders, applied in sequence.
+    pub fn sort(mut self, sort: impl IntoIterator<Item = MediaSort>) -> Self {
+        self.sort = Some(sort.into_iter().collect());
+        self
+    }
+}
+
+/// Represents the filters of a manga search.
+///
+/// The `MangaSearchOptions` struct maps onto the media arguments of the
+/// API. Filters left unset are omitted from the request entirely.
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::models::{CountryOfOrigin, Format, MangaSearchOptions, MediaStatus};
+/// let options = MangaSearchOptions::new()
+///     .country_of_origin(CountryOfOrigin::SouthKorea)
+///     .format_in([Format::Manga])
+///     .status_in([MediaStatus::Releasing])
+///     .chapters_greater(100);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct MangaSearchOptions {
+    /// The title to search for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    /// The country the manga originates from.
+    #[serde(rename = "countryOfOrigin", skip_serializing_if = "Option::is_none")]
+    pub country_of_origin: Option<CountryOfOrigin>,
+    /// The genres the manga must have at least one of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genre_in: Option<Vec<String>>,
+    /// The tags the manga must have at least one of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_in: Option<Vec<String>>,
+    /// The formats the manga must have one of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format_in: Option<Vec<Format>>,
+    /// The statuses the manga must have one of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_in: Option<Vec<MediaStatus>>,
+    /// The amount of chapters the manga must have more than.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chapters_greater: Option<u32>,
+    /// The amount of chapters the manga must have less than.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chapters_lesser: Option<u32>,
+    /// The amount of volumes the manga must have more than.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volumes_greater: Option<u32>,
+    /// The amount of volumes the manga must have less than.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volumes_lesser: Option<u32>,
+    /// The order in which the results are sorted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<Vec<MediaSort>>,
+}
+
+impl MangaSearchOptions {
+    /// Creates new options with no filters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title to search for.
+    ///
+    /// # Arguments
+    ///
+    /// * `search` - The title to search for.
+    pub fn search(mut self, search: &str) -> Self {
+        self.search = Some(search.to_string());
+        self
+    }
+
+    /// Sets the country the manga originates from.
+    ///
+    /// # Arguments
+    ///
+    /// * `country` - The country to filter by.
+    pub fn country_of_origin(mut self, country: CountryOfOrigin) -> Self {
+        self.country_of_origin = Some(country);
+        self
+    }
+
+    /// Sets the genres the manga must have at least one of.
+    ///
+    /// # Arguments
+    ///
+    /// * `genres` - The genres to filter by, either as `Genre` or by
+    ///   their names.
+    pub fn genre_in<I, S>(mut self, genres: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.genre_in = Some(genres.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the tags the manga must have at least one of.
+    ///
+    /// # Arguments
+    ///
+    /// * `tags` - The tags to filter by.
+    pub fn tag_in<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tag_in = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the formats the manga must have one of.
+    ///
+    /// # Arguments
+    ///
+    /// * `formats` - The formats to filter by.
+    pub fn format_in(mut self, formats: impl IntoIterator<Item = Format>) -> Self {
+        self.format_in = Some(formats.into_iter().collect());
+        self
+    }
+
+    /// Sets the statuses the manga must have one of.
+    ///
+    /// # Arguments
+    ///
+    /// * `statuses` - The statuses to filter by.
+    pub fn status_in(mut self, statuses: impl IntoIterator<Item = MediaStatus>) -> Self {
+        self.status_in = Some(statuses.into_iter().collect());
+        self
+    }
+
+    /// Sets the amount of chapters the manga must have more than.
+    ///
+    /// # Arguments
+    ///
+    /// * `chapters` - The amount of chapters.
+    pub fn chapters_greater(mut self, chapters: u32) -> Self {
+        self.chapters_greater = Some(chapters);
+        self
+    }
+
+    /// Sets the amount of chapters the manga must have less than.
+    ///
+    /// # Arguments
+    ///
+    /// * `chapters` - The amount of chapters.
+    pub fn chapters_lesser(mut self, chapters: u32) -> Self {
+        self.chapters_lesser = Some(chapters);
+        self
+    }
+
+    /// Sets the amount of volumes the manga must have more than.
+    ///
+    /// # Arguments
+    ///
+    /// * `volumes` - The amount of volumes.
+    pub fn volumes_greater(mut self, volumes: u32) -> Self {
+        self.volumes_greater = Some(volumes);
+        self
+    }
+
+    /// Sets the amount of volumes the manga must have less than.
+    ///
+    /// # Arguments
+    ///
+    /// * `volumes` - The amount of volumes.
+    pub fn volumes_lesser(mut self, volumes: u32) -> Self {
+        self.volumes_lesser = Some(volumes);
+        self
+    }
+
+    /// Sets the order in which the results are sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sort` - The sort orders, applied in sequence.
+    pub fn sort(mut self, sort: impl IntoIterator<Item = MediaSort>) -> Self {
+        self.sort = Some(sort.into_iter().collect());
+        self
+    }
+}
+
+/// Serializes a date as a fuzzy date integer (`YYYYMMDD`), where the
+/// missing parts of the date are zero.
+fn fuzzy_date<S: Serializer>(date: &Option<Date>, serializer: S) -> Result<S::Ok, S::Error> {
+    match date {
+        Some(date) => serializer.serialize_i64(
+            date.year.unwrap_or_default() as i64 * 10000
+                + date.month.unwrap_or_default() as i64 * 100
+                + date.day.unwrap_or_default() as i64,
+        ),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Genre;
+
+    #[test]
+    fn test_unset_options_are_omitted() {
+        assert_eq!(
+            serde_json::to_value(AnimeSearchOptions::new()).unwrap(),
+            serde_json::json!({})
+        );
+        assert_eq!(
+            serde_json::to_value(AnimeSearchOptions::new().is_adult(false)).unwrap(),
+            serde_json::json!({ "isAdult": false })
+        );
+    }
+
+    #[test]
+    fn test_options_serialize_graphql_names() {
+        let options = AnimeSearchOptions::new()
+            .genre_in(["Action"])
+            .tag_in(["Isekai".to_string()])
+            .season(Season::Fall)
+            .season_year(2023)
+            .format_in([Format::Tv, Format::TvShort])
+            .status_in([MediaStatus::Finished])
+            .average_score_greater(80)
+            .start_date_greater(Date::new(Some(2023), Some(7), None))
+            .start_date_lesser(Date::new(Some(2024), Some(1), Some(15)))
+            .sort([MediaSort::ScoreDesc]);
+
+        assert_eq!(
+            serde_json::to_value(options).unwrap(),
+            serde_json::json!({
+                "genre_in": ["Action"],
+                "tag_in": ["Isekai"],
+                "season": "FALL",
+                "seasonYear": 2023,
+                "format_in": ["TV", "TV_SHORT"],
+                "status_in": ["FINISHED"],
+                "averageScore_greater": 80,
+                "startDate_greater": 20230700,
+                "startDate_lesser": 20240115,
+                "sort": ["SCORE_DESC"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_manga_options_serialize_graphql_names() {
+        let options = MangaSearchOptions::new()
+            .country_of_origin(CountryOfOrigin::China)
+            .format_in([Format::Novel, Format::OneShot])
+            .chapters_greater(10)
+            .volumes_lesser(5);
+
+        assert_eq!(
+            serde_json::to_value(options).unwrap(),
+            serde_json::json!({
+                "countryOfOrigin": "CN",
+                "format_in": ["NOVEL", "ONE_SHOT"],
+                "chapters_greater": 10,
+                "volumes_lesser": 5,
+            })
+        );
+    }
+
+    #[test]
+    fn test_genre_in_with_genres() {
+        let options = MangaSearchOptions::new().genre_in([Genre::SciFi, Genre::SliceOfLife]);
+
+        assert_eq!(
+            options.genre_in,
+            Some(vec!["Sci-Fi".to_string(), "Slice of Life".to_string()])
+        );
+    }
+}
@@ -3,15 +3,18 @@
 
 //! This module contains the `Season` enum.
 
+use chrono::{Datelike, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 
+use super::Date;
+
 /// Represents the four seasons of the year.
 ///
 /// The `Season` enum defines the four seasons: Winter, Spring, Summer,
 /// and Fall. This can be used to categorize or filter data based on
 /// the season.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "UPPERCASE"))]
+#[serde(rename_all = "UPPERCASE")]
 pub enum Season {
     /// Represents the winter season.
     #[default]
@@ -22,6 +25,9 @@ pub enum Season {
     Summer,
     /// Represents the fall season.
     Fall,
+    /// Represents a season not known to this version of the crate.
+    #[serde(other)]
+    Unknown,
 }
 
 impl Season {
@@ -40,16 +46,104 @@ impl Season {
             Season::Spring => "Spring",
             Season::Summer => "Summer",
             Season::Fall => "Fall",
+            Season::Unknown => "Unknown",
+        }
+    }
+
+    /// Returns the current season and its year.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Season;
+    /// let (season, year) = Season::current();
+    /// let (next_season, next_year) = season.next(year);
+    /// ```
+    pub fn current() -> (Season, u32) {
+        Season::of_date(Local::now().date_naive())
+    }
+
+    /// Returns the season of a month, from `1` for January to `12` for
+    /// December.
+    ///
+    /// January to March is winter, April to June is spring, July to
+    /// September is summer and October to December is fall. Returns
+    /// the default season for months out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `month` - The month to get the season of.
+    pub fn from_month(month: u32) -> Season {
+        match month {
+            1..=3 => Season::Winter,
+            4..=6 => Season::Spring,
+            7..=9 => Season::Summer,
+            10..=12 => Season::Fall,
+            _ => Season::default(),
+        }
+    }
+
+    /// Returns the season after this one and its year.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year of this season.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Season;
+    /// assert_eq!(Season::Fall.next(2024), (Season::Winter, 2025));
+    /// ```
+    pub fn next(&self, year: u32) -> (Season, u32) {
+        match self {
+            Season::Winter => (Season::Spring, year),
+            Season::Spring => (Season::Summer, year),
+            Season::Summer => (Season::Fall, year),
+            Season::Fall => (Season::Winter, year + 1),
+            Season::Unknown => (Season::Unknown, year),
+        }
+    }
+
+    /// Returns the season before this one and its year.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year of this season.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Season;
+    /// assert_eq!(Season::Winter.previous(2025), (Season::Fall, 2024));
+    /// ```
+    pub fn previous(&self, year: u32) -> (Season, u32) {
+        match self {
+            Season::Winter => (Season::Fall, year.saturating_sub(1)),
+            Season::Spring => (Season::Winter, year),
+            Season::Summer => (Season::Spring, year),
+            Season::Fall => (Season::Summer, year),
+            Season::Unknown => (Season::Unknown, year),
         }
     }
 
+    /// Returns the season of a date and its year.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date to get the season of.
+    fn of_date(date: NaiveDate) -> (Season, u32) {
+        (Season::from_month(date.month()), date.year().max(0) as u32)
+    }
+
     /// Returns a summary of the season.
     pub fn summary(&self) -> &str {
         match self {
             Season::Winter => "Winter is the coldest season of the year in polar and temperate zones; it does not occur in most of the tropical zone.",
             Season::Spring => "Spring is one of the four temperate seasons, following winter and preceding summer.",
             Season::Summer => "Summer is the hottest of the four temperate seasons, falling after spring and before autumn.",
-            Season::Fall => "Autumn, also known as fall in North American English, is one of the four temperate seasons."
+            Season::Fall => "Autumn, also known as fall in North American English, is one of the four temperate seasons.",
+            Season::Unknown => "A season not known to this version of the crate."
         }
     }
 }
@@ -72,6 +166,15 @@ impl From<String> for Season {
     }
 }
 
+impl From<&Date> for Option<Season> {
+    fn from(date: &Date) -> Self {
+        match date.month? {
+            month @ 1..=12 => Some(Season::from_month(month)),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for Season {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.name())
@@ -107,4 +210,72 @@ mod tests {
         assert_eq!(Season::from("fall".to_string()), Season::Fall);
         assert_eq!(Season::from("unknown".to_string()), Season::Winter); // Default case
     }
+
+    #[test]
+    fn test_from_month() {
+        assert_eq!(Season::from_month(1), Season::Winter);
+        assert_eq!(Season::from_month(3), Season::Winter);
+        assert_eq!(Season::from_month(4), Season::Spring);
+        assert_eq!(Season::from_month(9), Season::Summer);
+        assert_eq!(Season::from_month(10), Season::Fall);
+        assert_eq!(Season::from_month(12), Season::Fall);
+        assert_eq!(Season::from_month(13), Season::Winter); // Default case
+    }
+
+    #[test]
+    fn test_from_date() {
+        assert_eq!(
+            Option::<Season>::from(&Date::new(Some(2023), Some(5), None)),
+            Some(Season::Spring)
+        );
+        assert_eq!(
+            Option::<Season>::from(&Date::new(Some(2023), None, None)),
+            None
+        );
+        assert_eq!(
+            Option::<Season>::from(&Date::new(Some(2023), Some(0), None)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_of_date_across_new_year() {
+        let december = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        let january = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+
+        assert_eq!(Season::of_date(december), (Season::Fall, 2024));
+        assert_eq!(Season::of_date(january), (Season::Winter, 2025));
+    }
+
+    #[test]
+    fn test_next_and_previous() {
+        assert_eq!(Season::Winter.next(2025), (Season::Spring, 2025));
+        assert_eq!(Season::Fall.next(2024), (Season::Winter, 2025));
+        assert_eq!(Season::Winter.previous(2025), (Season::Fall, 2024));
+        assert_eq!(Season::Summer.previous(2025), (Season::Spring, 2025));
+
+        let (season, year) = Season::Fall.next(2024);
+        assert_eq!(season.previous(year), (Season::Fall, 2024));
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_value(Season::Winter).unwrap(),
+            serde_json::json!("WINTER")
+        );
+        assert_eq!(
+            serde_json::to_value(Season::Fall).unwrap(),
+            serde_json::json!("FALL")
+        );
+    }
+
+    #[test]
+    fn test_deserialize_unknown() {
+        assert_eq!(
+            serde_json::from_value::<Season>(serde_json::json!("MONSOON")).unwrap(),
+            Season::Unknown
+        );
+        assert_eq!(Season::Unknown.next(2024), (Season::Unknown, 2024));
+    }
 }
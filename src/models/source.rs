@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 /// Represents the source of a media.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Source {
     /// The original source.
     Original,
@@ -40,6 +40,9 @@ pub enum Source {
     MultimediaProject,
     /// Picture book source.
     PictureBook,
+    /// A source not known to this version of the crate.
+    #[serde(other)]
+    Unknown,
 }
 
 impl Source {
@@ -69,6 +72,7 @@ impl Source {
             Source::Comic => "A publication that consists of comic art in the form of sequential panels excluding manga",
             Source::MultimediaProject => "A work that is a collection of multiple media",
             Source::PictureBook => "A book with pictures and little text",
+            Source::Unknown => "A source not known to this version of the crate",
         }
     }
 }
@@ -120,6 +124,7 @@ impl std::fmt::Display for Source {
             Source::Comic => write!(f, "Comic"),
             Source::MultimediaProject => write!(f, "Multimedia Project"),
             Source::PictureBook => write!(f, "Picture Book"),
+            Source::Unknown => write!(f, "Unknown"),
         }
     }
 }
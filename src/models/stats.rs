@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `MediaStats` struct and its related types.
+
+use serde::{Deserialize, Serialize};
+
+use super::MediaListStatus;
+
+/// Represents the statistics of a media.
+///
+/// The `MediaStats` struct contains how the users scored a media and
+/// how it is distributed across their lists.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaStats {
+    /// The amount of users per score, if loaded.
+    pub score_distribution: Option<Vec<ScoreDistribution>>,
+    /// The amount of users per list status, if loaded.
+    pub status_distribution: Option<Vec<StatusDistribution>>,
+}
+
+/// Represents the amount of users who gave a media a score.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ScoreDistribution {
+    /// The score, from 10 to 100.
+    pub score: u8,
+    /// The amount of users who gave the score.
+    pub amount: u32,
+}
+
+/// Represents the amount of users who have a media in a list.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct StatusDistribution {
+    /// The status of the list.
+    pub status: MediaListStatus,
+    /// The amount of users who have the media in the list.
+    pub amount: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let stats: MediaStats = serde_json::from_value(serde_json::json!({
+            "scoreDistribution": [
+                { "score": 10, "amount": 120 },
+                { "score": 100, "amount": 5000 }
+            ],
+            "statusDistribution": [
+                { "status": "CURRENT", "amount": 300 },
+                { "status": "PLANNING", "amount": 900 }
+            ]
+        }))
+        .unwrap();
+
+        let scores = stats.score_distribution.unwrap();
+        assert_eq!(scores[1].score, 100);
+        assert_eq!(scores[1].amount, 5000);
+
+        let statuses = stats.status_distribution.unwrap();
+        assert_eq!(statuses[0].status, MediaListStatus::Current);
+        assert_eq!(statuses[1].status, MediaListStatus::Planning);
+    }
+
+    #[test]
+    fn test_deserialize_partial() {
+        let stats: MediaStats = serde_json::from_value(serde_json::json!({
+            "scoreDistribution": [{ "score": 50, "amount": 1 }]
+        }))
+        .unwrap();
+
+        assert!(stats.score_distribution.is_some());
+        assert_eq!(stats.status_distribution, None);
+    }
+}
@@ -1,13 +1,114 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
 
-//! This module contains the `Status` enum.
+//! This module contains the `MediaStatus` and `MediaListStatus` enums,
+//! and the deprecated `Status` enum.
 
 use serde::{Deserialize, Serialize};
 
+/// Represents the release status of a media.
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MediaStatus {
+    /// The media is finished.
+    Finished,
+    /// The media is currently releasing.
+    Releasing,
+    /// The media is not yet released.
+    #[default]
+    NotYetReleased,
+    /// The media has been cancelled.
+    Cancelled,
+    /// The media is on hiatus.
+    Hiatus,
+    /// A status not known to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl MediaStatus {
+    /// Returns a summary of the status.
+    pub fn summary(&self) -> &str {
+        match self {
+            MediaStatus::Finished => "Has completed and is no longer being updated.",
+            MediaStatus::Releasing => "Currently releasing.",
+            MediaStatus::NotYetReleased => "To be released in the future.",
+            MediaStatus::Cancelled => "Ended before the work could be completed.",
+            MediaStatus::Hiatus => "Currently paused with the intention of resuming in the future.",
+            MediaStatus::Unknown => "A status not known to this version of the crate.",
+        }
+    }
+}
+
+impl std::fmt::Display for MediaStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MediaStatus::Finished => write!(f, "Finished"),
+            MediaStatus::Releasing => write!(f, "Releasing"),
+            MediaStatus::NotYetReleased => write!(f, "Not Yet Released"),
+            MediaStatus::Cancelled => write!(f, "Cancelled"),
+            MediaStatus::Hiatus => write!(f, "Hiatus"),
+            MediaStatus::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Represents the status of a media in a user's list.
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MediaListStatus {
+    /// The user is currently watching or reading the media.
+    #[default]
+    Current,
+    /// The user is planning to watch or read the media.
+    Planning,
+    /// The user has completed the media.
+    Completed,
+    /// The user has dropped the media.
+    Dropped,
+    /// The user has paused the media.
+    Paused,
+    /// The user is rewatching or rereading the media.
+    Repeating,
+    /// A status not known to this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl MediaListStatus {
+    /// Returns a summary of the status.
+    pub fn summary(&self) -> &str {
+        match self {
+            MediaListStatus::Current => "Currently watching or reading.",
+            MediaListStatus::Planning => "Planning to watch or read.",
+            MediaListStatus::Completed => "Finished watching or reading.",
+            MediaListStatus::Dropped => "Stopped watching or reading before finishing.",
+            MediaListStatus::Paused => "Paused with the intention of resuming.",
+            MediaListStatus::Repeating => "Watching or reading again.",
+            MediaListStatus::Unknown => "A status not known to this version of the crate.",
+        }
+    }
+}
+
+impl std::fmt::Display for MediaListStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MediaListStatus::Current => write!(f, "Current"),
+            MediaListStatus::Planning => write!(f, "Planning"),
+            MediaListStatus::Completed => write!(f, "Completed"),
+            MediaListStatus::Dropped => write!(f, "Dropped"),
+            MediaListStatus::Paused => write!(f, "Paused"),
+            MediaListStatus::Repeating => write!(f, "Repeating"),
+            MediaListStatus::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
 /// Represents the status of a media.
+#[deprecated(note = "use `MediaStatus` or `MediaListStatus` instead")]
+#[allow(deprecated)]
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Status {
     /// The media is finished.
     Finished,
@@ -34,6 +135,7 @@ pub enum Status {
     Repeating,
 }
 
+#[allow(deprecated)]
 impl Status {
     /// Returns a summary of the status.
     pub fn summary(&self) -> &str {
@@ -55,6 +157,7 @@ impl Status {
     }
 }
 
+#[allow(deprecated)]
 impl std::fmt::Display for Status {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -72,3 +175,61 @@ impl std::fmt::Display for Status {
         }
     }
 }
+
+#[allow(deprecated)]
+impl From<MediaStatus> for Status {
+    fn from(value: MediaStatus) -> Self {
+        match value {
+            MediaStatus::Finished => Status::Finished,
+            MediaStatus::Releasing => Status::Releasing,
+            MediaStatus::NotYetReleased => Status::NotYetReleased,
+            MediaStatus::Cancelled => Status::Cancelled,
+            MediaStatus::Hiatus => Status::Hiatus,
+            MediaStatus::Unknown => Status::default(),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<MediaListStatus> for Status {
+    fn from(value: MediaListStatus) -> Self {
+        match value {
+            MediaListStatus::Current => Status::Current,
+            MediaListStatus::Planning => Status::Planning,
+            MediaListStatus::Completed => Status::Completed,
+            MediaListStatus::Dropped => Status::Dropped,
+            MediaListStatus::Paused => Status::Paused,
+            MediaListStatus::Repeating => Status::Repeating,
+            MediaListStatus::Unknown => Status::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_value(MediaStatus::NotYetReleased).unwrap(),
+            serde_json::json!("NOT_YET_RELEASED")
+        );
+        assert_eq!(
+            serde_json::to_value(MediaListStatus::Repeating).unwrap(),
+            serde_json::json!("REPEATING")
+        );
+    }
+
+    #[test]
+    fn test_media_status_does_not_accept_list_status() {
+        assert_eq!(
+            serde_json::from_value::<MediaStatus>(serde_json::json!("DROPPED")).unwrap(),
+            MediaStatus::Unknown
+        );
+        assert_eq!(
+            serde_json::from_value::<MediaListStatus>(serde_json::json!("FINISHED")).unwrap(),
+            MediaListStatus::Unknown
+        );
+    }
+}
@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `StreamingEpisode` struct and its related
+//! types.
+
+use serde::{Deserialize, Serialize};
+
+/// Represents an episode of an anime on a streaming site.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct StreamingEpisode {
+    /// The title of the episode, if any.
+    pub title: Option<String>,
+    /// The URL of the thumbnail of the episode, if any.
+    pub thumbnail: Option<String>,
+    /// The URL of the episode, if any.
+    pub url: Option<String>,
+    /// The site streaming the episode.
+    #[serde(default)]
+    pub site: StreamingSite,
+}
+
+/// Represents a site streaming anime.
+///
+/// The `StreamingSite` enum defines the most common streaming providers,
+/// and holds the name of the site for any other provider.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum StreamingSite {
+    /// Crunchyroll.
+    Crunchyroll,
+    /// Netflix.
+    Netflix,
+    /// Hulu.
+    Hulu,
+    /// HIDIVE.
+    Hidive,
+    /// Amazon Prime Video.
+    Amazon,
+    /// Bilibili.
+    Bilibili,
+    /// Another site, by its name.
+    Other(String),
+}
+
+impl StreamingSite {
+    /// Returns the name of the site.
+    pub fn name(&self) -> &str {
+        match self {
+            StreamingSite::Crunchyroll => "Crunchyroll",
+            StreamingSite::Netflix => "Netflix",
+            StreamingSite::Hulu => "Hulu",
+            StreamingSite::Hidive => "HIDIVE",
+            StreamingSite::Amazon => "Amazon Prime Video",
+            StreamingSite::Bilibili => "Bilibili TV",
+            StreamingSite::Other(name) => name,
+        }
+    }
+}
+
+impl Default for StreamingSite {
+    fn default() -> Self {
+        StreamingSite::Other(String::new())
+    }
+}
+
+impl From<&str> for StreamingSite {
+    fn from(value: &str) -> Self {
+        let name = value.trim().to_lowercase();
+
+        match name.as_str() {
+            "crunchyroll" => StreamingSite::Crunchyroll,
+            "netflix" => StreamingSite::Netflix,
+            "hulu" => StreamingSite::Hulu,
+            "hidive" => StreamingSite::Hidive,
+            _ if name.starts_with("amazon") => StreamingSite::Amazon,
+            _ if name.starts_with("bilibili") => StreamingSite::Bilibili,
+            _ => StreamingSite::Other(value.trim().to_string()),
+        }
+    }
+}
+
+impl From<String> for StreamingSite {
+    fn from(value: String) -> Self {
+        StreamingSite::from(value.as_str())
+    }
+}
+
+impl From<StreamingSite> for String {
+    fn from(value: StreamingSite) -> Self {
+        value.name().to_string()
+    }
+}
+
+impl std::fmt::Display for StreamingSite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            StreamingSite::from("Crunchyroll"),
+            StreamingSite::Crunchyroll
+        );
+        assert_eq!(StreamingSite::from("HIDIVE"), StreamingSite::Hidive);
+        assert_eq!(
+            StreamingSite::from("Amazon Prime Video"),
+            StreamingSite::Amazon
+        );
+        assert_eq!(StreamingSite::from("Bilibili TV"), StreamingSite::Bilibili);
+        assert_eq!(
+            StreamingSite::from("Tubi TV"),
+            StreamingSite::Other("Tubi TV".to_string())
+        );
+    }
+
+    #[test]
+    fn test_deserialize_episode() {
+        let episode: StreamingEpisode = serde_json::from_value(serde_json::json!({
+            "title": "Episode 1 - Asterisk",
+            "thumbnail": "https://example.com/thumbnail.jpg",
+            "url": "https://www.crunchyroll.com/watch/1",
+            "site": "Crunchyroll"
+        }))
+        .unwrap();
+
+        assert_eq!(episode.site, StreamingSite::Crunchyroll);
+        assert_eq!(episode.title.as_deref(), Some("Episode 1 - Asterisk"));
+        assert_eq!(
+            serde_json::to_value(&episode.site).unwrap(),
+            serde_json::json!("Crunchyroll")
+        );
+    }
+}
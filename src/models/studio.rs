@@ -4,8 +4,10 @@
 //! This module contains the `Studio` struct.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::Result;
+use super::MediaType;
+use crate::{parse, Client, Error, Result};
 
 /// Represents a studio with various attributes.
 ///
@@ -13,7 +15,7 @@ use crate::Result;
 /// including its ID, name, whether it is an animation studio, URL,
 /// whether it is a favorite, and the number of favorites.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Studio {
     /// The ID of the studio.
     pub id: i64,
@@ -22,14 +24,47 @@ pub struct Studio {
     /// Whether the studio is an animation studio.
     pub is_animation_studio: bool,
     /// The URL of the studio.
+    #[serde(rename = "siteUrl")]
     pub url: String,
     /// Whether the studio is a favorite.
     pub is_favourite: Option<bool>,
     /// The number of favorites the studio has.
     pub favourites: i64,
+
+    /// The client used to fetch additional data.
+    #[serde(skip)]
+    pub(crate) client: Client,
+    /// Whether the studio's data is fully loaded.
+    #[serde(skip)]
+    pub(crate) is_full_loaded: bool,
 }
 
 impl Studio {
+    /// Builds a partially loaded studio from a studio node of an API
+    /// response, such as the ones returned by searches.
+    ///
+    /// # Arguments
+    ///
+    /// * `studio` - The studio node to build the studio from.
+    /// * `client` - The client to attach to the studio.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node has no ID or an invalid name.
+    pub(crate) fn from_node(studio: &Value, client: &Client) -> Result<Self> {
+        Ok(Self {
+            id: studio["id"].as_i64().ok_or(Error::InvalidId)?,
+            name: parse::deserialize(studio, "name")?,
+            is_animation_studio: studio["isAnimationStudio"].as_bool().unwrap_or_default(),
+            url: studio["siteUrl"].as_str().unwrap_or_default().to_string(),
+            is_favourite: studio["isFavourite"].as_bool(),
+            favourites: studio["favourites"].as_i64().unwrap_or_default(),
+
+            client: client.clone(),
+            ..Default::default()
+        })
+    }
+
     /// Retrieves media associated with the studio.
     ///
     /// This function fetches media related to the studio and returns a
@@ -52,4 +87,35 @@ impl Studio {
     pub async fn get_medias<T>(&self) -> Result<Vec<T>> {
         unimplemented!()
     }
+
+    /// Toggles whether the studio is a favourite of the authenticated
+    /// user, returning whether it is a favourite afterwards.
+    ///
+    /// The new state is read back from the API and stored in
+    /// `is_favourite`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Unauthorized`, without sending any request, if the
+    /// client has no API token, or an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Studio, Result};
+    /// #
+    /// # async fn f(mut studio: Studio) -> Result<()> {
+    /// let is_favourite = studio.toggle_favourite().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn toggle_favourite(&mut self) -> Result<bool> {
+        let is_favourite = self
+            .client
+            .toggle_favourite_state(MediaType::Studio, self.id, None)
+            .await?;
+        self.is_favourite = Some(is_favourite);
+
+        Ok(is_favourite)
+    }
 }
@@ -3,11 +3,13 @@
 
 //! This module contains the `Tag` struct.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Represents a tag in the system.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Tag {
     /// The ID of the tag.
     pub id: i64,
@@ -18,13 +20,137 @@ pub struct Tag {
     /// The category of the tag.
     pub category: String,
     /// The rank of the tag.
-    pub rank: i64,
+    ///
+    /// This is only set when the tag is attached to a media, and may
+    /// be null even then.
+    #[serde(default)]
+    pub rank: Option<i64>,
     /// Whether the tag is a general spoiler.
     pub is_general_spoiler: bool,
     /// Whether the tag is a media spoiler.
+    #[serde(default)]
     pub is_media_spoiler: bool,
     /// Whether the tag is adult content.
     pub is_adult: bool,
     /// The user ID associated with the tag.
     pub user_id: Option<i64>,
 }
+
+impl Tag {
+    /// Returns whether the tag spoils the media, either in general or
+    /// for the media it is attached to.
+    pub fn is_spoiler(&self) -> bool {
+        self.is_general_spoiler || self.is_media_spoiler
+    }
+}
+
+/// Returns the tags of a media that are not spoilers.
+///
+/// # Arguments
+///
+/// * `tags` - The tags of the media, if loaded.
+pub(crate) fn tags_without_spoilers(tags: Option<&[Tag]>) -> Vec<&Tag> {
+    tags.unwrap_or_default()
+        .iter()
+        .filter(|tag| !tag.is_spoiler())
+        .collect()
+}
+
+/// Groups the tags of a media by their category.
+///
+/// # Arguments
+///
+/// * `tags` - The tags of the media, if loaded.
+pub(crate) fn tags_by_category(tags: Option<&[Tag]>) -> BTreeMap<String, Vec<&Tag>> {
+    let mut categories: BTreeMap<String, Vec<&Tag>> = BTreeMap::new();
+
+    for tag in tags.unwrap_or_default() {
+        categories
+            .entry(tag.category.clone())
+            .or_default()
+            .push(tag);
+    }
+
+    categories
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_tag_collection() {
+        let json = r#"[
+            {
+                "id": 29,
+                "name": "Magic",
+                "description": "Prominently features magical elements or the use of magic.",
+                "category": "Theme-Fantasy",
+                "isGeneralSpoiler": false,
+                "isAdult": false
+            },
+            {
+                "id": 50,
+                "name": "Maids",
+                "description": "Prominently features a character who is a maid.",
+                "category": "Cast-Traits",
+                "isGeneralSpoiler": false,
+                "isAdult": false
+            }
+        ]"#;
+        let tags: Vec<Tag> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].id, 29);
+        assert_eq!(tags[0].name, "Magic");
+        assert_eq!(tags[1].category, "Cast-Traits");
+        assert_eq!(tags[1].rank, None);
+        assert!(!tags[1].is_media_spoiler);
+        assert_eq!(tags[1].user_id, None);
+    }
+
+    fn media_tags() -> Vec<Tag> {
+        serde_json::from_value(serde_json::json!([
+            { "id": 1, "name": "Time Skip", "description": "", "category": "Setting-Time", "rank": 80, "isGeneralSpoiler": false, "isMediaSpoiler": true, "isAdult": false },
+            { "id": 2, "name": "Magic", "description": "", "category": "Theme-Fantasy", "rank": null, "isGeneralSpoiler": false, "isMediaSpoiler": false, "isAdult": false },
+            { "id": 3, "name": "Twins", "description": "", "category": "Cast-Traits", "rank": 60, "isGeneralSpoiler": true, "isMediaSpoiler": false, "isAdult": false },
+            { "id": 4, "name": "Elf", "description": "", "category": "Theme-Fantasy", "rank": 50, "isGeneralSpoiler": false, "isMediaSpoiler": false, "isAdult": false }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_null_rank() {
+        let tags = media_tags();
+
+        assert_eq!(tags[0].rank, Some(80));
+        assert_eq!(tags[1].rank, None);
+    }
+
+    #[test]
+    fn test_tags_without_spoilers() {
+        let tags = media_tags();
+        let ids: Vec<i64> = tags_without_spoilers(Some(&tags))
+            .iter()
+            .map(|tag| tag.id)
+            .collect();
+
+        assert!(tags[0].is_spoiler());
+        assert!(tags[2].is_spoiler());
+        assert_eq!(ids, vec![2, 4]);
+        assert!(tags_without_spoilers(None).is_empty());
+    }
+
+    #[test]
+    fn test_tags_by_category() {
+        let tags = media_tags();
+        let categories = tags_by_category(Some(&tags));
+
+        assert_eq!(
+            categories.keys().collect::<Vec<_>>(),
+            vec!["Cast-Traits", "Setting-Time", "Theme-Fantasy"]
+        );
+        assert_eq!(categories["Theme-Fantasy"].len(), 2);
+        assert!(tags_by_category(None).is_empty());
+    }
+}
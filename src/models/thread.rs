@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Thread` struct and its related types.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::User;
+use crate::{Client, Error, Result};
+
+/// Represents a forum thread.
+///
+/// The `Thread` struct contains a thread posted by a user, including
+/// its title, body, categories, the media it is about, its reply and
+/// view counts and the timestamps of its activity.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Thread {
+    /// The ID of the thread.
+    pub id: i64,
+    /// The title of the thread.
+    pub title: String,
+    /// The body of the thread, in markdown.
+    pub body: String,
+    /// The body of the thread, in HTML, if loaded.
+    pub body_html: Option<String>,
+    /// The author of the thread.
+    pub user: User,
+    /// The categories of the thread.
+    pub categories: Vec<ThreadCategory>,
+    /// The IDs of the media the thread is about.
+    pub media_ids: Vec<i64>,
+    /// The number of replies to the thread.
+    pub reply_count: u32,
+    /// The number of times the thread was viewed.
+    pub view_count: u32,
+    /// The number of likes of the thread.
+    pub like_count: u32,
+    /// Whether the thread is pinned to the top of the forum.
+    pub is_sticky: bool,
+    /// Whether the thread is locked for new replies.
+    pub is_locked: bool,
+    /// The site URL of the thread.
+    pub url: String,
+    /// The date of the last reply to the thread, as a UNIX timestamp.
+    pub replied_at: Option<i64>,
+    /// The creation date of the thread, as a UNIX timestamp.
+    pub created_at: i64,
+    /// The last update date of the thread, as a UNIX timestamp.
+    pub updated_at: i64,
+}
+
+impl Thread {
+    /// Builds a thread from a thread node of an API response.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread` - The thread node to build the thread from.
+    /// * `client` - The client to attach to the author.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node or its author has no ID.
+    pub(crate) fn from_node(thread: &Value, client: &Client) -> Result<Self> {
+        Ok(Self {
+            id: thread["id"].as_i64().ok_or(Error::InvalidId)?,
+            title: thread["title"].as_str().unwrap_or_default().to_string(),
+            body: thread["body"].as_str().unwrap_or_default().to_string(),
+            body_html: thread["bodyHtml"].as_str().map(String::from),
+            user: User::from_node(&thread["user"], client)?,
+            categories: Vec::<ThreadCategory>::deserialize(&thread["categories"])
+                .unwrap_or_default(),
+            media_ids: thread["mediaCategories"]
+                .as_array()
+                .map(|media| media.iter().filter_map(|m| m["id"].as_i64()).collect())
+                .unwrap_or_default(),
+            reply_count: thread["replyCount"].as_u64().unwrap_or_default() as u32,
+            view_count: thread["viewCount"].as_u64().unwrap_or_default() as u32,
+            like_count: thread["likeCount"].as_u64().unwrap_or_default() as u32,
+            is_sticky: thread["isSticky"].as_bool().unwrap_or_default(),
+            is_locked: thread["isLocked"].as_bool().unwrap_or_default(),
+            url: thread["siteUrl"].as_str().unwrap_or_default().to_string(),
+            replied_at: thread["repliedAt"].as_i64(),
+            created_at: thread["createdAt"].as_i64().unwrap_or_default(),
+            updated_at: thread["updatedAt"].as_i64().unwrap_or_default(),
+        })
+    }
+}
+
+/// Represents a category of forum threads, like "Anime" or "Release
+/// Discussion".
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct ThreadCategory {
+    /// The ID of the category.
+    pub id: i64,
+    /// The name of the category.
+    pub name: String,
+}
+
+/// Represents a comment on a forum thread.
+///
+/// The `ThreadComment` struct contains a comment posted by a user and
+/// the replies to it, which are comments themselves.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ThreadComment {
+    /// The ID of the comment.
+    pub id: i64,
+    /// The ID of the thread of the comment.
+    pub thread_id: Option<i64>,
+    /// The text of the comment, in markdown.
+    pub comment: String,
+    /// The text of the comment, in HTML, if loaded.
+    pub comment_html: Option<String>,
+    /// The author of the comment.
+    pub user: User,
+    /// The number of likes of the comment.
+    pub like_count: u32,
+    /// Whether the comment is locked for new replies.
+    pub is_locked: bool,
+    /// The site URL of the comment, if loaded.
+    pub url: Option<String>,
+    /// The creation date of the comment, as a UNIX timestamp.
+    pub created_at: i64,
+    /// The last update date of the comment, as a UNIX timestamp.
+    pub updated_at: i64,
+    /// The replies to the comment.
+    pub child_comments: Vec<ThreadComment>,
+}
+
+impl ThreadComment {
+    /// Builds a comment and its replies from a comment node of an API
+    /// response.
+    ///
+    /// The replies are returned by the API as untyped JSON, with the
+    /// same shape as the comment, so they are built recursively.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment` - The comment node to build the comment from.
+    /// * `client` - The client to attach to the authors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node, one of its replies or one of their
+    /// authors has no ID.
+    pub(crate) fn from_node(comment: &Value, client: &Client) -> Result<Self> {
+        let mut child_comments = Vec::new();
+
+        if let Some(children) = comment["childComments"].as_array() {
+            for child in children {
+                child_comments.push(ThreadComment::from_node(child, client)?);
+            }
+        }
+
+        Ok(Self {
+            id: comment["id"].as_i64().ok_or(Error::InvalidId)?,
+            thread_id: comment["threadId"].as_i64(),
+            comment: comment["comment"].as_str().unwrap_or_default().to_string(),
+            comment_html: comment["commentHtml"].as_str().map(String::from),
+            user: User::from_node(&comment["user"], client)?,
+            like_count: comment["likeCount"].as_u64().unwrap_or_default() as u32,
+            is_locked: comment["isLocked"].as_bool().unwrap_or_default(),
+            url: comment["siteUrl"].as_str().map(String::from),
+            created_at: comment["createdAt"].as_i64().unwrap_or_default(),
+            updated_at: comment["updatedAt"].as_i64().unwrap_or_default(),
+            child_comments,
+        })
+    }
+
+    /// Returns the number of replies to the comment, including the
+    /// replies to the replies.
+    pub fn reply_count(&self) -> usize {
+        self.child_comments
+            .iter()
+            .map(|child| 1 + child.reply_count())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_from_node() {
+        let comment = ThreadComment::from_node(
+            &serde_json::json!({
+                "id": 1,
+                "threadId": 10,
+                "comment": "Great episode!",
+                "likeCount": 3,
+                "user": { "id": 1, "name": "Josh" },
+                "createdAt": 1700000000,
+                "updatedAt": 1700000000,
+                "childComments": [{
+                    "id": 2,
+                    "comment": "Agreed",
+                    "user": { "id": 2, "name": "Ann" },
+                    "childComments": [{
+                        "id": 3,
+                        "comment": "Same",
+                        "user": { "id": 1, "name": "Josh" },
+                        "childComments": null
+                    }]
+                }]
+            }),
+            &Client::default(),
+        )
+        .unwrap();
+
+        assert_eq!(comment.thread_id, Some(10));
+        assert_eq!(comment.like_count, 3);
+        assert_eq!(comment.child_comments[0].user.name, "Ann");
+        assert_eq!(comment.child_comments[0].child_comments[0].id, 3);
+        assert_eq!(comment.reply_count(), 2);
+    }
+
+    #[test]
+    fn test_comment_from_node_without_id() {
+        let comment = serde_json::json!({
+            "id": 1,
+            "user": { "id": 1 },
+            "childComments": [{ "comment": "Broken" }]
+        });
+
+        assert!(matches!(
+            ThreadComment::from_node(&comment, &Client::default()),
+            Err(Error::InvalidId)
+        ));
+    }
+}
@@ -5,15 +5,18 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::{connection::nullable, UserTitleLanguage};
+
 /// Represents a title with various language options.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "lowercase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Title {
     /// The title in Romaji (Latin script).
     romaji: Option<String>,
     /// The title in English.
     english: Option<String>,
     /// The title in the native language.
+    #[serde(default, deserialize_with = "nullable")]
     native: String,
     /// The title preferred by the user.
     user_preferred: Option<String>,
@@ -40,6 +43,68 @@ impl Title {
         self.user_preferred.as_deref().unwrap_or(&self.native)
     }
 
+    /// Returns the title in Romaji (Latin script), if any.
+    pub fn romaji_raw(&self) -> Option<&str> {
+        self.romaji.as_deref().filter(|title| !title.is_empty())
+    }
+
+    /// Returns the title in English, if any.
+    pub fn english_raw(&self) -> Option<&str> {
+        self.english.as_deref().filter(|title| !title.is_empty())
+    }
+
+    /// Returns the title in the native language, if any.
+    pub fn native_raw(&self) -> Option<&str> {
+        Some(self.native.as_str()).filter(|title| !title.is_empty())
+    }
+
+    /// Returns the title preferred by the user, if any.
+    pub fn user_preferred_raw(&self) -> Option<&str> {
+        self.user_preferred
+            .as_deref()
+            .filter(|title| !title.is_empty())
+    }
+
+    /// Returns the title in the title language chosen by a user.
+    ///
+    /// Falls back to the other languages when the title is missing in
+    /// the chosen one, Romaji and English falling back to each other
+    /// before the native title.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The title language chosen by the user.
+    pub fn preferred(&self, language: &UserTitleLanguage) -> &str {
+        let titles = match language {
+            UserTitleLanguage::Romaji | UserTitleLanguage::RomajiStylised => {
+                [self.romaji_raw(), self.english_raw(), self.native_raw()]
+            }
+            UserTitleLanguage::English | UserTitleLanguage::EnglishStylised => {
+                [self.english_raw(), self.romaji_raw(), self.native_raw()]
+            }
+            UserTitleLanguage::Native | UserTitleLanguage::NativeStylised => {
+                [self.native_raw(), self.romaji_raw(), self.english_raw()]
+            }
+        };
+
+        titles.into_iter().flatten().next().unwrap_or_default()
+    }
+
+    /// Returns the first title available, in order, the one preferred by
+    /// the user, the English, the Romaji and the native one.
+    pub fn any(&self) -> &str {
+        [
+            self.user_preferred_raw(),
+            self.english_raw(),
+            self.romaji_raw(),
+            self.native_raw(),
+        ]
+        .into_iter()
+        .flatten()
+        .next()
+        .unwrap_or_default()
+    }
+
     /// Checks if the title is empty.
     ///
     /// A title is considered empty if all of its fields are either `None` or empty.
@@ -229,4 +294,41 @@ mod tests {
 
         assert_eq!(title_string, "Native Title");
     }
+
+    #[test]
+    fn test_preferred() {
+        let title = Title {
+            romaji: Some("Shingeki no Kyojin".to_string()),
+            english: None,
+            native: "進撃の巨人".to_string(),
+            user_preferred: None,
+        };
+
+        assert_eq!(
+            title.preferred(&UserTitleLanguage::Romaji),
+            "Shingeki no Kyojin"
+        );
+        assert_eq!(
+            title.preferred(&UserTitleLanguage::EnglishStylised),
+            "Shingeki no Kyojin"
+        );
+        assert_eq!(title.preferred(&UserTitleLanguage::Native), "進撃の巨人");
+    }
+
+    #[test]
+    fn test_any_and_raw() {
+        let title: Title = serde_json::from_value(serde_json::json!({
+            "romaji": "Shingeki no Kyojin",
+            "english": "Attack on Titan",
+            "native": null,
+            "userPreferred": ""
+        }))
+        .unwrap();
+
+        assert_eq!(title.any(), "Attack on Titan");
+        assert_eq!(title.native_raw(), None);
+        assert_eq!(title.user_preferred_raw(), None);
+        assert_eq!(title.romaji_raw(), Some("Shingeki no Kyojin"));
+        assert_eq!(Title::default().any(), "");
+    }
 }
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Trailer` struct.
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the trailer of a media.
+///
+/// The `Trailer` struct contains the ID of the trailer video on the site
+/// hosting it, the name of that site and a thumbnail of the video.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Trailer {
+    /// The ID of the video on its site.
+    pub id: String,
+    /// The site hosting the video, usually "youtube" or "dailymotion".
+    pub site: String,
+    /// The URL of the thumbnail of the video, if any.
+    pub thumbnail: Option<String>,
+}
+
+impl Trailer {
+    /// Returns the URL to watch the trailer.
+    ///
+    /// Returns `None` if the site of the trailer is not known.
+    pub fn url(&self) -> Option<String> {
+        match self.site.trim().to_lowercase().as_str() {
+            "youtube" => Some(format!("https://www.youtube.com/watch?v={}", self.id)),
+            "dailymotion" => Some(format!("https://www.dailymotion.com/video/{}", self.id)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url() {
+        let trailer = Trailer {
+            id: "dQw4w9WgXcQ".to_string(),
+            site: "youtube".to_string(),
+            thumbnail: None,
+        };
+        assert_eq!(
+            trailer.url().as_deref(),
+            Some("https://www.youtube.com/watch?v=dQw4w9WgXcQ")
+        );
+
+        let trailer = Trailer {
+            id: "x7tgad0".to_string(),
+            site: "dailymotion".to_string(),
+            thumbnail: None,
+        };
+        assert_eq!(
+            trailer.url().as_deref(),
+            Some("https://www.dailymotion.com/video/x7tgad0")
+        );
+    }
+
+    #[test]
+    fn test_url_with_unknown_site() {
+        let trailer = Trailer {
+            id: "1".to_string(),
+            site: "vimeo".to_string(),
+            thumbnail: None,
+        };
+
+        assert_eq!(trailer.url(), None);
+    }
+}
@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `MediaTrend` struct and its related types.
+
+use chrono::{DateTime, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Represents the daily statistics of a media.
+///
+/// The `MediaTrend` struct contains the trending, score, popularity and
+/// watching or reading counts of a media on a given day, along with the
+/// episode aired that day, if any.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaTrend {
+    /// The ID of the media.
+    pub media_id: i64,
+    /// The day of the statistics, as a Unix timestamp.
+    pub date: i64,
+    /// The amount of activity on the media that day.
+    pub trending: i32,
+    /// The average score of the media that day.
+    pub average_score: Option<i32>,
+    /// The number of users with the media on their list that day.
+    pub popularity: Option<i32>,
+    /// The number of users watching or reading the media that day.
+    pub in_progress: Option<i32>,
+    /// Whether the media was releasing that day.
+    pub releasing: bool,
+    /// The episode aired that day, if any.
+    pub episode: Option<i32>,
+}
+
+impl MediaTrend {
+    /// Returns the day of the statistics, in UTC.
+    ///
+    /// Returns `None` if the timestamp is out of range.
+    pub fn date_naive(&self) -> Option<NaiveDate> {
+        DateTime::from_timestamp(self.date, 0).map(|date| date.date_naive())
+    }
+}
+
+/// Represents the order in which media trends are sorted.
+///
+/// The `MediaTrendSort` enum defines the sort orders supported by the
+/// API when listing the trends of a media, newest first by default.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MediaTrendSort {
+    /// Sort by ID, ascending.
+    Id,
+    /// Sort by ID, descending.
+    IdDesc,
+    /// Sort by media ID, ascending.
+    MediaId,
+    /// Sort by media ID, descending.
+    MediaIdDesc,
+    /// Sort by date, ascending.
+    Date,
+    /// Sort by date, descending.
+    #[default]
+    DateDesc,
+    /// Sort by score, ascending.
+    Score,
+    /// Sort by score, descending.
+    ScoreDesc,
+    /// Sort by popularity, ascending.
+    Popularity,
+    /// Sort by popularity, descending.
+    PopularityDesc,
+    /// Sort by trending, ascending.
+    Trending,
+    /// Sort by trending, descending.
+    TrendingDesc,
+    /// Sort by episode, ascending.
+    Episode,
+    /// Sort by episode, descending.
+    EpisodeDesc,
+}
+
+impl MediaTrendSort {
+    /// Returns the name of the sort order.
+    pub fn name(&self) -> &str {
+        match self {
+            MediaTrendSort::Id => "ID",
+            MediaTrendSort::IdDesc => "ID (Descending)",
+            MediaTrendSort::MediaId => "Media ID",
+            MediaTrendSort::MediaIdDesc => "Media ID (Descending)",
+            MediaTrendSort::Date => "Date",
+            MediaTrendSort::DateDesc => "Date (Descending)",
+            MediaTrendSort::Score => "Score",
+            MediaTrendSort::ScoreDesc => "Score (Descending)",
+            MediaTrendSort::Popularity => "Popularity",
+            MediaTrendSort::PopularityDesc => "Popularity (Descending)",
+            MediaTrendSort::Trending => "Trending",
+            MediaTrendSort::TrendingDesc => "Trending (Descending)",
+            MediaTrendSort::Episode => "Episode",
+            MediaTrendSort::EpisodeDesc => "Episode (Descending)",
+        }
+    }
+}
+
+impl From<&str> for MediaTrendSort {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "ID" => MediaTrendSort::Id,
+            "ID_DESC" => MediaTrendSort::IdDesc,
+            "MEDIA_ID" => MediaTrendSort::MediaId,
+            "MEDIA_ID_DESC" => MediaTrendSort::MediaIdDesc,
+            "DATE" => MediaTrendSort::Date,
+            "DATE_DESC" => MediaTrendSort::DateDesc,
+            "SCORE" => MediaTrendSort::Score,
+            "SCORE_DESC" => MediaTrendSort::ScoreDesc,
+            "POPULARITY" => MediaTrendSort::Popularity,
+            "POPULARITY_DESC" => MediaTrendSort::PopularityDesc,
+            "TRENDING" => MediaTrendSort::Trending,
+            "TRENDING_DESC" => MediaTrendSort::TrendingDesc,
+            "EPISODE" => MediaTrendSort::Episode,
+            "EPISODE_DESC" => MediaTrendSort::EpisodeDesc,
+            _ => MediaTrendSort::default(),
+        }
+    }
+}
+
+impl From<String> for MediaTrendSort {
+    fn from(value: String) -> Self {
+        MediaTrendSort::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for MediaTrendSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let trend: MediaTrend = serde_json::from_value(serde_json::json!({
+            "mediaId": 1,
+            "date": 1736121600,
+            "trending": 25,
+            "averageScore": 86,
+            "popularity": 1000,
+            "inProgress": 50,
+            "releasing": true,
+            "episode": null
+        }))
+        .unwrap();
+
+        assert_eq!(trend.average_score, Some(86));
+        assert_eq!(trend.in_progress, Some(50));
+        assert_eq!(trend.episode, None);
+        assert_eq!(trend.date_naive(), NaiveDate::from_ymd_opt(2025, 1, 6));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(MediaTrendSort::from("date"), MediaTrendSort::Date);
+        assert_eq!(
+            MediaTrendSort::from("POPULARITY_DESC"),
+            MediaTrendSort::PopularityDesc
+        );
+        assert_eq!(MediaTrendSort::from("unknown"), MediaTrendSort::DateDesc); // Default case
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_value(MediaTrendSort::DateDesc).unwrap(),
+            serde_json::json!("DATE_DESC")
+        );
+        assert_eq!(
+            serde_json::to_value(MediaTrendSort::MediaId).unwrap(),
+            serde_json::json!("MEDIA_ID")
+        );
+    }
+}
@@ -4,11 +4,13 @@
 //! This module contains the `User` struct and its related types.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use super::{
-    Anime, Character, Color, Format, Image, Manga, NotificationOption, Person, Status, Studio,
+    Anime, Character, Color, Format, Image, Manga, MediaListStatus, MediaType, NotificationOption,
+    Person, ScoreFormat, Studio, Tag,
 };
-use crate::{Client, Result};
+use crate::{Client, Error, Result};
 
 /// Represents a user with various attributes.
 ///
@@ -18,10 +20,10 @@ use crate::{Client, Result};
 /// statistics, notification count, and timestamps for creation and
 /// updates.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct User {
     /// The ID of the user.
-    pub id: i32,
+    pub id: i64,
     /// The name of the user.
     pub name: String,
     /// The about of the user.
@@ -35,7 +37,8 @@ pub struct User {
     pub donator_badge: String,
     /// The donator tier of the user.
     pub donator_tier: i32,
-    /// The favourites of the user.
+    /// The favourites of the user, only the first page of each category
+    /// when fetched by ID, see `User::favourites`.
     #[serde(skip)]
     pub favourites: Favourites,
     /// Whether the user is blocked or not.
@@ -64,21 +67,19 @@ pub struct User {
     #[serde(skip)]
     pub(crate) client: Client,
     /// Whether the person's data is fully loaded.
-    #[serde(default)]
+    #[serde(skip)]
     pub(crate) is_full_loaded: bool,
 }
 
 impl User {
     /// Loads the full details of the user.
     ///
+    /// If the user is already fully loaded, it is returned as is.
+    ///
     /// # Errors
     ///
     /// Returns an error if the user details cannot be loaded.
     ///
-    /// # Panics
-    ///
-    /// Panics if the user is already fully loaded.
-    ///
     /// # Example
     ///
     /// ```no_run
@@ -90,17 +91,164 @@ impl User {
     /// # }
     /// ```
     pub async fn load_full(self) -> Result<Self> {
-        if !self.is_full_loaded {
-            self.client.get_user(self.id).await
+        if self.is_full_loaded {
+            return Ok(self);
+        }
+
+        self.client.invalidate(MediaType::User, self.id);
+        self.client.get_user(self.id).await
+    }
+
+    /// Returns all the favourites of the user.
+    ///
+    /// The first page of each category comes with the fully loaded
+    /// user, the remaining pages are fetched through the client, one
+    /// category at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the favourites cannot be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::User, Result};
+    /// #
+    /// # async fn f(user: User) -> Result<()> {
+    /// let favourites = user.favourites().await?;
+    /// println!("{} favourite anime", favourites.anime.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn favourites(&self) -> Result<Favourites> {
+        let (mut favourites, first_page) = if self.is_full_loaded {
+            (self.favourites.clone(), 2)
         } else {
-            panic!("This user is already full loaded")
+            let favourites = Favourites {
+                incomplete: FavouriteCategory::ALL.to_vec(),
+                ..Default::default()
+            };
+
+            (favourites, 1)
+        };
+
+        for category in std::mem::take(&mut favourites.incomplete) {
+            let mut page = first_page;
+
+            loop {
+                let next = self
+                    .client
+                    .get_user_favourites(self.id, category, page)
+                    .await?;
+                let has_next_page = next.incomplete.contains(&category);
+                favourites.extend(next);
+
+                if !has_next_page {
+                    break;
+                }
+                page += 1;
+            }
+        }
+
+        Ok(favourites)
+    }
+
+    /// Builds a partially loaded user from a user node of an API
+    /// response, such as the ones returned by searches.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user node to build the user from.
+    /// * `client` - The client to attach to the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node has no ID.
+    pub(crate) fn from_node(user: &Value, client: &Client) -> Result<Self> {
+        Ok(Self {
+            id: user["id"].as_i64().ok_or(Error::InvalidId)?,
+            name: user["name"].as_str().unwrap_or_default().to_string(),
+            about: user["about"].as_str().map(String::from),
+            avatar: Image::deserialize(&user["avatar"]).ok(),
+            banner: user["bannerImage"].as_str().map(String::from),
+            is_follower: user["isFollower"].as_bool(),
+            is_following: user["isFollowing"].as_bool(),
+            url: user["siteUrl"].as_str().unwrap_or_default().to_string(),
+
+            client: client.clone(),
+            ..Default::default()
+        })
+    }
+}
+
+/// Identifies a user either by its ID or by its name.
+///
+/// Converting a string parses it as an ID when it is numeric, use
+/// `UserIdentifier::Name` to look up a user whose name is numeric.
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::models::UserIdentifier;
+/// assert_eq!(UserIdentifier::from("5375822"), UserIdentifier::Id(5375822));
+/// assert_eq!(
+///     UserIdentifier::from("andrielfr"),
+///     UserIdentifier::Name("andrielfr".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum UserIdentifier {
+    /// The ID of the user.
+    Id(i64),
+    /// The name of the user.
+    Name(String),
+}
+
+impl UserIdentifier {
+    /// Returns the GraphQL variables selecting the user.
+    pub(crate) fn variables(&self) -> Value {
+        match self {
+            UserIdentifier::Id(id) => serde_json::json!({ "id": id }),
+            UserIdentifier::Name(name) => serde_json::json!({ "name": name }),
+        }
+    }
+}
+
+impl From<i64> for UserIdentifier {
+    fn from(id: i64) -> Self {
+        UserIdentifier::Id(id)
+    }
+}
+
+impl From<&str> for UserIdentifier {
+    fn from(value: &str) -> Self {
+        let value = value.trim();
+
+        match value.parse() {
+            Ok(id) => UserIdentifier::Id(id),
+            Err(_) => UserIdentifier::Name(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for UserIdentifier {
+    fn from(value: String) -> Self {
+        UserIdentifier::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for UserIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserIdentifier::Id(id) => write!(f, "{}", id),
+            UserIdentifier::Name(name) => write!(f, "{}", name),
         }
     }
 }
 
 /// The options of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Options {
     /// The title language of the user.
     pub title_language: Option<UserTitleLanguage>,
@@ -131,7 +279,7 @@ pub struct Options {
 
 /// The title language of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum UserTitleLanguage {
     /// The Romaji title language.
     #[default]
@@ -150,7 +298,7 @@ pub enum UserTitleLanguage {
 
 /// The staff name language of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum UserStaffNameLanguage {
     /// The Romaji Western staff name language.
     RomajiWestern,
@@ -163,18 +311,21 @@ pub enum UserStaffNameLanguage {
 
 /// The list activity option of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct ListActivityOption {
     /// The status of the list activity.
-    pub status: Status,
+    pub status: MediaListStatus,
     /// Whether the list activity is disabled or
     pub disabled: bool,
 }
 
 /// The media list options of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct MediaListOptions {
+    /// The score format of the user.
+    #[serde(default)]
+    pub score_format: ScoreFormat,
     /// The row order of the media list options.
     pub row_order: String,
     /// The anime list of the media list options.
@@ -185,7 +336,7 @@ pub struct MediaListOptions {
 
 /// The media list type options of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct MediaListTypeOptions {
     /// The section order of the media list type options.
     pub section_order: Vec<String>,
@@ -212,11 +363,112 @@ pub struct Favourites {
     pub staff: Vec<Person>,
     /// The favourited studios.
     pub studios: Vec<Studio>,
+
+    /// The categories with more favourites than the loaded ones.
+    #[serde(skip)]
+    pub(crate) incomplete: Vec<FavouriteCategory>,
+}
+
+impl Favourites {
+    /// Builds the favourites from a favourites node of an API response.
+    ///
+    /// Only the categories present in the node are filled, the ones
+    /// with a next page are marked as incomplete.
+    ///
+    /// # Arguments
+    ///
+    /// * `favourites` - The favourites node to build the favourites from.
+    /// * `client` - The client to attach to the favourites.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a favourite has no ID or is malformed.
+    pub(crate) fn from_node(favourites: &Value, client: &Client) -> Result<Self> {
+        let mut result = Self::default();
+
+        for category in FavouriteCategory::ALL {
+            let connection = &favourites[category.key()];
+            if connection["pageInfo"]["hasNextPage"].as_bool() == Some(true) {
+                result.incomplete.push(category);
+            }
+
+            let Some(nodes) = connection["nodes"].as_array() else {
+                continue;
+            };
+            for node in nodes {
+                match category {
+                    FavouriteCategory::Anime => result.anime.push(Anime::from_node(node, client)?),
+                    FavouriteCategory::Manga => result.manga.push(Manga::from_node(node, client)?),
+                    FavouriteCategory::Characters => {
+                        result.characters.push(Character::from_node(node, client)?)
+                    }
+                    FavouriteCategory::Staff => result.staff.push(Person::from_node(node, client)?),
+                    FavouriteCategory::Studios => {
+                        result.studios.push(Studio::from_node(node, client)?)
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Appends the favourites of another page.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The favourites to append.
+    fn extend(&mut self, other: Favourites) {
+        self.anime.extend(other.anime);
+        self.manga.extend(other.manga);
+        self.characters.extend(other.characters);
+        self.staff.extend(other.staff);
+        self.studios.extend(other.studios);
+    }
+}
+
+/// Represents a category of favourites.
+///
+/// Each category is paginated separately by the API.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum FavouriteCategory {
+    /// The favourited animes.
+    Anime,
+    /// The favourited mangas.
+    Manga,
+    /// The favourited characters.
+    Characters,
+    /// The favourited staff.
+    Staff,
+    /// The favourited studios.
+    Studios,
+}
+
+impl FavouriteCategory {
+    /// All the categories.
+    pub(crate) const ALL: [FavouriteCategory; 5] = [
+        FavouriteCategory::Anime,
+        FavouriteCategory::Manga,
+        FavouriteCategory::Characters,
+        FavouriteCategory::Staff,
+        FavouriteCategory::Studios,
+    ];
+
+    /// Returns the key of the category in the API.
+    pub(crate) fn key(&self) -> &'static str {
+        match self {
+            FavouriteCategory::Anime => "anime",
+            FavouriteCategory::Manga => "manga",
+            FavouriteCategory::Characters => "characters",
+            FavouriteCategory::Staff => "staff",
+            FavouriteCategory::Studios => "studios",
+        }
+    }
 }
 
 /// The statistics of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct UserStatisticTypes {
     /// The anime statistics of the user.
     pub anime: UserStatistics,
@@ -226,7 +478,7 @@ pub struct UserStatisticTypes {
 
 /// The statistics of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct UserStatistics {
     /// The count of the statistics.
     pub count: i32,
@@ -244,11 +496,21 @@ pub struct UserStatistics {
     pub formats: Option<Vec<UserFormatStatistic>>,
     /// The statuses of the statistics.
     pub statuses: Vec<UserStatusStatistic>,
+    /// The genres of the statistics.
+    pub genres: Option<Vec<UserGenreStatistic>>,
+    /// The tags of the statistics.
+    pub tags: Option<Vec<UserTagStatistic>>,
+    /// The studios of the statistics.
+    pub studios: Option<Vec<UserStudioStatistic>>,
+    /// The staff of the statistics.
+    pub staff: Option<Vec<UserStaffStatistic>>,
+    /// The release years of the statistics.
+    pub release_years: Option<Vec<UserReleaseYearStatistic>>,
 }
 
 /// The format statistics of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct UserFormatStatistic {
     /// The count of the format statistics.
     pub count: i32,
@@ -258,14 +520,14 @@ pub struct UserFormatStatistic {
     pub chapters_read: Option<i32>,
     /// The media IDs of the format statistics.
     #[serde(default)]
-    pub media_ids: Vec<i32>,
+    pub media_ids: Vec<i64>,
     /// The format of the format statistics.
     pub format: Format,
 }
 
 /// The status statistics of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct UserStatusStatistic {
     /// The count of the status statistics.
     pub count: i32,
@@ -276,7 +538,243 @@ pub struct UserStatusStatistic {
     /// The media IDs of the status statistics.
     #[serde(default)]
     /// The status of the status statistics.
-    pub media_ids: Vec<i32>,
+    pub media_ids: Vec<i64>,
     /// The status of the status statistics.
-    pub status: Status,
+    pub status: MediaListStatus,
+}
+
+/// The genre statistics of a user.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserGenreStatistic {
+    /// The count of the genre statistics.
+    pub count: i32,
+    /// The minutes watched of the genre statistics.
+    pub minutes_watched: Option<i32>,
+    /// The chapters read of the genre statistics.
+    pub chapters_read: Option<i32>,
+    /// The mean score of the genre statistics.
+    pub mean_score: Option<f32>,
+    /// The media IDs of the genre statistics.
+    #[serde(default)]
+    pub media_ids: Vec<i64>,
+    /// The genre of the genre statistics.
+    pub genre: String,
+}
+
+/// The tag statistics of a user.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTagStatistic {
+    /// The count of the tag statistics.
+    pub count: i32,
+    /// The minutes watched of the tag statistics.
+    pub minutes_watched: Option<i32>,
+    /// The chapters read of the tag statistics.
+    pub chapters_read: Option<i32>,
+    /// The mean score of the tag statistics.
+    pub mean_score: Option<f32>,
+    /// The media IDs of the tag statistics.
+    #[serde(default)]
+    pub media_ids: Vec<i64>,
+    /// The tag of the tag statistics.
+    pub tag: Tag,
+}
+
+/// The studio statistics of a user.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserStudioStatistic {
+    /// The count of the studio statistics.
+    pub count: i32,
+    /// The minutes watched of the studio statistics.
+    pub minutes_watched: Option<i32>,
+    /// The chapters read of the studio statistics.
+    pub chapters_read: Option<i32>,
+    /// The mean score of the studio statistics.
+    pub mean_score: Option<f32>,
+    /// The media IDs of the studio statistics.
+    #[serde(default)]
+    pub media_ids: Vec<i64>,
+    /// The studio of the studio statistics.
+    pub studio: Studio,
+}
+
+/// The staff statistics of a user.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserStaffStatistic {
+    /// The count of the staff statistics.
+    pub count: i32,
+    /// The minutes watched of the staff statistics.
+    pub minutes_watched: Option<i32>,
+    /// The chapters read of the staff statistics.
+    pub chapters_read: Option<i32>,
+    /// The mean score of the staff statistics.
+    pub mean_score: Option<f32>,
+    /// The media IDs of the staff statistics.
+    #[serde(default)]
+    pub media_ids: Vec<i64>,
+    /// The staff of the staff statistics.
+    pub staff: Person,
+}
+
+/// The release year statistics of a user.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserReleaseYearStatistic {
+    /// The count of the release year statistics.
+    pub count: i32,
+    /// The minutes watched of the release year statistics.
+    pub minutes_watched: Option<i32>,
+    /// The chapters read of the release year statistics.
+    pub chapters_read: Option<i32>,
+    /// The mean score of the release year statistics.
+    pub mean_score: Option<f32>,
+    /// The media IDs of the release year statistics.
+    #[serde(default)]
+    pub media_ids: Vec<i64>,
+    /// The release year of the release year statistics.
+    pub release_year: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_statistics_breakdowns() {
+        let statistics: UserStatistics = serde_json::from_value(serde_json::json!({
+            "count": 2,
+            "statuses": [],
+            "genres": [
+                { "count": 2, "meanScore": 85.5, "minutesWatched": 600, "mediaIds": [1, 20], "genre": "Action" }
+            ],
+            "tags": [{
+                "count": 1,
+                "meanScore": 90.0,
+                "minutesWatched": 300,
+                "mediaIds": [1],
+                "tag": {
+                    "id": 29,
+                    "name": "Magic",
+                    "description": "Prominently features magical elements or the use of magic.",
+                    "category": "Theme-Fantasy",
+                    "isGeneralSpoiler": false,
+                    "isAdult": false
+                }
+            }],
+            "staff": [{
+                "count": 1,
+                "meanScore": 90.0,
+                "mediaIds": [1],
+                "staff": {
+                    "id": 95269,
+                    "name": { "first": "Shinichirou", "full": "Shinichirou Watanabe" },
+                    "languageV2": "Japanese",
+                    "gender": null,
+                    "siteUrl": "https://anilist.co/staff/95269",
+                    "favourites": 1
+                }
+            }],
+            "releaseYears": [{ "count": 1, "meanScore": 90.0, "mediaIds": [1], "releaseYear": 1998 }]
+        }))
+        .unwrap();
+
+        assert_eq!(statistics.genres.unwrap()[0].genre, "Action");
+        assert_eq!(statistics.tags.unwrap()[0].tag.name, "Magic");
+        assert_eq!(statistics.staff.unwrap()[0].staff.id, 95269);
+        assert_eq!(statistics.release_years.unwrap()[0].release_year, 1998);
+        assert_eq!(statistics.studios, None);
+    }
+
+    #[test]
+    fn test_deserialize_slim_statistics() {
+        let statistics: UserStatistics =
+            serde_json::from_value(serde_json::json!({ "count": 0, "statuses": [] })).unwrap();
+
+        assert_eq!(statistics.genres, None);
+        assert_eq!(statistics.release_years, None);
+    }
+
+    #[test]
+    fn test_deserialize_media_list_options() {
+        let options: MediaListOptions = serde_json::from_value(serde_json::json!({
+            "scoreFormat": "POINT_3",
+            "rowOrder": "score",
+            "animeList": {
+                "sectionOrder": [],
+                "splitCompletedSectionByFormat": false,
+                "customLists": [],
+                "advancedScoring": [],
+                "advancedScoringEnabled": false
+            },
+            "mangaList": {
+                "sectionOrder": [],
+                "splitCompletedSectionByFormat": false,
+                "customLists": [],
+                "advancedScoring": [],
+                "advancedScoringEnabled": false
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(options.score_format, ScoreFormat::Point3);
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let user: User = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "name": "Josh",
+            "about": null,
+            "avatar": { "large": "https://example.com/large.png", "medium": "https://example.com/medium.png" },
+            "bannerImage": "https://example.com/banner.jpg",
+            "donatorBadge": "Donator",
+            "donatorTier": 0,
+            "options": {
+                "titleLanguage": "ENGLISH_STYLISED",
+                "displayAdultContent": false,
+                "airingNotifications": true,
+                "profileColor": "blue",
+                "timezone": "-03:00",
+                "staffNameLanguage": "ROMAJI_WESTERN",
+                "disabledListActivity": [{ "status": "CURRENT", "disabled": true }]
+            },
+            "mediaListOptions": {
+                "scoreFormat": "POINT_10_DECIMAL",
+                "rowOrder": "score",
+                "animeList": {
+                    "sectionOrder": ["Watching"],
+                    "splitCompletedSectionByFormat": false,
+                    "customLists": [],
+                    "advancedScoring": [],
+                    "advancedScoringEnabled": false
+                },
+                "mangaList": {
+                    "sectionOrder": [],
+                    "splitCompletedSectionByFormat": true,
+                    "customLists": ["Favourites"],
+                    "advancedScoring": [],
+                    "advancedScoringEnabled": false
+                }
+            },
+            "siteUrl": "https://anilist.co/user/1",
+            "statistics": {
+                "anime": {
+                    "count": 1,
+                    "statuses": [{ "count": 1, "status": "COMPLETED" }],
+                    "genres": [{ "count": 1, "meanScore": 85.5, "minutesWatched": 600, "mediaIds": [1], "genre": "Action" }]
+                },
+                "manga": { "count": 0, "statuses": [] }
+            },
+            "createdAt": 1353081600,
+            "updatedAt": 1700000000
+        }))
+        .unwrap();
+
+        let json = serde_json::to_string(&user).unwrap();
+
+        assert_eq!(serde_json::from_str::<User>(&json).unwrap(), user);
+    }
 }
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the functions used to deserialize the responses
+//! of the API.
+
+use serde::Deserialize;
+use serde_json::Value;
+use serde_path_to_error::Segment;
+
+use crate::{Error, Result};
+
+/// Deserializes the value at a path of a JSON value.
+///
+/// On failure, the error holds the full path to the field that could
+/// not be deserialized, like `data.Media.description`, along with the
+/// ID of the closest object on that path that has one.
+///
+/// # Arguments
+///
+/// * `root` - The value to start from.
+/// * `path` - The dot-separated keys and indices leading to the value,
+///   or an empty string for the root itself.
+pub(crate) fn deserialize<'de, T: Deserialize<'de>>(root: &'de Value, path: &str) -> Result<T> {
+    let keys: Vec<&str> = path.split('.').filter(|key| !key.is_empty()).collect();
+    let value = keys.iter().fold(root, |value, key| child(value, key));
+
+    serde_path_to_error::deserialize(value).map_err(|error| {
+        let inner = error.path().iter().map(|segment| match segment {
+            Segment::Seq { index } => index.to_string(),
+            Segment::Map { key } | Segment::Enum { variant: key } => key.clone(),
+            Segment::Unknown => "?".to_string(),
+        });
+        let keys: Vec<String> = keys
+            .iter()
+            .map(|key| key.to_string())
+            .chain(inner)
+            .collect();
+
+        Error::Deserialization {
+            path: join(&keys),
+            id: closest_id(root, &keys),
+            message: error.into_inner().to_string(),
+        }
+    })
+}
+
+/// Returns the child of a value at a key, or at an index for arrays.
+fn child<'a>(value: &'a Value, key: &str) -> &'a Value {
+    match (value, key.parse::<usize>()) {
+        (Value::Array(_), Ok(index)) => &value[index],
+        _ => &value[key],
+    }
+}
+
+/// Returns the ID of the deepest object along a path that has one.
+fn closest_id(root: &Value, keys: &[String]) -> Option<i64> {
+    let mut value = root;
+    let mut id = value["id"].as_i64();
+
+    for key in keys {
+        value = child(value, key);
+        id = value["id"].as_i64().or(id);
+    }
+
+    id
+}
+
+/// Joins the keys of a path, writing the indices between brackets.
+fn join(keys: &[String]) -> String {
+    let mut path = String::new();
+
+    for key in keys {
+        if key.parse::<usize>().is_ok() {
+            path.push_str(&format!("[{key}]"));
+        } else {
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(key);
+        }
+    }
+
+    if path.is_empty() {
+        path.push('.');
+    }
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Anime, PageInfo, Title};
+
+    #[test]
+    fn test_deserialize() {
+        let value = serde_json::json!({
+            "data": { "Page": { "pageInfo": { "total": 1, "currentPage": 1 } } }
+        });
+
+        let page_info: PageInfo = deserialize(&value, "data.Page.pageInfo").unwrap();
+
+        assert_eq!(page_info.total, 1);
+    }
+
+    #[test]
+    fn test_deserialize_field_path() {
+        let value = serde_json::json!({
+            "data": { "Media": { "id": 1, "title": { "romaji": 7 } } }
+        });
+
+        match deserialize::<Anime>(&value, "data.Media") {
+            Err(Error::Deserialization { path, id, message }) => {
+                assert_eq!(path, "data.Media.title.romaji");
+                assert_eq!(id, Some(1));
+                assert!(message.starts_with("invalid type: integer `7`"));
+            }
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_index_path() {
+        let value = serde_json::json!({
+            "media": [
+                { "id": 1, "title": { "romaji": "Cowboy Bebop" } },
+                { "id": 5, "title": { "romaji": null, "native": [] } }
+            ]
+        });
+
+        let error = deserialize::<Title>(&value, "media.1.title").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "media[1].title.native: invalid type: sequence, expected a string (id 5)"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_root() {
+        let error = deserialize::<PageInfo>(&Value::Null, "").unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::Deserialization { ref path, id: None, .. } if path == "."
+        ));
+    }
+}
@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `RateLimitStatus` and `RateLimiter` structs
+//! and their related functions.
+
+use std::{sync::Mutex, time::Duration};
+
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use tokio::time::Instant;
+
+/// The time to wait when a rate limited response has no `Retry-After`
+/// header.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Represents the rate limit quota reported by the API.
+///
+/// The `RateLimitStatus` struct is built from the `X-RateLimit-Limit`,
+/// `X-RateLimit-Remaining` and `X-RateLimit-Reset` headers of the last
+/// response.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct RateLimitStatus {
+    /// The maximum number of requests allowed per minute, if reported.
+    pub limit: Option<u32>,
+    /// The number of requests remaining in the current minute.
+    pub remaining: u32,
+    /// The time the quota is reset at, if reported.
+    ///
+    /// AniList only reports it once the quota is exhausted.
+    pub reset_at: Option<DateTime<Utc>>,
+}
+
+impl RateLimitStatus {
+    /// Reads the rate limit quota from the headers of a response.
+    ///
+    /// Returns `None` if the response has no `X-RateLimit-Remaining`
+    /// header.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - The headers of the response.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        Some(Self {
+            limit: header_value(headers, "x-ratelimit-limit"),
+            remaining: header_value(headers, "x-ratelimit-remaining")?,
+            reset_at: header_value(headers, "x-ratelimit-reset")
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0)),
+        })
+    }
+}
+
+/// Represents a client-side rate limiter.
+///
+/// The `RateLimiter` struct is a token bucket holding up to a minute
+/// worth of requests, refilled continuously. When the bucket is empty,
+/// callers reserve the next token and wait until it is available, so
+/// concurrent callers are served in order without exceeding the limit.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    /// The maximum number of tokens in the bucket.
+    capacity: f64,
+    /// The time it takes to refill one token.
+    period: Duration,
+    /// The state of the bucket.
+    bucket: Mutex<Bucket>,
+}
+
+/// Represents the state of a `RateLimiter` bucket.
+#[derive(Debug)]
+struct Bucket {
+    /// The number of available tokens, negative when tokens are reserved.
+    tokens: f64,
+    /// The last time the bucket was refilled.
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter with a full bucket.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests_per_minute` - The maximum number of requests per minute.
+    pub(crate) fn new(requests_per_minute: u32) -> Self {
+        let requests_per_minute = requests_per_minute.max(1);
+
+        Self {
+            capacity: f64::from(requests_per_minute),
+            period: Duration::from_secs(60) / requests_per_minute,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(requests_per_minute),
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Takes a token from the bucket, waiting until one is available.
+    pub(crate) async fn acquire(&self) {
+        let wait = {
+            let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+
+            let refilled =
+                now.duration_since(bucket.refilled_at).as_secs_f64() / self.period.as_secs_f64();
+            bucket.tokens = (bucket.tokens + refilled).min(self.capacity);
+            bucket.refilled_at = now;
+            bucket.tokens -= 1.0;
+
+            if bucket.tokens >= 0.0 {
+                return;
+            }
+
+            self.period.mul_f64(-bucket.tokens)
+        };
+
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Reads the time to wait before retrying from the headers of a rate
+/// limited response.
+///
+/// Falls back to one minute if the response has no valid `Retry-After`
+/// header.
+///
+/// # Arguments
+///
+/// * `headers` - The headers of the response.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Duration {
+    header_value(headers, "retry-after")
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_RETRY_AFTER)
+}
+
+/// Parses the value of a header as a number.
+///
+/// # Arguments
+///
+/// * `headers` - The headers to read from.
+/// * `name` - The name of the header.
+fn header_value<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(values: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in values {
+            headers.insert(*name, value.parse().unwrap());
+        }
+
+        headers
+    }
+
+    #[test]
+    fn test_status_from_headers() {
+        let headers = headers(&[("X-RateLimit-Limit", "90"), ("X-RateLimit-Remaining", "42")]);
+
+        assert_eq!(
+            RateLimitStatus::from_headers(&headers),
+            Some(RateLimitStatus {
+                limit: Some(90),
+                remaining: 42,
+                reset_at: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_status_with_reset() {
+        let headers = headers(&[
+            ("X-RateLimit-Limit", "90"),
+            ("X-RateLimit-Remaining", "0"),
+            ("X-RateLimit-Reset", "1735689600"),
+        ]);
+        let status = RateLimitStatus::from_headers(&headers).unwrap();
+
+        assert_eq!(status.remaining, 0);
+        assert_eq!(status.reset_at, DateTime::from_timestamp(1_735_689_600, 0));
+    }
+
+    #[test]
+    fn test_status_without_headers() {
+        assert_eq!(RateLimitStatus::from_headers(&HeaderMap::new()), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_allows_burst() {
+        let limiter = RateLimiter::new(3);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_spaces_requests() {
+        let limiter = RateLimiter::new(3);
+        let start = Instant::now();
+
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+
+        assert!(start.elapsed() >= Duration::from_secs(40));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_is_shared_between_tasks() {
+        let limiter = std::sync::Arc::new(RateLimiter::new(60));
+        let start = Instant::now();
+
+        let tasks = (0..70)
+            .map(|_| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move { limiter.acquire().await })
+            })
+            .collect::<Vec<_>>();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert!(start.elapsed() >= Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let headers = headers(&[("Retry-After", "30")]);
+
+        assert_eq!(retry_after(&headers), Duration::from_secs(30));
+        assert_eq!(retry_after(&HeaderMap::new()), DEFAULT_RETRY_AFTER);
+    }
+}
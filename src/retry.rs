@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the functions used to retry transient failures.
+
+use std::{
+    collections::hash_map::RandomState,
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use crate::{Error, Result};
+
+/// The delay before the first retry.
+const BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// The maximum delay between two attempts, before jitter.
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Runs an operation, retrying it on transient failures.
+///
+/// The operation is retried up to `max_retries` times, waiting an
+/// exponentially growing delay with jitter between attempts. Errors
+/// that are not transient are returned immediately.
+///
+/// # Arguments
+///
+/// * `max_retries` - The maximum number of retries.
+/// * `operation` - The operation to run.
+///
+/// # Errors
+///
+/// Returns the first non transient error, or `Error::RetriesExhausted`
+/// wrapping the last error once all the retries failed.
+pub(crate) async fn retry<T, F, Fut>(max_retries: u32, mut operation: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+
+        match operation().await {
+            Err(error) if is_transient(&error) && max_retries > 0 => {
+                if attempts > max_retries {
+                    return Err(Error::RetriesExhausted {
+                        attempts,
+                        source: Box::new(error),
+                    });
+                }
+
+                tokio::time::sleep(backoff(attempts)).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Returns whether an error is worth retrying.
+///
+/// Only network errors, timeouts and server errors are transient, client
+/// errors and GraphQL validation errors are never retried.
+///
+/// # Arguments
+///
+/// * `error` - The error to check.
+pub(crate) fn is_transient(error: &Error) -> bool {
+    match error {
+        Error::Timeout | Error::ServerError(_) => true,
+        Error::Http(error) => error
+            .status()
+            .map_or(!error.is_builder(), |status| status.is_server_error()),
+        Error::GraphQl {
+            status: Some(status),
+            ..
+        } => (500..600).contains(status),
+        _ => false,
+    }
+}
+
+/// Returns the delay to wait after a failed attempt.
+///
+/// The delay doubles after every attempt, up to a maximum, and a random
+/// jitter of up to half of it is added.
+///
+/// # Arguments
+///
+/// * `attempt` - The number of the failed attempt, starting at one.
+pub(crate) fn backoff(attempt: u32) -> Duration {
+    let delay = BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+        .min(MAX_DELAY);
+    let jitter = delay.mul_f64(random() / 2.0);
+
+    delay + jitter
+}
+
+/// Returns a random number between `0.0` and `1.0`.
+fn random() -> f64 {
+    let value = RandomState::new().build_hasher().finish();
+
+    (value >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use tokio::time::Instant;
+
+    use super::*;
+
+    #[test]
+    fn test_is_transient() {
+        assert!(is_transient(&Error::Timeout));
+        assert!(is_transient(&Error::ServerError(502)));
+        assert!(is_transient(&Error::GraphQl {
+            message: "Internal Server Error".to_string(),
+            status: Some(500),
+            locations: Vec::new(),
+        }));
+        assert!(!is_transient(&Error::GraphQl {
+            message: "Validation error".to_string(),
+            status: Some(400),
+            locations: Vec::new(),
+        }));
+        assert!(!is_transient(&Error::NotFound));
+        assert!(!is_transient(&Error::Unauthorized));
+    }
+
+    #[test]
+    fn test_backoff() {
+        for attempt in 1..=3 {
+            let delay = BASE_DELAY * 2u32.pow(attempt - 1);
+            let backoff = backoff(attempt);
+
+            assert!(backoff >= delay);
+            assert!(backoff <= delay + delay / 2);
+        }
+
+        assert!(backoff(u32::MAX) <= MAX_DELAY + MAX_DELAY / 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_until_success() {
+        let calls = AtomicU32::new(0);
+        let start = Instant::now();
+
+        let result = retry(3, || async {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Err(Error::ServerError(503)),
+                _ => Ok("ok"),
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), "ok");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(start.elapsed() >= BASE_DELAY * 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_exhausted() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<()> = retry(2, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(Error::Timeout)
+        })
+        .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(matches!(
+            result,
+            Err(Error::RetriesExhausted { attempts: 3, source }) if matches!(*source, Error::Timeout)
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_skips_client_errors() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<()> = retry(3, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(Error::NotFound)
+        })
+        .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(matches!(result, Err(Error::NotFound)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_disabled() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<()> = retry(0, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(Error::ServerError(500))
+        })
+        .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(matches!(result, Err(Error::ServerError(500))));
+    }
+}
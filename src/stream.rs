@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the functions used to stream paginated results.
+
+use std::future::Future;
+
+use futures_util::{stream, Stream, TryStreamExt};
+
+use crate::{models::Page, Error, Result};
+
+/// Streams the items of a paginated query.
+///
+/// Pages are fetched lazily, starting from the first one, as the stream
+/// is polled. The stream ends after the last page or after the first
+/// error.
+///
+/// # Arguments
+///
+/// * `fetch` - The function fetching a page by its number.
+pub(crate) fn paginate<T, F, Fut>(mut fetch: F) -> impl Stream<Item = Result<T>>
+where
+    F: FnMut(u16) -> Fut,
+    Fut: Future<Output = Result<Page<T>>>,
+{
+    stream::try_unfold(Some(1), move |page: Option<u16>| {
+        let next = page.map(|page| (page, fetch(page)));
+
+        async move {
+            let Some((page, fetch)) = next else {
+                return Ok::<_, Error>(None);
+            };
+            let items = fetch.await?;
+            let next = items.has_next_page().then(|| page.saturating_add(1));
+
+            Ok(Some((items, next)))
+        }
+    })
+    .map_ok(|page| stream::iter(page.items.into_iter().map(Ok)))
+    .try_flatten()
+}
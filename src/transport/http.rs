@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `HttpTransport` struct.
+
+use std::{sync::Mutex, time::Duration};
+
+use async_trait::async_trait;
+use reqwest::Url;
+use serde_json::Value;
+
+use super::Transport;
+use crate::{
+    rate_limit::{self, RateLimitStatus},
+    Error, Result,
+};
+
+/// Represents the default transport, sending requests to AniList over
+/// HTTP.
+///
+/// The `HttpTransport` struct holds a single `reqwest::Client`, so the
+/// connection pool is reused between requests, and keeps track of the
+/// rate limit quota reported by the API.
+///
+/// Responses are requested compressed with gzip or brotli and decoded
+/// transparently. Large responses, like media lists of thousands of
+/// entries, shrink to about a tenth of their size on the wire.
+#[derive(Debug)]
+pub(crate) struct HttpTransport {
+    /// The HTTP client used to send requests.
+    http: reqwest::Client,
+    /// The URL of the GraphQL endpoint.
+    base_url: Url,
+    /// The rate limit quota reported by the last response.
+    rate_limit: Mutex<Option<RateLimitStatus>>,
+}
+
+impl HttpTransport {
+    /// Creates a new HTTP transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The timeout duration for requests.
+    /// * `connect_timeout` - The timeout duration for connecting.
+    /// * `base_url` - The URL of the GraphQL endpoint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized, just like
+    /// `reqwest::Client::new`.
+    pub(crate) fn new(timeout: Duration, connect_timeout: Duration, base_url: Url) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(timeout)
+            .connect_timeout(connect_timeout)
+            .gzip(true)
+            .brotli(true)
+            .build()
+            .expect("failed to build the HTTP client");
+
+        Self {
+            http,
+            base_url,
+            rate_limit: Mutex::new(None),
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for HttpTransport {
+    async fn execute(&self, query: &str, variables: &Value, token: Option<&str>) -> Result<Value> {
+        let json = serde_json::json!({"query": query, "variables": variables});
+        let mut body = self
+            .http
+            .post(self.base_url.clone())
+            .header("Accept", "application/json")
+            .json(&json);
+
+        if let Some(token) = token {
+            body = body.bearer_auth(token);
+        }
+
+        let response = body.send().await?;
+
+        if let Some(status) = RateLimitStatus::from_headers(response.headers()) {
+            *self.rate_limit.lock().unwrap_or_else(|e| e.into_inner()) = Some(status);
+        }
+
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::RateLimited {
+                retry_after: rate_limit::retry_after(response.headers()),
+            });
+        }
+
+        let status = response.status();
+        let response = response.text().await?;
+
+        // The size is the one of the body once decompressed, since the
+        // length of compressed bodies is not kept by the HTTP client.
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            status = status.as_u16(),
+            size = response.len(),
+            "response received"
+        );
+
+        match serde_json::from_str::<Value>(&response) {
+            Ok(result) => Ok(result),
+            Err(_) if status.is_server_error() => Err(Error::ServerError(status.as_u16())),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        *self.rate_limit.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    async fn download(&self, url: &str) -> Result<Vec<u8>> {
+        let url = Url::parse(url).map_err(|e| Error::InvalidUrl(e.to_string()))?;
+        let response = self.http.get(url.clone()).send().await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(Error::ApiError(format!(
+                "HTTP {} while downloading `{}`",
+                status.as_u16(),
+                url
+            )));
+        }
+
+        let bytes = response.bytes().await?;
+
+        Ok(bytes.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use super::*;
+
+    /// Serves a single gzip compressed JSON response on a local port,
+    /// returning its URL and the headers of the request received.
+    fn serve_gzip(body: Vec<u8>) -> (Url, std::thread::JoinHandle<String>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 4096];
+            let headers = loop {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+
+                let text = String::from_utf8_lossy(&request).to_lowercase();
+                if let Some((headers, body)) = text.split_once("\r\n\r\n") {
+                    let length = headers
+                        .lines()
+                        .find_map(|line| line.strip_prefix("content-length: "))
+                        .and_then(|length| length.trim().parse().ok())
+                        .unwrap_or(0);
+                    if body.len() >= length {
+                        break headers.to_string();
+                    }
+                }
+            };
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Encoding: gzip\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            )
+            .unwrap();
+            stream.write_all(&body).unwrap();
+
+            headers
+        });
+
+        (Url::parse(&format!("http://{}/", address)).unwrap(), handle)
+    }
+
+    #[tokio::test]
+    async fn test_gzip_response() {
+        let entries: Vec<_> = (1..=4000)
+            .map(|id| {
+                serde_json::json!({
+                    "id": id,
+                    "mediaId": id,
+                    "status": "COMPLETED",
+                    "score": 8.5,
+                    "progress": 12,
+                    "media": { "id": id, "title": { "romaji": "Cowboy Bebop" } }
+                })
+            })
+            .collect();
+        let response = serde_json::json!({
+            "data": { "MediaListCollection": { "lists": [{ "entries": entries }] } }
+        });
+        let raw = response.to_string().into_bytes();
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&raw).unwrap();
+        let compressed = encoder.finish().unwrap();
+        // A large media list shrinks to about a tenth of its size.
+        assert!(compressed.len() * 10 < raw.len());
+
+        let (url, handle) = serve_gzip(compressed);
+        let transport = HttpTransport::new(Duration::from_secs(5), Duration::from_secs(5), url);
+
+        let result = transport
+            .execute("query { Viewer { id } }", &serde_json::json!({}), None)
+            .await
+            .unwrap();
+        let headers = handle.join().unwrap();
+
+        assert_eq!(result, response);
+        assert!(headers.contains("content-type: application/json"));
+        assert!(headers
+            .lines()
+            .any(|line| line.starts_with("accept-encoding:") && line.contains("gzip")));
+    }
+}
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `MockTransport` struct.
+
+use std::{collections::VecDeque, sync::Mutex};
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use super::Transport;
+use crate::{Error, Result};
+
+/// Represents a transport replaying canned responses, for tests.
+///
+/// The `MockTransport` struct returns the queued responses in order,
+/// one per request, and records the requests it received. It is only
+/// available with the `test-util` feature.
+///
+/// # Example
+///
+/// ```
+/// # async fn f() -> rust_anilist::Result<()> {
+/// use std::sync::Arc;
+///
+/// use rust_anilist::{Client, MockTransport};
+///
+/// let transport = Arc::new(MockTransport::new());
+/// transport.push(serde_json::json!({
+///     "data": { "GenreCollection": ["Action", "Comedy"] }
+/// }));
+///
+/// let client = Client::with_transport(transport.clone());
+/// let genres = client.get_genres().await?;
+///
+/// assert_eq!(genres, vec!["Action", "Comedy"]);
+/// assert_eq!(transport.requests().len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    /// The responses to replay.
+    responses: Mutex<VecDeque<Result<Value>>>,
+    /// The requests received.
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+/// Represents a request received by a `MockTransport`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockRequest {
+    /// The GraphQL document of the request.
+    pub query: String,
+    /// The variables of the request.
+    pub variables: Value,
+    /// The API token of the request, if any.
+    pub token: Option<String>,
+}
+
+impl MockTransport {
+    /// Creates a new mock transport with no responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a JSON response.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The JSON response to return.
+    pub fn push(&self, response: Value) -> &Self {
+        self.lock_responses().push_back(Ok(response));
+        self
+    }
+
+    /// Queues a JSON response from a fixture.
+    ///
+    /// # Arguments
+    ///
+    /// * `fixture` - The JSON response to return, as a string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixture is not valid JSON.
+    pub fn push_fixture(&self, fixture: &str) -> &Self {
+        let response = serde_json::from_str(fixture).expect("the fixture is not valid JSON");
+        self.push(response)
+    }
+
+    /// Queues an error, as if the API could not be reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error to return.
+    pub fn push_error(&self, error: Error) -> &Self {
+        self.lock_responses().push_back(Err(error));
+        self
+    }
+
+    /// Returns the requests received so far.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Locks the queued responses.
+    fn lock_responses(&self) -> std::sync::MutexGuard<'_, VecDeque<Result<Value>>> {
+        self.responses.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn execute(&self, query: &str, variables: &Value, token: Option<&str>) -> Result<Value> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(MockRequest {
+                query: query.to_string(),
+                variables: variables.clone(),
+                token: token.map(String::from),
+            });
+
+        self.lock_responses()
+            .pop_front()
+            .unwrap_or_else(|| Err(Error::ApiError("no mock response left".to_string())))
+    }
+}
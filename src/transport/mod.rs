@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Transport` trait and its implementations.
+
+mod http;
+#[cfg(any(test, feature = "test-util"))]
+mod mock;
+
+pub(crate) use http::HttpTransport;
+#[cfg(any(test, feature = "test-util"))]
+pub use mock::{MockRequest, MockTransport};
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::{Error, RateLimitStatus, Result};
+
+/// Represents the transport used by a client to reach the API.
+///
+/// The `Transport` trait sends a GraphQL document with its variables
+/// and returns the raw JSON response. The default implementation sends
+/// the document over HTTP, while other implementations can be used to
+/// mock the API in tests.
+///
+/// # Example
+///
+/// ```
+/// use rust_anilist::{Client, Result, Transport};
+///
+/// #[derive(Debug)]
+/// struct Offline;
+///
+/// #[rust_anilist::async_trait]
+/// impl Transport for Offline {
+///     async fn execute(
+///         &self,
+///         _query: &str,
+///         _variables: &serde_json::Value,
+///         _token: Option<&str>,
+///     ) -> Result<serde_json::Value> {
+///         Ok(serde_json::json!({ "data": null }))
+///     }
+/// }
+///
+/// let client = Client::with_transport(Offline);
+/// ```
+#[async_trait]
+pub trait Transport: std::fmt::Debug + Send + Sync {
+    /// Sends a GraphQL document and returns the JSON response.
+    ///
+    /// GraphQL errors are expected to be returned as part of the
+    /// response, the client converts them into an `Error`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL document to send.
+    /// * `variables` - The variables to send with the document.
+    /// * `token` - The API token to authenticate with, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API could not be reached or if it
+    /// returned an invalid response.
+    async fn execute(&self, query: &str, variables: &Value, token: Option<&str>) -> Result<Value>;
+
+    /// Returns the rate limit quota reported by the last response, if
+    /// the transport tracks it.
+    fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        None
+    }
+
+    /// Downloads the resource at the given URL, such as an image.
+    ///
+    /// The default implementation returns an error, as not every
+    /// transport is able to reach URLs outside of the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resource could not be downloaded.
+    async fn download(&self, url: &str) -> Result<Vec<u8>> {
+        Err(Error::ApiError(format!(
+            "the transport cannot download `{}`",
+            url
+        )))
+    }
+}
+
+#[async_trait]
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    async fn execute(&self, query: &str, variables: &Value, token: Option<&str>) -> Result<Value> {
+        (**self).execute(query, variables, token).await
+    }
+
+    fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        (**self).rate_limit_status()
+    }
+
+    async fn download(&self, url: &str) -> Result<Vec<u8>> {
+        (**self).download(url).await
+    }
+}
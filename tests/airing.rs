@@ -0,0 +1,9 @@
+use rust_anilist::Client;
+
+#[tokio::test]
+async fn get_airing_schedule() {
+    let schedules = Client::default()
+        .get_airing_schedule(1735689600, 1736294400, 1, 50)
+        .await;
+    assert!(schedules.is_ok())
+}
@@ -1,7 +1,89 @@
-use rust_anilist::Client;
+use rust_anilist::{
+    models::{AnimeSearchOptions, Format, Language, Media, Season},
+    Client, Error,
+};
 
 #[tokio::test]
 async fn get_anime() {
     let anime = Client::default().get_anime(20).await;
     assert!(anime.is_ok())
 }
+
+#[tokio::test]
+async fn get_anime_not_found() {
+    let anime = Client::default().get_anime(999999999).await;
+    assert!(matches!(anime, Err(Error::NotFound)))
+}
+
+#[tokio::test]
+async fn get_animes() {
+    let animes = Client::default().get_animes(&[1, 20, 21]).await.unwrap();
+    assert_eq!(
+        animes.iter().map(|anime| anime.id).collect::<Vec<_>>(),
+        vec![1, 20, 21]
+    )
+}
+
+#[tokio::test]
+async fn search_anime() {
+    let animes = Client::default().search_anime("Naruto", 1, 10).await;
+    assert!(animes.is_ok())
+}
+
+#[tokio::test]
+async fn search_anime_with() {
+    let options = AnimeSearchOptions::new()
+        .genre_in(["Action"])
+        .season(Season::Fall)
+        .season_year(2023)
+        .format_in([Format::Tv]);
+    let animes = Client::default().search_anime_with(options, 1, 10).await;
+    assert!(animes.is_ok())
+}
+
+#[tokio::test]
+async fn browse_anime() {
+    let animes = Client::default()
+        .browse_anime(Season::Winter, 2025, 1, 10)
+        .await;
+    assert!(animes.is_ok())
+}
+
+#[tokio::test]
+async fn get_trending_anime() {
+    let animes = Client::default().get_trending_anime(1, 10).await;
+    assert!(animes.is_ok())
+}
+
+#[tokio::test]
+async fn load_full_relation() {
+    let anime = Client::default().get_anime(1).await.unwrap();
+    let relation = anime.relations().unwrap().remove(0);
+    let media = match relation.media() {
+        Media::Anime(anime) => anime.load_full().await.map(|anime| anime.id),
+        Media::Manga(manga) => manga.load_full().await.map(|manga| manga.id),
+        Media::Unknown => panic!("the related media is unknown"),
+    };
+    assert!(media.is_ok())
+}
+
+#[tokio::test]
+async fn get_anime_recommendations() {
+    let anime = Client::default().get_anime(1).await.unwrap();
+    let recommendations = anime.recommendations(1, 10).await.unwrap();
+    assert!(!recommendations.is_empty())
+}
+
+#[tokio::test]
+async fn get_anime_reviews() {
+    let anime = Client::default().get_anime(1).await.unwrap();
+    let reviews = anime.reviews(1, 10).await;
+    assert!(reviews.is_ok())
+}
+
+#[tokio::test]
+async fn characters_with_voice_actors() {
+    let anime = Client::default().get_anime(1).await.unwrap();
+    let characters = anime.characters_with_voice_actors(Language::English).await;
+    assert!(characters.is_ok())
+}
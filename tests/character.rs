@@ -18,3 +18,16 @@ async fn get_character_and_char_are_equal() {
     let character2 = Client::default().get_char(40).await.unwrap();
     assert_eq!(character1, character2)
 }
+
+#[tokio::test]
+async fn search_character() {
+    let characters = Client::default().search_character("Lelouch", 1, 10).await;
+    assert!(characters.is_ok())
+}
+
+#[tokio::test]
+async fn get_character_media() {
+    let character = Client::default().get_character(40).await.unwrap();
+    let media = character.media(2, 10).await;
+    assert!(media.is_ok())
+}
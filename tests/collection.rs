@@ -0,0 +1,13 @@
+use rust_anilist::Client;
+
+#[tokio::test]
+async fn get_genres() {
+    let genres = Client::default().get_genres().await;
+    assert!(genres.is_ok())
+}
+
+#[tokio::test]
+async fn get_tags() {
+    let tags = Client::default().get_tags().await;
+    assert!(tags.is_ok())
+}
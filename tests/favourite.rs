@@ -0,0 +1,20 @@
+use rust_anilist::{models::MediaType, Client, Error};
+
+#[tokio::test]
+async fn toggle_favourite_requires_token() {
+    let result = Client::default()
+        .toggle_favourite(MediaType::Anime, 1)
+        .await;
+    assert!(matches!(result, Err(Error::Unauthorized)))
+}
+
+#[tokio::test]
+async fn toggle_favourite_rejects_users() {
+    let result = Client::with_token("token")
+        .toggle_favourite(MediaType::User, 1)
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::InvalidMediaType(MediaType::User))
+    ))
+}
@@ -1,7 +1,51 @@
-use rust_anilist::Client;
+use rust_anilist::{
+    models::{CountryOfOrigin, MangaSearchOptions},
+    Client,
+};
 
 #[tokio::test]
 async fn get_manga() {
     let manga = Client::default().get_manga(30026).await;
     assert!(manga.is_ok())
 }
+
+#[tokio::test]
+async fn get_manga_by_mal_id() {
+    let manga = Client::default().get_manga_by_mal_id(13).await;
+    assert_eq!(manga.unwrap().id_mal, Some(13))
+}
+
+#[tokio::test]
+async fn get_mangas() {
+    let mangas = Client::default().get_mangas(&[30013, 30002]).await.unwrap();
+    assert_eq!(
+        mangas.iter().map(|manga| manga.id).collect::<Vec<_>>(),
+        vec![30013, 30002]
+    )
+}
+
+#[tokio::test]
+async fn search_manga() {
+    let mangas = Client::default().search_manga("Naruto", 1, 10).await;
+    assert!(mangas.is_ok())
+}
+
+#[tokio::test]
+async fn search_manga_with() {
+    let options = MangaSearchOptions::new().country_of_origin(CountryOfOrigin::SouthKorea);
+    let mangas = Client::default().search_manga_with(options, 1, 10).await;
+    assert!(mangas.is_ok())
+}
+
+#[tokio::test]
+async fn get_trending_manga() {
+    let mangas = Client::default().get_trending_manga(1, 10).await;
+    assert!(mangas.is_ok())
+}
+
+#[tokio::test]
+async fn get_manga_recommendations() {
+    let manga = Client::default().get_manga(30013).await.unwrap();
+    let recommendations = manga.recommendations(1, 10).await.unwrap();
+    assert!(!recommendations.is_empty())
+}
@@ -0,0 +1,26 @@
+use rust_anilist::{
+    models::{MediaListEntryUpdate, MediaType},
+    Client, Error,
+};
+
+#[tokio::test]
+async fn save_media_list_entry_requires_token() {
+    let entry = Client::default()
+        .save_media_list_entry(1, MediaListEntryUpdate::new().progress(1))
+        .await;
+    assert!(matches!(entry, Err(Error::Unauthorized)))
+}
+
+#[tokio::test]
+async fn delete_media_list_entry_requires_token() {
+    let deleted = Client::default().delete_media_list_entry(1).await;
+    assert!(matches!(deleted, Err(Error::Unauthorized)))
+}
+
+#[tokio::test]
+async fn get_user_media_list() {
+    let lists = Client::default()
+        .get_user_media_list(5375822, MediaType::Anime, None)
+        .await;
+    assert!(lists.is_ok())
+}
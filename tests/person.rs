@@ -5,3 +5,25 @@ async fn get_person() {
     let person = Client::default().get_person(96879).await;
     assert!(person.is_ok())
 }
+
+#[tokio::test]
+async fn search_person() {
+    let persons = Client::default()
+        .search_person("Kana Hanazawa", 1, 10)
+        .await;
+    assert!(persons.is_ok())
+}
+
+#[tokio::test]
+async fn get_person_characters() {
+    let person = Client::default().get_person(96879).await.unwrap();
+    let characters = person.characters(2, 10).await;
+    assert!(characters.is_ok())
+}
+
+#[tokio::test]
+async fn get_person_staff_media() {
+    let person = Client::default().get_person(96879).await.unwrap();
+    let media = person.staff_media(1, 10).await;
+    assert!(media.is_ok())
+}
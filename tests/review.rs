@@ -0,0 +1,7 @@
+use rust_anilist::Client;
+
+#[tokio::test]
+async fn get_review() {
+    let review = Client::default().get_review(1).await;
+    assert!(review.is_ok())
+}
@@ -0,0 +1,13 @@
+use rust_anilist::Client;
+
+#[tokio::test]
+async fn get_studio() {
+    let studio = Client::default().get_studio(569).await;
+    assert!(studio.is_ok())
+}
+
+#[tokio::test]
+async fn search_studio() {
+    let studios = Client::default().search_studio("ufotable", 1, 10).await;
+    assert_eq!(studios.unwrap().items[0].id, 43)
+}
@@ -12,3 +12,16 @@ async fn get_user_by_name() {
     let user = Client::default().get_user_by_name("andrielfr").await;
     assert!(user.is_ok())
 }
+
+#[tokio::test]
+async fn search_user() {
+    let users = Client::default().search_user("andrielfr", 1, 10).await;
+    assert!(users.is_ok())
+}
+
+#[tokio::test]
+async fn get_user_favourites() {
+    let user = Client::default().get_user(5375822).await.unwrap();
+    let favourites = user.favourites().await;
+    assert!(favourites.is_ok())
+}